@@ -0,0 +1,43 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(nums : &[u32]) -> Option<u32> {
+    for x in nums.iter() {
+        for y in nums.iter() {
+            if x + y  == 2020 {
+                return Some(x * y)
+            }
+        }
+    }
+    None
+}
+
+fn star2(nums : &[u32]) -> Option<u32> {
+    for x in nums.iter() {
+        for y in nums.iter() {
+            for z in nums.iter() {
+                if x + y + z == 2020 {
+                    return Some(x * y * z)
+                }
+            }
+        }
+    }
+    None
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+
+    fn parse(input : &str) -> Result<Vec<u32>, ParseError> {
+        input.split_whitespace().map(|x| aoc::parse_at(input, x, "an expense")).collect()
+    }
+
+    fn part1(nums : &Vec<u32>) -> Answer {
+        star1(nums).map_or(Answer::NoAnswer, Answer::from)
+    }
+
+    fn part2(nums : &Vec<u32>) -> Answer {
+        star2(nums).map_or(Answer::NoAnswer, Answer::from)
+    }
+}
@@ -0,0 +1,42 @@
+use aoc::{Answer, ParseError, Solution};
+
+type Policy = (usize, usize, char, String);
+
+fn star1((min, max, c, s) : &Policy) -> bool {
+    let o = s.chars().filter(|x| x == c).count();
+    o <= *max && o >= *min
+}
+
+fn star2((min, max, c, s) : &Policy) -> bool {
+    (s.chars().nth(min-1) == Some(*c)) ^ (s.chars().nth(max-1) == Some(*c))
+}
+
+fn convert(input : &str, s : &str) -> Result<Policy, ParseError> {
+    let sp : Vec<&str> = s.split_whitespace().collect();
+    let [range, letter, password] = sp[..] else {
+        return Err(ParseError::within(input, s, "a policy A-B c: password"));
+    };
+    let minmax : Vec<usize> = range.split('-').map(|x| aoc::parse_at(input, x, "a position from 1")).collect::<Result<_,_>>()?;
+    match (&minmax[..], letter.chars().next()) {
+        (&[min, max], Some(c)) if min > 0 && max > 0 => Ok((min, max, c, password.to_string())),
+        _ => Err(ParseError::within(input, s, "a policy A-B c: password")),
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Policy>;
+
+    fn parse(input : &str) -> Result<Vec<Policy>, ParseError> {
+        input.lines().map(|x| convert(input, x)).collect()
+    }
+
+    fn part1(pass : &Vec<Policy>) -> Answer {
+        pass.iter().filter(|t| star1(t)).count().into()
+    }
+
+    fn part2(pass : &Vec<Policy>) -> Answer {
+        pass.iter().filter(|t| star2(t)).count().into()
+    }
+}
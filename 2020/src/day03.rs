@@ -0,0 +1,26 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn trees_found(forest : &[String], down : usize, right : usize) -> usize {
+    forest.iter().enumerate().filter(
+        |(num,line)| { num % down == 0 && {
+            line.chars().nth((num/down)*right % (line.len())) == Some('#')
+        }}).count()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(input : &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().filter(|l| !l.is_empty()).map(String::from).collect())
+    }
+
+    fn part1(forest : &Vec<String>) -> Answer {
+        trees_found(forest, 1, 3).into()
+    }
+
+    fn part2(forest : &Vec<String>) -> Answer {
+        (trees_found(forest, 1, 1) * trees_found(forest, 1, 3) * trees_found(forest, 1, 5) * trees_found(forest, 1, 7) * trees_found(forest, 2, 1)).into()
+    }
+}
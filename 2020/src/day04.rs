@@ -0,0 +1,45 @@
+use aoc::{Answer, ParseError, Solution};
+
+const FIELDS : [&str ; 7] = ["byr","iyr","eyr","hgt","hcl","ecl","pid"]; // cid is optional
+
+type Passport = Vec<(String, String)>;
+
+fn complete(p : &Passport) -> bool {
+    FIELDS.iter().all(|f| p.iter().any(|e| &e.0 == f))
+}
+
+fn valid(p : &Passport) -> bool {
+    p.iter().all ( |field| { match field.0.as_str() {
+        "byr" => match field.1.parse::<u16>() { Ok(y) => (1920..=2002).contains(&y), _ => false },
+        "iyr" => match field.1.parse::<u16>() { Ok(y) => (2010..=2020).contains(&y), _ => false },
+        "eyr" => match field.1.parse::<u16>() { Ok(y) => (2020..=2030).contains(&y), _ => false },
+        "hgt" =>
+            (field.1.ends_with("cm") && match field.1[..3].parse::<u8>() { Ok(h) => (150..=193).contains(&h), _ => false }) ||
+            (field.1.ends_with("in") && match field.1[..2].parse::<u8>() { Ok(h) => (59..=76).contains(&h), _ => false }),
+        "hcl" => field.1.len() == 7 && field.1.starts_with('#') && field.1[1..].chars().all(|c| c.is_ascii_hexdigit()),
+        "ecl" => ["amb","blu","brn","gry","grn","hzl","oth"].contains(&field.1.as_str()),
+        "pid" => field.1.len() == 9 && field.1.chars().all(|c| c.is_ascii_digit()),
+        _ => true
+    }})
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+
+    fn parse(input : &str) -> Result<Vec<Passport>, ParseError> {
+        input.split("\n\n").map( |x| x.split_whitespace().map(|f| {
+            let (key, value) = f.split_once(':').ok_or_else(|| ParseError::within(input, f, "a field key:value"))?;
+            Ok((key.to_string(), value.to_string()))
+        }).collect()).collect()
+    }
+
+    fn part1(passports : &Vec<Passport>) -> Answer {
+        passports.iter().filter(|p| complete(p)).count().into()
+    }
+
+    fn part2(passports : &Vec<Passport>) -> Answer {
+        passports.iter().filter(|p| complete(p) && valid(p)).count().into()
+    }
+}
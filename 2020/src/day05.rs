@@ -0,0 +1,38 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    // The seat ids, sorted
+    type Input = Vec<u16>;
+
+    fn parse(input : &str) -> Result<Vec<u16>, ParseError> {
+        let mut passes_ids : Vec<u16> = input.lines().map(|l| {
+            let (row, column) = l.split_at_checked(7).unwrap_or((l, ""));
+            if column.len() != 3 || !row.chars().all(|c| c == 'F' || c == 'B') || !column.chars().all(|c| c == 'L' || c == 'R') {
+                return Err(ParseError::within(input, l, "a boarding pass of 7 F or B then 3 L or R"));
+            }
+            Ok(l.chars().fold(0, |id, c| id*2 + u16::from(c == 'B' || c == 'R')))
+        }).collect::<Result<_,_>>()?;
+        passes_ids.sort();
+        // Part 2 counts on every seat being taken once
+        if let Some(seat) = passes_ids.windows(2).find(|w| w[0] == w[1]) {
+            return Err(ParseError::new(format!("each seat once (seat {} is listed twice)", seat[0])));
+        }
+        Ok(passes_ids)
+    }
+
+    fn part1(passes_ids : &Vec<u16>) -> Answer {
+        passes_ids.last().map_or(Answer::NoAnswer, |n| (*n as u32).into())
+    }
+
+    fn part2(passes_ids : &Vec<u16>) -> Answer {
+        let Some(c) = passes_ids.first() else { return Answer::NoAnswer };
+        for (x, n) in passes_ids.iter().enumerate() {
+            if n - x as u16 != *c {
+                return ((n-1) as u32).into() // The previous entry was missing
+            }
+        }
+        Answer::NoAnswer
+    }
+}
@@ -0,0 +1,31 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // The answers of each person, by group
+    type Input = Vec<Vec<Vec<char>>>;
+
+    fn parse(input : &str) -> Result<Vec<Vec<Vec<char>>>, ParseError> {
+        Ok(input.split("\n\n").map(|x| x.lines().map(|l| l.chars().collect()).collect()).collect())
+    }
+
+    fn part1(groups : &Vec<Vec<Vec<char>>>) -> Answer {
+        let all_questions = groups.iter().map( |x| {
+            let mut q : Vec<char> = x.iter().flatten().copied().collect();
+            q.sort();
+            q.dedup();
+            q
+        });
+        all_questions.map(|x| x.len()).sum::<usize>().into()
+    }
+
+    fn part2(groups : &Vec<Vec<Vec<char>>>) -> Answer {
+        let questions_common = groups.iter().filter(|q| !q.is_empty()).map( |q| {
+            q.iter().fold(q.first().unwrap().to_vec(), |all_answered, lq| // Compute vecs intersection
+                          lq.iter().filter(|e| all_answered.contains(e)).copied().collect::<Vec<char>>()
+            )
+        });
+        questions_common.map(|x| x.len()).sum::<usize>().into()
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
+
+type Rules = Vec<(String,Vec<(usize,String)>)>;
+
+fn search_contained<'a>(to_carry : &String, m: &HashMap<&String, Vec<&'a String>>) -> Vec<&'a String> {
+    match m.get(to_carry) {
+        None => Vec::new(),
+        Some(parent) => {
+            let mut r = parent.to_vec();
+            for e in parent { r.append(&mut search_contained(e,m))}
+            r
+        }
+    }
+}
+
+fn nested_bags(bag : &String, m: &HashMap<&String, &Vec<(usize,String)>>) -> usize {
+    match m.get(bag) {
+        None => 0,
+        Some(parent) => parent.iter().fold(
+            0, |acc, (size, n_bag)| acc + size + size*nested_bags(n_bag, m))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Rules;
+
+    fn parse(input : &str) -> Result<Rules, ParseError> {
+        input.lines().map(|l| {
+            let transform = |v : Vec<&str>| {v.split_last().map_or(String::new(), |(_, b)| b.join(" "))};
+            let (bag, contained) = l.split_once("contain").ok_or_else(|| ParseError::within(input, l, "a rule BAG bags contain ..."))?;
+            Ok((transform(bag.split_whitespace().collect::<Vec<&str>>()),
+             contained.split(",").map(|s| {
+                 let defined = s.split_whitespace().collect::<Vec<&str>>();
+                 let (n, b) = defined.split_first().ok_or_else(|| ParseError::within(input, s, "a number of bags"))?;
+                 Ok(( match n { &"no" => 0, _ => aoc::parse_at(input, n, "a number of bags")? }, transform(b.to_vec()) ))
+             }).collect::<Result<Vec<(usize,String)>,ParseError>>()?))
+        }).collect()
+    }
+
+    fn part1(parsed : &Rules) -> Answer {
+        let mut contained_by : HashMap<&String, Vec<&String>> = HashMap::new();
+        for (source,contained) in parsed {
+            for (_n,bag) in contained {
+                contained_by.entry(bag).or_default().push(source)}
+        }
+        let mut star1 = search_contained(&String::from("shiny gold"), &contained_by);
+        star1.sort(); star1.dedup(); // We leave the unique elements
+        star1.len().into()
+    }
+
+    fn part2(parsed : &Rules) -> Answer {
+        let contains : HashMap<&String, &Vec<(usize,String)>> = parsed.iter().map(|(source,contained)| (source,contained)).collect();
+        nested_bags(&String::from("shiny gold"), &contains).into()
+    }
+}
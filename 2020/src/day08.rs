@@ -0,0 +1,77 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub enum Ins {
+    Nop(isize),
+    Acc(isize),
+    Jmp(isize)
+}
+
+fn inf_loop(instructions : &Vec<Ins>, index : usize, acc : isize, executed : &mut Vec<usize>) -> (isize, bool) {
+    if index == instructions.len() { (acc, true) }
+    else if executed.contains(&index) { (acc, false) } else {
+            executed.push(index);
+            match instructions[index] {
+                Ins::Nop(_) => inf_loop(instructions, index+1, acc, executed),
+                Ins::Jmp(n) => inf_loop(instructions, index.wrapping_add_signed(n), acc, executed),
+                Ins::Acc(n) => inf_loop(instructions, index+1, acc+n, executed)
+            }
+        }
+}
+
+// executed will be modified in the inner executions, but it doesn't matter because when braking the loop a never reached instruction will be reached
+// `tried` holds the instructions of the original loop already considered for the change
+fn solve_loop(instructions : &Vec<Ins>, index : usize, acc : isize, executed : &mut Vec<usize>, tried : &mut Vec<usize>) -> Option<isize> {
+    // A program that already ends needs no repair
+    let Some(instruction) = instructions.get(index) else { return Some(acc) };
+    // Back at the start of the loop: no single change ends the program
+    if tried.contains(&index) { return None }
+    tried.push(index);
+    match *instruction {
+        Ins::Nop(n) => match inf_loop(instructions, index.wrapping_add_signed(n), acc, executed) {
+            (acc, true) => Some(acc),
+            _ => solve_loop(instructions, index+1, acc, executed, tried)
+        },
+        Ins::Jmp(n) => match inf_loop(instructions, index+1, acc, executed) {
+            (acc, true) => Some(acc),
+            _ => solve_loop(instructions, index.wrapping_add_signed(n), acc, executed, tried)
+        },
+        Ins::Acc(n) => solve_loop(instructions, index+1, acc+n, executed, tried),
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Ins>;
+
+    fn parse(input : &str) -> Result<Vec<Ins>, ParseError> {
+        let count = input.lines().count();
+        input.lines().enumerate().map(|(index, l)| {
+            let mut i = l.split_whitespace();
+            let op = match i.next() {
+                Some("nop") => Ins::Nop,
+                Some("acc") => Ins::Acc,
+                Some("jmp") => Ins::Jmp,
+                _ => return Err(ParseError::within(input, l, "nop, acc or jmp"))
+            };
+            let argument = i.next().unwrap_or(l);
+            let n : isize = aoc::parse_at(input, argument, "an argument")?;
+            // A nop may be turned into a jmp, so its target must be in the program too
+            if !matches!(op(n), Ins::Acc(_)) && index.checked_add_signed(n).is_none_or(|to| to > count) {
+                return Err(ParseError::within(input, argument, "a jump within the program"));
+            }
+            Ok(op(n))
+        }).collect()
+    }
+
+    fn part1(instructions : &Vec<Ins>) -> Answer {
+        inf_loop(instructions, 0, 0, &mut Vec::new()).0.into()
+    }
+
+    fn part2(instructions : &Vec<Ins>) -> Answer {
+        // The instructions run by part 1 are the ones `solve_loop` goes through
+        let mut executed : Vec<usize> = Vec::new();
+        inf_loop(instructions, 0, 0, &mut executed);
+        solve_loop(instructions, 0, 0, &mut executed, &mut Vec::new()).map_or(Answer::NoAnswer, Answer::from)
+    }
+}
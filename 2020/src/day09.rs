@@ -0,0 +1,58 @@
+use aoc::{Answer, InputKind, Param, ParseError, Solution};
+
+// The numbers each one must be a sum of two of
+const PREAMBLE : Param<usize> = Param { real: 25, example: 5 };
+
+pub struct Xmas {
+    nums: Vec<usize>,
+    preamble: usize,
+}
+
+fn is_sum_of(r : usize, prev : &[usize]) -> bool {
+    prev.iter().filter(|x| **x <= r && prev.contains(&(r-*x)) && r != *x*2 ).count() > 0
+}
+
+fn find_summing(r : usize, nums : &[usize]) -> usize {
+    let mut adding = 0;
+    let mut min = 0;
+    for max in 0..nums.len() {
+        adding += nums[max];
+        while adding > r {
+            adding -= nums[min];
+            min += 1;
+        }
+        if adding == r {
+            let v : Vec<usize> = nums[min..max+1].to_vec();
+            let min = v.iter().min().unwrap();
+            let max = v.iter().max().unwrap();
+            return min+max;
+        }
+    }
+    0
+}
+
+// The first number that is not the sum of two of the preamble before it
+fn invalid(x : &Xmas) -> Option<usize> {
+    (x.preamble..x.nums.len()).find(|i| !is_sum_of(x.nums[*i], &x.nums[i-x.preamble..*i])).map(|i| x.nums[i])
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Xmas;
+
+    fn parse_with(input : &str, kind : InputKind) -> Result<Xmas, ParseError> {
+        Ok(Xmas {
+            nums: input.lines().map(|l| aoc::parse_at(input, l, "a number")).collect::<Result<_,_>>()?,
+            preamble: PREAMBLE.get(kind),
+        })
+    }
+
+    fn part1(x : &Xmas) -> Answer {
+        invalid(x).map_or(Answer::NoAnswer, Answer::from)
+    }
+
+    fn part2(x : &Xmas) -> Answer {
+        invalid(x).map_or(Answer::NoAnswer, |r| find_summing(r, &x.nums).into())
+    }
+}
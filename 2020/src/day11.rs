@@ -0,0 +1,46 @@
+use aoc::automaton::{Automaton, Dense};
+use aoc::grid::{Grid, Pos, ADJACENT};
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Seat { Floor, Occupied, Empty }
+
+/// Seats people until nobody moves, `seen` counting the occupied seats around one.
+fn seat_people(seats : Grid<Seat>, tolerance : usize, seen : impl Fn(&Dense<Seat>, Pos) -> usize) -> usize {
+    let mut room = Automaton::new(Dense::new(seats, Seat::Floor), |room : &Dense<Seat>| room.step(0, |room, p| {
+        let c = seen(room, p);
+        match *room.get(p) {
+            Seat::Empty if c == 0 => Seat::Occupied,
+            Seat::Occupied if c >= tolerance => Seat::Empty,
+            s => s
+        }
+    }));
+    room.run_until_stable();
+    room.state.grid.values().filter(|s| **s == Seat::Occupied).count()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Seat>;
+
+    fn parse(input : &str) -> Result<Grid<Seat>, ParseError> {
+        Grid::parse(input, |s| match s {
+            '#' => Some(Seat::Occupied),
+            'L' => Some(Seat::Empty),
+            '.' => Some(Seat::Floor),
+            _ => None
+        }, "a seat 'L', '#' or floor '.'")
+    }
+
+    fn part1(seats : &Grid<Seat>) -> Answer {
+        seat_people(seats.clone(), 4, |room, p| room.count_around(p, &ADJACENT, |s| *s == Seat::Occupied)).into()
+    }
+
+    fn part2(seats : &Grid<Seat>) -> Answer {
+        // The first seat seen in each direction, skipping the floor
+        seat_people(seats.clone(), 5, |room, p| ADJACENT.iter().filter(|step| {
+            room.grid.line(p, **step).skip(1).map(|(_, s)| *s).find(|s| *s != Seat::Floor) == Some(Seat::Occupied)
+        }).count()).into()
+    }
+}
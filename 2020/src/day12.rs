@@ -0,0 +1,70 @@
+use aoc::geometry::{Dir4, Point2};
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(Debug, PartialEq)]
+pub enum Turn {
+    Left, Right, Forward
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Action { Dir(Dir4), Turn(Turn) }
+
+fn turn(prev: Dir4, new: &Turn, degrees: isize) -> Dir4 {
+    match new {
+        Turn::Forward => prev,
+        Turn::Left => prev.turn(-degrees as i64 / 90),
+        Turn::Right => prev.turn(degrees as i64 / 90),
+    }
+}
+
+fn move_ship(s: Point2, dir: Dir4, (action, moved): &(Action, isize)) -> (Point2, Dir4) {
+    match action {
+        Action::Dir(d) => (s + d.delta() * *moved as i64, dir),
+        Action::Turn(Turn::Forward) => (s + dir.delta() * *moved as i64, dir),
+        Action::Turn(t) => (s, turn(dir, t, *moved)),
+    }
+}
+
+fn star2(s: Point2, w : Point2, (action, moved): &(Action, isize)) -> (Point2, Point2) {
+    match action {
+        Action::Dir(d) => (s, w + d.delta() * *moved as i64),
+        Action::Turn(Turn::Forward) => (s + w * *moved as i64, w),
+        Action::Turn(Turn::Right) => if moved == &0 {(s,w)} else {
+            star2(s,w.rotate_right(),&(Action::Turn(Turn::Right), *moved-90))},
+        Action::Turn(Turn::Left) => if moved == &0 {(s,w)} else {
+            star2(s,w.rotate_left(),&(Action::Turn(Turn::Left), *moved-90))},
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Action, isize)>;
+
+    fn parse(input : &str) -> Result<Vec<(Action, isize)>, ParseError> {
+        input.lines().map( |l| {
+            let mut it = l.chars();
+            let action = match it.next() {
+                Some('L') => Action::Turn(Turn::Left),
+                Some('R') => Action::Turn(Turn::Right),
+                Some('F') => Action::Turn(Turn::Forward),
+                Some(c) => Action::Dir(Dir4::from_char(c).ok_or_else(|| ParseError::within(input, l, "an action NSEWLRF"))?),
+                None => return Err(ParseError::within(input, l, "an action NSEWLRF")),
+            };
+            Ok((action, aoc::parse_at(input, it.as_str(), "a value")?))
+        }).collect()
+    }
+
+    fn part1(actions : &Vec<(Action, isize)>) -> Answer {
+        let (ship1,_) : (Point2,Dir4) = actions.iter().fold(
+            (Point2::ORIGIN, Dir4::Right), |(s,d),a| move_ship(s,d,a));
+        ship1.manhattan().into()
+    }
+
+    fn part2(actions : &Vec<(Action, isize)>) -> Answer {
+        // The waypoint starts 10 east and 1 north of the ship
+        let (ship2, _) : (Point2, Point2) = actions.iter().fold(
+            (Point2::ORIGIN, Point2::new(10,-1)), |(s,w),a| star2(s,w,a));
+        ship2.manhattan().into()
+    }
+}
@@ -0,0 +1,48 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Notes {
+    earliest_timestamp: usize,
+    // The buses in service, with their offset in the list
+    bus_ids: Vec<(usize,usize)>,
+}
+
+fn first_max(min: usize, current: usize, step: &usize) -> usize {
+    if current > min { current } else { first_max(min, current+step, step)}
+}
+
+fn get_min_timestamp(ids  : &Vec<(usize, usize)>) -> usize {
+    let mut timestamp = 0;
+    let mut inc = 1;
+    // CRT
+    for (i,id) in ids {
+        while (timestamp + i) % id != 0 {
+            timestamp += inc;
+        }
+        inc *= id;
+    }
+    timestamp
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+
+    fn parse(input : &str) -> Result<Notes, ParseError> {
+        let mut it = input.lines();
+        let earliest_timestamp = aoc::parse_at(input, it.next().unwrap_or(input), "the earliest timestamp")?;
+        let buses = it.next().ok_or_else(|| ParseError::new("a line of bus ids"))?;
+        let bus_ids = buses.split(',').enumerate().filter(|s| s.1 != "x").map(|(i,n)| Ok((i,aoc::parse_at(input, n, "a bus id or x")?))).collect::<Result<_,ParseError>>()?;
+        aoc::debug!("{:?}", bus_ids);
+        Ok(Notes { earliest_timestamp, bus_ids })
+    }
+
+    fn part1(n : &Notes) -> Answer {
+        let differences = n.bus_ids.iter().map(|(_,id)| (first_max(n.earliest_timestamp, 0, id) - n.earliest_timestamp,id));
+        differences.min().map_or(Answer::NoAnswer, |min| (min.0*min.1).into())
+    }
+
+    fn part2(n : &Notes) -> Answer {
+        get_min_timestamp(&n.bus_ids).into()
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
+
+type Mask = Vec<(usize,Option<bool>)>; // index,val
+
+pub enum Line {
+    Mask(Mask),
+    Mem(usize, usize),
+}
+
+fn update_value(val: usize, mask : &Mask) -> usize {
+    mask.iter().fold(val, |x, (pos,v)| match v {
+            None => x,
+            Some(true) => x | 1 << (35 - pos),
+            Some(false) => x & !(1 << (35 - pos))
+    })
+}
+
+fn update_address(val: usize, mask : &Mask) -> Vec<usize> {
+    mask.iter().fold(vec!(val), |s, (pos,v)| match v {
+        None => {
+            let mut new_s : Vec<usize> = s.iter().map(|x| x | 1 << (35 - pos)).collect(); // Set it at 1
+            new_s.append(&mut s.iter().map(|x| x & !(1 << (35 - pos))).collect()); // Set it at 0
+            new_s
+        },
+        Some(true) => s.iter().map(|x| x | 1 << (35 - pos)).collect(),
+        Some(false) => s
+    })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Line>;
+
+    fn parse(input : &str) -> Result<Vec<Line>, ParseError> {
+        input.lines().map(|l| {
+            let (var, value) = l.split_once('=').ok_or_else(|| ParseError::within(input, l, "mask = ... or mem[N] = ..."))?;
+            let (var, value) = (var.trim(), value.trim());
+            match var {
+                "mask" => Ok(Line::Mask(value.chars().enumerate()//.filter(|(_,x)| *x != 'X')
+                    .map(|(i,v)| (i, if v == 'X' {None} else {Some(v=='1')})).collect())),
+                _ => {
+                    let pos = var.strip_prefix("mem[").and_then(|v| v.strip_suffix(']')).ok_or_else(|| ParseError::within(input, var, "mem[N]"))?;
+                    Ok(Line::Mem(aoc::parse_at(input, pos, "an address")?, aoc::parse_at(input, value, "a value")?))
+                }
+            }
+        }).collect()
+    }
+
+    fn part1(lines : &Vec<Line>) -> Answer {
+        let mut current_mask : &Mask = &Vec::new();
+        let mut mem1 : HashMap<usize, usize> = HashMap::new();
+        for l in lines {
+            match l {
+                Line::Mask(mask) => current_mask = mask,
+                Line::Mem(pos, value) => { mem1.insert(*pos, update_value(*value, current_mask)); }
+            }
+        }
+        mem1.values().sum::<usize>().into()
+    }
+
+    fn part2(lines : &Vec<Line>) -> Answer {
+        let mut current_mask : &Mask = &Vec::new();
+        let mut mem2 : HashMap<usize, usize> = HashMap::new();
+        for l in lines {
+            match l {
+                Line::Mask(mask) => current_mask = mask,
+                Line::Mem(pos, value) => for pos in update_address(*pos, current_mask) {
+                    mem2.insert(pos, *value);
+                }
+            }
+        }
+        mem2.values().sum::<usize>().into()
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
+
+// The number spoken on the given turn
+fn spoken(nums : &[usize], turns : usize) -> usize {
+    let mut last_time_spoken : HashMap<usize, usize> = HashMap::new();
+    for (turn,num) in nums[..nums.len()-1].iter().enumerate() {
+        last_time_spoken.insert(*num,turn);
+    }
+    let mut last_spoken_number : usize = *nums.last().unwrap();
+    for turn in nums.len()..turns {
+        let new_num = match last_time_spoken.get(&last_spoken_number) {
+            None => {
+                0
+            },
+            Some(last_time) => {
+                (turn-1)-last_time
+            }
+        };
+        last_time_spoken.insert(last_spoken_number, turn-1);
+        last_spoken_number = new_num;
+    }
+    last_spoken_number
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+
+    fn parse(input : &str) -> Result<Vec<usize>, ParseError> {
+        let nums : Vec<usize> = input.trim().split(',').map(|x| aoc::parse_at(input, x, "a starting number")).collect::<Result<_,_>>()?;
+        if nums.is_empty() { return Err(ParseError::new("a starting number")) }
+        Ok(nums)
+    }
+
+    fn part1(nums : &Vec<usize>) -> Answer {
+        spoken(nums, 2020).into()
+    }
+
+    fn part2(nums : &Vec<usize>) -> Answer {
+        spoken(nums, 30000000).into()
+    }
+}
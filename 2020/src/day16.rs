@@ -0,0 +1,55 @@
+use aoc::{Answer, ParseError, Solution};
+
+pub struct Notes {
+    constraints: Vec<(String,Vec<(usize,usize)>)>,
+    own_ticket: Vec<usize>,
+    nearby_tickets: Vec<Vec<usize>>,
+}
+
+fn ticket(input : &str, l : &str) -> Result<Vec<usize>, ParseError> {
+    l.split(',').map(|x| aoc::parse_at(input, x, "a ticket field")).collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(input : &str) -> Result<Notes, ParseError> {
+        let mut it = input.split("\n\n");
+        let mut section = |name| it.next().ok_or_else(|| ParseError::new(name));
+        let constraints = section("the rules")?.lines().map(|l| {
+            let (name, ranges) = l.split_once(':').ok_or_else(|| ParseError::within(input, l, "a rule NAME: A-B or C-D"))?;
+            Ok(( name.to_string(), // Field name
+              ranges.split("or").map(|nums| {
+                let ns : Vec<usize> = nums.split('-').map(|x| aoc::parse_at(input, x.trim(), "a bound")).collect::<Result<_,_>>()?;
+                match ns[..] {
+                    [a, b] => Ok((a,b)),
+                    _ => Err(ParseError::within(input, nums, "a range A-B"))
+                }
+            }).collect::<Result<_,ParseError>>()?))
+        }).collect::<Result<_,ParseError>>()?;
+        let own_ticket = ticket(input, section("your ticket")?.lines().nth(1).ok_or_else(|| ParseError::new("your ticket"))?)?;
+        let nearby_tickets = section("nearby tickets")?.lines().skip(1).map(|l| ticket(input, l)).collect::<Result<_,_>>()?;
+        Ok(Notes { constraints, own_ticket, nearby_tickets })
+    }
+
+    fn part1(n : &Notes) -> Answer {
+        let invalid_fields = n.nearby_tickets.iter().map(|t| t.iter().filter(|f| !n.constraints.iter().flat_map(|x| &x.1).any(|(x,y)| *f >= x && *f <= y)));
+        invalid_fields.flatten().sum::<usize>().into()
+    }
+
+    fn part2(n : &Notes) -> Answer {
+        let constraints = &n.constraints;
+        let mut constrained : Vec<(usize,Vec<&String>)> = (0..constraints.len()).map(|index| {
+            let valid = n.nearby_tickets.iter().filter(|t| t.iter().all(|n| constraints.iter().flat_map(|x| &x.1).any(|(x,y)| n >= x && n <= y)));
+            let fields : Vec<usize> = valid.map(|t| t[index]).collect();
+            (index, constraints.iter().filter(
+                |(_name,req)| fields.iter().all(|f| req.iter().any(|(x,y)| f >= x && f <= y)))
+                .map(|(name,_)| name).collect())
+        }).collect();
+        constrained.sort_by(|(_i1,c1),(_i2,c2)| c1.len().partial_cmp(&c2.len()).unwrap()); // Sort by possible corresponding fields  the 1 element vector the first
+        let constrained_unique = constrained.iter().enumerate().map(|(i,x)| (x.0, if i == 0 {x.1[0]} else {x.1.iter().find(|f| !constrained[i-1].1.contains(f)).unwrap()})); // Obtain unique elements
+        constrained_unique.filter(|(_,v)| v.contains("departure")).map(|(i,_)| n.own_ticket[i]).product::<usize>().into()
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+use aoc::automaton::{self, Automaton};
+use aoc::{Answer, ParseError, Solution};
+
+/// The active cubes after six cycles, the initial slice lying at 0 in every other dimension.
+fn boot<const D: usize>(slice: &[(i64,i64)]) -> usize {
+    let cubes : HashSet<[i64;D]> = slice.iter().map(|&(x,y)| {
+        let mut c = [0;D];
+        c[0] = x; c[1] = y;
+        c
+    }).collect();
+    let mut pocket = Automaton::new(cubes, |cubes : &HashSet<[i64;D]>| {
+        automaton::sparse(cubes, automaton::moore, |active,n| n == 3 || active && n == 2)
+    });
+    for _ in 0..6 {
+        aoc::debug!("cycle {}: {} active", pocket.generation, pocket.state.len());
+        pocket.step();
+    }
+    pocket.state.len()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<(i64,i64)>;
+
+    fn parse(input : &str) -> Result<Vec<(i64,i64)>, ParseError> {
+        Ok(input.lines().enumerate().flat_map(|(x,l)| {
+            l.chars().enumerate().filter(|(_,e)| e == &'#').map(move |(y,_)| (x as i64,y as i64))
+        }).collect())
+    }
+
+    fn part1(slice : &Vec<(i64,i64)>) -> Answer {
+        boot::<3>(slice).into()
+    }
+
+    fn part2(slice : &Vec<(i64,i64)>) -> Answer {
+        boot::<4>(slice).into()
+    }
+}
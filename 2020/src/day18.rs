@@ -1,4 +1,4 @@
-use std::fs;
+use aoc::{Answer, ParseError, Solution};
 
 // Returns tuple (result, index)
 fn solve_operation_1(mut i : usize, operation : &str) -> (usize, usize) {
@@ -22,8 +22,8 @@ fn solve_operation_1(mut i : usize, operation : &str) -> (usize, usize) {
                 i = op.1;
                 match current_operation {
                     None => current_result = op.0,
-                    Some('+') => current_result += op.0 as usize,
-                    Some('*') => current_result *= op.0 as usize,
+                    Some('+') => current_result += op.0,
+                    Some('*') => current_result *= op.0,
                     _ => panic!()
                 }
             },
@@ -49,7 +49,7 @@ fn solve_operation_2(mut i : usize, operation : &str) -> (usize, usize) {
                     None => current_result = num as usize,
                     Some('+') => current_result += num as usize,
                     Some('*') => {
-                        elems.push(current_result as usize);
+                        elems.push(current_result);
                         current_result = num as usize;
                     },
                     _ => panic!()
@@ -60,11 +60,11 @@ fn solve_operation_2(mut i : usize, operation : &str) -> (usize, usize) {
                 let op = solve_operation_2(i+1, operation);
                 let num = op.0; i = op.1;
                 match current_operation {
-                    None => current_result = num as usize,
-                    Some('+') => current_result += num as usize,
+                    None => current_result = num,
+                    Some('+') => current_result += num,
                     Some('*') => {
-                        elems.push(current_result as usize);
-                        current_result = num as usize;
+                        elems.push(current_result);
+                        current_result = num;
                     },
                 _ => panic!()
                 }
@@ -80,11 +80,23 @@ fn solve_operation_2(mut i : usize, operation : &str) -> (usize, usize) {
     (elems.iter().product::<usize>(), operation.len())
 }
 
-fn main () {
-    let r = fs::read_to_string("input").unwrap();
-    let operations : Vec<(usize,usize)> = r.lines().map(|l| {
-        (solve_operation_1(0,l).0, solve_operation_2(0, l).0)
-    }).collect();
-    println!("{:?}", operations.iter().map(|x| x.0).sum::<usize>());
-    println!("{:?}", operations.iter().map(|x| x.1).sum::<usize>());
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+
+    fn parse(input : &str) -> Result<Vec<String>, ParseError> {
+        input.lines().map(|l| match l.chars().find(|c| !"0123456789+*() ".contains(*c)) {
+            Some(_) => Err(ParseError::within(input, l, "an expression of digits, +, *, and parentheses")),
+            None => Ok(l.to_string())
+        }).collect()
+    }
+
+    fn part1(operations : &Vec<String>) -> Answer {
+        operations.iter().map(|l| solve_operation_1(0, l).0).sum::<usize>().into()
+    }
+
+    fn part2(operations : &Vec<String>) -> Answer {
+        operations.iter().map(|l| solve_operation_2(0, l).0).sum::<usize>().into()
+    }
 }
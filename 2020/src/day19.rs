@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Lit(bool),
+    Chain(Vec<Vec<usize>>)
+    // Or(Rule, Rule),
+    // And(Rule, Rule)
+}
+
+fn match_rule(message : &str, rule_ids : &[usize], rules : &HashMap<usize,Rule>) -> bool {
+    match rule_ids.split_first() {
+        None => message.is_empty(),
+        Some((first_rule,next_rules)) => match &rules[first_rule] {
+            Rule::Lit(c) => message.starts_with(if *c {'a'} else {'b'}) && match_rule(&message[1..], next_rules, rules),
+            Rule::Chain(chain) => {
+                chain.iter().any(|seq_rules| {
+                    let mut r : Vec<usize> = seq_rules.to_vec(); // TODO without replicating vec
+                    r.extend_from_slice(next_rules);
+                    match_rule(message, r.as_slice(), rules)
+                })
+            }
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<usize,Rule>, Vec<String>);
+
+    fn parse(input : &str) -> Result<(HashMap<usize,Rule>, Vec<String>), ParseError> {
+        let (rules, messages) = input.split_once("\n\n").ok_or_else(|| ParseError::new("rules, a blank line and messages"))?;
+        let rules : HashMap<usize,Rule> = rules.lines().map(|l| {
+            let (rule_n, rule_def) = l.split_once(':').ok_or_else(|| ParseError::within(input, l, "a rule N: ..."))?;
+            let rule_def : Rule = match rule_def.trim() {
+                "\"a\"" => Rule::Lit(true),
+                "\"b\"" => Rule::Lit(false),
+                chain => Rule::Chain({
+                    chain.split_whitespace().collect::<Vec<&str>>().split(|n| n == &"|").map(|e| e.iter().map(|n| aoc::parse_at(input, n, "a rule number")).collect::<Result<Vec<usize>,_>>()).collect::<Result<_,_>>()?
+                }),
+            };
+            Ok((aoc::parse_at(input, rule_n, "a rule number")?, rule_def))
+        }).collect::<Result<_,ParseError>>()?;
+        if !rules.contains_key(&0) { return Err(ParseError::new("a rule 0")) }
+        Ok((rules, messages.lines().map(String::from).collect()))
+    }
+
+    fn part1((rules, messages) : &(HashMap<usize,Rule>, Vec<String>)) -> Answer {
+        messages.iter().filter(|m| match_rule(m, &[0], rules)).count().into()
+    }
+
+    fn part2((rules, messages) : &(HashMap<usize,Rule>, Vec<String>)) -> Answer {
+        let mut rules = rules.clone();
+        rules.insert(8, Rule::Chain(vec![vec![42],vec![42,8]]));
+        rules.insert(11, Rule::Chain(vec![vec![42,31],vec![42,11,31]]));
+        messages.iter().filter(|m| match_rule(m, &[0], &rules)).count().into()
+    }
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use aoc::{Answer, ParseError, Solution};
 
 type Tile = Vec<Vec<bool>>;
 
@@ -45,34 +45,35 @@ fn matches(tiles : &[(usize,[Tile;8])]) -> Vec<usize> {
     }
 }
 
-fn tile_adjacents(ids : &Vec<usize>, matches_id : &Vec<usize>) -> Vec<(usize,usize)> {
+fn tile_adjacents(ids : &[usize], matches_id : &[usize]) -> Vec<(usize,usize)> {
     ids.iter().map(|id| (*id,matches_id.iter().filter(|id_m| **id_m % id == 0).count())).collect()
 }
 
-fn tile_by_right<'a>(left : &Tile, possibilities : &'a[Tile;8]) -> &'a Tile {
+fn tile_by_right<'a>(left : &Tile, possibilities : &'a[Tile;8]) -> Option<&'a Tile> {
     let to_match : Vec<&bool> = left.iter().map(|r| r.last().unwrap()).collect();
-    possibilities.iter().find(|t| t.iter().map(|r| r.first().unwrap()).collect::<Vec<&bool>>() == to_match).unwrap()
+    possibilities.iter().find(|t| t.iter().map(|r| r.first().unwrap()).collect::<Vec<&bool>>() == to_match)
 }
 
-fn tile_by_down<'a>(up : &Tile, possibilities : &'a [Tile;8]) -> &'a Tile {
+fn tile_by_down<'a>(up : &Tile, possibilities : &'a [Tile;8]) -> Option<&'a Tile> {
     let to_match : &Vec<bool> = up.last().unwrap();
-    possibilities.iter().find(|t| t.first().unwrap() == to_match).unwrap()
+    possibilities.iter().find(|t| t.first().unwrap() == to_match)
 }
 
 
-fn reconstruct_image(ids : &Vec<usize>, matches_id : &Vec<usize>, tiles: &[(usize,[Tile;8])]) -> Tile {
+// `None` when the tiles cannot be put together
+fn reconstruct_image(ids : &[usize], matches_id : &[usize], tiles: &[(usize,[Tile;8])]) -> Option<Tile> {
     let dimension = (tiles.len() as f64).sqrt() as usize;
     let adjacencies : Vec<(usize,Vec<usize>)> = ids.iter().map(|id| (*id,matches_id.iter().filter(|id_m| **id_m % id == 0).map(|id_m| id_m / id).collect())).collect();
     // 1st step, place the ids to satisfy the adjacency constraints
     // `image_ids` could be defined with slices, but their dimension is not dynamic
     let mut image_ids : Vec<Vec<Option<usize>>> = vec![vec![None;dimension];dimension];
-    let corner1_id : usize = adjacencies.iter().find(|(_id,adjacents)| adjacents.len() == 2).unwrap().0;
+    let corner1_id : usize = adjacencies.iter().find(|(_id,adjacents)| adjacents.len() == 2)?.0;
     image_ids[0][0] = Some(corner1_id);
     for x in 0..dimension {
         for y in 0..dimension {
-            if image_ids[x][y] == None {
+            if image_ids[x][y].is_none() {
                 let mut adjacent : Vec<Option<usize>> = Vec::new();
-                for (x1,y1) in &[(x,y-1), (x,y+1), (x+1,y), (x-1,y)] {
+                for (x1,y1) in &[(x,y.wrapping_sub(1)), (x,y+1), (x+1,y), (x.wrapping_sub(1),y)] {
                     match image_ids.get(*x1) {
                         None => {},
                         Some(row) => match row.get(*y1) {
@@ -86,22 +87,22 @@ fn reconstruct_image(ids : &Vec<usize>, matches_id : &Vec<usize>, tiles: &[(usiz
                         None => true,
                         Some(id_n) => adjs.contains(id_n)
                     }
-                } && adjs.len() == adjacent.len() && !image_ids.iter().flatten().collect::<Vec<&Option<usize>>>().contains(&&Some(*id)))).unwrap().0;
+                } && adjs.len() == adjacent.len() && !image_ids.iter().flatten().collect::<Vec<&Option<usize>>>().contains(&&Some(*id))))?.0;
                 image_ids[x][y] = Some(next_tile);
             }
         }
     }
     // 2nd step, place the tiles correctly flipped & rotated
     let mut image_tiles : Vec<Vec<Option<&Tile>>> = vec![vec![None;dimension];dimension];
-    image_tiles[0][0] = Some(&tiles.iter().find(|(t_id,_)| t_id == &image_ids[0][0].unwrap()).unwrap().1[0]); // FIXME selection of the first tile (it may not be oriented), if not the program will fail finding the adjacent tile
+    image_tiles[0][0] = Some(&tiles.iter().find(|(t_id,_)| t_id == &image_ids[0][0].unwrap())?.1[0]); // FIXME selection of the first tile (it may not be oriented), if not the program will fail finding the adjacent tile
     for x in 0..dimension {
         for y in 0..dimension {
-            if image_tiles[x][y] == None {
+            if image_tiles[x][y].is_none() {
                 let id = image_ids[x][y].unwrap();
-                let possibilities = &tiles.iter().find(|(t_id,_)| t_id == &id).unwrap().1;
+                let possibilities = &tiles.iter().find(|(t_id,_)| t_id == &id)?.1;
                 image_tiles[x][y] = Some(
-                    if y > 0 { tile_by_right(image_tiles[x][y-1].unwrap(), possibilities) }
-                    else { tile_by_down(image_tiles[x-1][y].unwrap(), possibilities) })
+                    if y > 0 { tile_by_right(image_tiles[x][y-1].unwrap(), possibilities)? }
+                    else { tile_by_down(image_tiles[x-1][y].unwrap(), possibilities)? })
             }
         }
     };    
@@ -119,25 +120,24 @@ fn reconstruct_image(ids : &Vec<usize>, matches_id : &Vec<usize>, tiles: &[(usiz
     }).collect();
 
     let (image, rest) = image_div.split_first_mut().unwrap();
-    for x in 0..rest.len() {
-        image.append(&mut rest[x]);
-    }    
-    image.to_vec()
+    for row in rest {
+        image.append(row);
+    }
+    Some(image.to_vec())
 }
 
-fn find_pattern(image : &mut Tile) {
+// The rough water left once the sea monsters are found, if any are
+fn find_pattern(image : &mut Tile) -> Option<usize> {
     let mut found = false;
-    for x in 0..image.len()-PATTERN_HEIGHT {
-        for y in 0..image[x].len()-PATTERN_WIDTH {
+    for x in 0..image.len().saturating_sub(PATTERN_HEIGHT) {
+        for y in 0..image[x].len().saturating_sub(PATTERN_WIDTH) {
             if PATTERN.iter().all(|(x1,y1)| image[x+x1][y+y1]) {
                 found = true;
                 for (x1,y1) in PATTERN.iter() { image[x+x1][y+y1] = false }
             }
         }
     }
-    if found {
-        println!("{}", image.iter().flatten().filter(|x| **x).count());
-    }
+    found.then(|| image.iter().flatten().filter(|x| **x).count())
 }
 
 fn obtain_transformations(tile : Tile) -> [Tile;8] {
@@ -152,21 +152,52 @@ fn obtain_transformations(tile : Tile) -> [Tile;8] {
     [tile, rot_1, rot_2, rot_3, flip_0, flip_1, flip_2, flip_3]
 }
 
-fn main () {
-    let r = fs::read_to_string("input").unwrap();
+pub struct Day20;
+
+impl Solution for Day20 {
     // 4 rotations included
-    let tiles : Vec<(usize,[Tile;8])> = r.split("\n\n").map(|tile| {
-        let mut it = tile.lines();
-        let id_tile = it.next().unwrap().split_whitespace().skip(1).next().unwrap()[..4].parse::<usize>().unwrap();
-        let tile : Tile = it.map(|l| l.chars().map(|c| c == '#').collect()).collect();
-        (id_tile, obtain_transformations(tile))
-    }).collect();
-    // A single `usize` with the product of IDs, because ids are prime numbers, instead of a tuple, this search facilitates the unidirectionality
-    let m : Vec<usize> = matches(tiles.as_slice());
-    let ids = tiles.iter().map(|(id,_)| *id).collect();
-    let adjs = tile_adjacents(&ids,&m);
-    let corners = adjs.iter().filter(|(_,n)| n == &2).map(|(id,_)| id);
-    println!("{:?}", corners.product::<usize>());
-    let image = reconstruct_image(&ids, &m, tiles.as_slice());
-    for t in obtain_transformations(image).iter() {find_pattern(&mut t.to_vec());}
+    type Input = Vec<(usize,[Tile;8])>;
+
+    fn parse(input : &str) -> Result<Vec<(usize,[Tile;8])>, ParseError> {
+        let mut size = None;
+        let tiles : Vec<(usize,[Tile;8])> = input.split("\n\n").filter(|tile| !tile.trim().is_empty()).map(|tile| {
+            let mut it = tile.lines();
+            let header = it.next().unwrap_or(tile);
+            let id = header.strip_prefix("Tile ").and_then(|h| h.strip_suffix(':')).ok_or_else(|| ParseError::within(input, header, "Tile N:"))?;
+            let id_tile = aoc::parse_at(input, id, "a tile id")?;
+            let rows : Vec<&str> = it.collect();
+            // Every tile is a square of the same side, with an inside left once the borders go
+            let side = *size.get_or_insert(rows.len());
+            if side < 3 || rows.len() != side {
+                return Err(ParseError::within(input, header, format!("a tile of {} rows", side.max(3))));
+            }
+            if let Some(row) = rows.iter().find(|r| r.len() != side || !r.chars().all(|c| c == '#' || c == '.')) {
+                return Err(ParseError::within(input, row, format!("{side} of # and .")));
+            }
+            let tile : Tile = rows.iter().map(|l| l.chars().map(|c| c == '#').collect()).collect();
+            Ok((id_tile, obtain_transformations(tile)))
+        }).collect::<Result<_,_>>()?;
+        let dimension = (tiles.len() as f64).sqrt() as usize;
+        if dimension == 0 || dimension*dimension != tiles.len() {
+            return Err(ParseError::new("a square number of tiles"));
+        }
+        Ok(tiles)
+    }
+
+    fn part1(tiles : &Vec<(usize,[Tile;8])>) -> Answer {
+        // A single `usize` with the product of IDs, because ids are prime numbers, instead of a tuple, this search facilitates the unidirectionality
+        let m : Vec<usize> = matches(tiles.as_slice());
+        let ids : Vec<usize> = tiles.iter().map(|(id,_)| *id).collect();
+        let adjs = tile_adjacents(&ids,&m);
+        let corners : Vec<usize> = adjs.iter().filter(|(_,n)| n == &2).map(|(id,_)| *id).collect();
+        if corners.len() != 4 { return Answer::NoAnswer }
+        corners.iter().product::<usize>().into()
+    }
+
+    fn part2(tiles : &Vec<(usize,[Tile;8])>) -> Answer {
+        let m : Vec<usize> = matches(tiles.as_slice());
+        let ids : Vec<usize> = tiles.iter().map(|(id,_)| *id).collect();
+        let Some(image) = reconstruct_image(&ids, &m, tiles.as_slice()) else { return Answer::NoAnswer };
+        obtain_transformations(image).iter().find_map(|t| find_pattern(&mut t.to_vec())).map_or(Answer::NoAnswer, Answer::from)
+    }
 }
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
+
+type Food = (Vec<String>,Vec<String>);
+
+fn intersect_all<'a>(l : &Vec<&'a Vec<String>>) -> Vec<&'a String> {
+    let (first, rest) = l.split_first().unwrap();
+    let mut intersection : Vec<&String> = first.iter().collect();
+    for x in rest {
+        intersection = intersection.iter().filter(|e| x.contains(e)).copied().collect();
+    }
+    intersection
+}
+
+// The foods in which each allergen is listed
+fn by_allergen(foods : &Vec<Food>) -> HashMap<&String,Vec<&Vec<String>>> {
+    let mut m : HashMap<&String,Vec<&Vec<String>>> = HashMap::new();
+    for (ingredients,l_allergens) in foods {
+        for allergen in l_allergens {
+            m.entry(allergen).or_default().push(ingredients);
+        }
+    }
+    m
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+
+    fn parse(input : &str) -> Result<Vec<Food>, ParseError> {
+        input.lines().map(|l| {
+            let (ingredients, allergens) = l.split_once("(contains").ok_or_else(|| ParseError::within(input, l, "ingredients (contains allergens)"))?;
+            let ingredients = ingredients.split_whitespace().map(String::from).collect();
+            let allergens = allergens.strip_suffix(')').ok_or_else(|| ParseError::within(input, l, "a closing parenthesis"))?;
+            Ok((ingredients, allergens.split(',').map(|al| al.trim().to_string()).collect()))
+        }).collect()
+    }
+
+    fn part1(foods : &Vec<Food>) -> Answer {
+        let m = by_allergen(foods);
+        let mut allergens : Vec<&String> = m.values().flat_map(|l| intersect_all(l)).collect();
+        allergens.sort();
+        allergens.dedup();
+        let not_listed : usize = foods.iter().map(|(ingredients,_)| ingredients.iter().filter(|i| !allergens.contains(i)).count()).sum();
+        not_listed.into()
+    }
+
+    fn part2(foods : &Vec<Food>) -> Answer {
+        let m = by_allergen(foods);
+        let mut allergens : Vec<(&String,Vec<&String>)> = m.iter().map(|(a,l)| (*a,intersect_all(l))).collect();
+        // We sort it to ensure all the elements with a single match are added
+        allergens.sort_by(|(_i1,c1),(_i2,c2)| c1.len().partial_cmp(&c2.len()).unwrap()); // Sort by length
+        let mut allergens_unique : Vec<(&String,&String)> = Vec::new();
+        while allergens_unique.len() != allergens.len() {
+            for (a,l) in &allergens {
+                if allergens_unique.iter().any(|(b,_)| a == b) { continue; }
+                // Only an allergen left with a single candidate is settled
+                let mut free = l.iter().filter(|i| allergens_unique.iter().filter(|(_,j)| *i==j).count() == 0);
+                if let (Some(i), None) = (free.next(), free.next()) { allergens_unique.push((a,i)) }
+            }
+        }
+        allergens_unique.sort();
+        // The dangerous ingredients, by allergen
+        allergens_unique.iter().map(|(_,i)| i.as_str()).collect::<Vec<&str>>().join(",").into()
+    }
+}
@@ -0,0 +1,59 @@
+use aoc::cycle;
+use aoc::{Answer, ParseError, Solution};
+
+
+fn game1(mut player1 : Vec<usize>, mut player2 : Vec<usize>) -> (bool,Vec<usize>) {
+    while !player1.is_empty() && !player2.is_empty() {
+        let a = player1[0]; player1.remove(0);
+        let b = player2[0]; player2.remove(0);
+        if a > b { player1.push(a); player1.push(b); }
+        else { player2.push(b); player2.push(a); }
+    }
+    let mut winner = if player1.is_empty() {(false,player2)} else {(true,player1)};
+    winner.1.reverse();
+    winner
+}
+
+fn game2(player1 : Vec<usize>, player2 : Vec<usize>) -> (bool,Vec<usize>) {
+    let rounds = cycle::hashed((player1,player2), |(player1,player2)| {
+        let (&a, player1) = player1.split_first()?;
+        let (&b, player2) = player2.split_first()?;
+        let who_wins = if (player1.len() >= a) && (player2.len() >= b) {
+            game2(player1[0..a].to_vec(), player2[0..b].to_vec()).0
+        } else { a > b };
+        let (mut player1, mut player2) = (player1.to_vec(), player2.to_vec());
+        if who_wins { player1.push(a); player1.push(b); }
+        else { player2.push(b); player2.push(a); }
+        Some((player1,player2))
+    });
+    // Decks seen before end the game for player 1
+    if rounds.cycle().is_some() { return (true,Vec::new()) }
+    let (player1,player2) = rounds.states().last().unwrap().clone();
+    let mut winner = if player1.is_empty() {(false,player2)} else {(true,player1)};
+    winner.1.reverse();
+    winner
+}
+
+fn score((_, deck) : (bool,Vec<usize>)) -> usize {
+    deck.iter().enumerate().map(|(i,x)| x*(i+1)).sum::<usize>()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(input : &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+        let mut players = input.split("\n\n");
+        let mut deck = |player| players.next().ok_or_else(|| ParseError::new(format!("the deck of player {}", player)))?.lines().skip(1).map(|x| aoc::parse_at(input, x, "a card")).collect::<Result<Vec<usize>,_>>();
+        Ok((deck(1)?, deck(2)?))
+    }
+
+    fn part1((player1, player2) : &(Vec<usize>, Vec<usize>)) -> Answer {
+        score(game1(player1.clone(), player2.clone())).into()
+    }
+
+    fn part2((player1, player2) : &(Vec<usize>, Vec<usize>)) -> Answer {
+        score(game2(player1.clone(), player2.clone())).into()
+    }
+}
@@ -0,0 +1,76 @@
+use aoc::{Answer, ParseError, Solution};
+
+
+// Data structure: position i indicates node with label i+1, data indicates the next
+fn move_cups(cups: &mut [usize], current : usize, dimension : usize) -> (&mut [usize], usize) {
+    let e1 = cups[current];
+    let e2 = cups[e1];
+    let e3 = cups[e2];
+    let inbounds = |x: usize, y : usize| {let a = x.wrapping_sub(y); if a > dimension {a.wrapping_add(dimension-1)} else {a}};
+    let not_seq = |x : usize| x != e1 && x != e2 && x != e3;
+    let next = cups[e3];
+
+    let to_place : usize = {
+        let prev : [usize;4] = [inbounds(current,1),inbounds(current,2),inbounds(current,3),inbounds(current,4)];
+        if not_seq(prev[0]) {prev[0]}
+        else if not_seq(prev[1]) {prev[1]}
+        else if not_seq(prev[2]) {prev[2]} else {prev[3]}};
+
+    let jump = cups[to_place];
+    cups[to_place] = e1;
+    cups[e3] = jump;
+    cups[current] = next;
+    
+    (cups, next)
+}
+
+// Links label i+1 to the label after it, the last cup closing the circle on the first
+fn links(cups : &[usize], dimension : usize) -> Vec<usize> {
+    let mut n : Vec<usize> = (0..dimension).map(|i| i+1).collect();
+    for (src,dst) in cups.iter().zip(&cups[1..]) { n[src-1] = dst-1; }
+    if dimension > cups.len() {
+        n[cups[cups.len()-1]-1] = cups.len();
+        n[dimension-1] = cups[0]-1;
+    } else {
+        n[cups[cups.len()-1]-1] = cups[0]-1;
+    }
+    n
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<usize>;
+
+    fn parse(input : &str) -> Result<Vec<usize>, ParseError> {
+        let cups = input.trim().chars().map(|e| e.to_digit(10).map(|d| d as usize).ok_or_else(|| ParseError::within(input, input.trim(), "cup labels"))).collect::<Result<Vec<usize>,_>>()?;
+        let mut sorted = cups.clone();
+        sorted.sort();
+        if sorted != (1..=9).collect::<Vec<usize>>() { return Err(ParseError::within(input, input.trim(), "the labels 1 to 9, each once")); }
+        Ok(cups)
+    }
+
+    fn part1(cups : &Vec<usize>) -> Answer {
+        let mut n = links(cups, cups.len());
+        let star1 : &mut [usize] = &mut n;
+        let c1 = (0..100).fold((star1, cups[0]-1), |(v,current),_| move_cups(v,current,10));
+        let star1 = c1.0;
+        let mut labels = String::new();
+        let mut current_index = star1[0];
+        while current_index != 0 {
+            labels.push_str(&(current_index+1).to_string());
+            current_index = star1[current_index];
+        }
+        labels.into()
+    }
+
+    fn part2(cups : &Vec<usize>) -> Answer {
+        let dimension = 1_000_000;
+        let mut n2 = links(cups, dimension);
+        let star2 : &mut [usize] = &mut n2;
+        let c2 = (0..10_000_000).fold((star2, cups[0]-1), |(v,current),_| move_cups(v,current,dimension+1));
+        let n1 = c2.0[0];
+        let n2 = c2.0[n1];
+        ((n1+1)*(n2+1)).into()
+    }
+}
@@ -0,0 +1,80 @@
+
+use std::collections::HashSet;
+use aoc::automaton::{self, Automaton};
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Dir {
+    East, SouthEast, NorthEast,
+    West, SouthWest, NorthWest
+}
+
+fn coord(v: &Dir) -> (isize, isize) {
+    match v {
+        Dir::East => (1, 0),
+        Dir::West => (-1, 0),
+        Dir::NorthEast => (0, -1),
+        Dir::NorthWest => (-1, -1),
+        Dir::SouthEast => (1, 1),
+        Dir::SouthWest => (0, 1),
+    }
+}
+
+fn flip(floor_blacks: &HashSet<[i64;2]>) -> HashSet<[i64;2]> {
+    automaton::sparse(floor_blacks, automaton::hex, |black,adj_black| {
+        if black { adj_black == 1 || adj_black == 2 } else { adj_black == 2 }
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = HashSet<[i64;2]>;
+
+    fn parse(input : &str) -> Result<HashSet<[i64;2]>, ParseError> {
+        let t = input.lines().map(|l| {
+            let bad = || ParseError::within(input, l, "directions e, se, sw, w, nw and ne");
+            let (tiles, ns) = l.chars().try_fold((Vec::new(),None), |(mut tiles,mut ns),c| {
+                match ns {
+                    None => {match c {
+                        'e' => tiles.push(Dir::East),
+                        'w' => tiles.push(Dir::West),
+                        'n' => ns = Some(true),
+                        's' => ns = Some(false),
+                        _ => return Err(bad())
+                    }; Ok((tiles,ns))},
+                    Some(true) => {tiles.push(match c {
+                        'e' => Dir::NorthEast,
+                        'w' => Dir::NorthWest,
+                        _ => return Err(bad())
+                    }); Ok((tiles,None))},
+                    Some(false) => {tiles.push(match c {
+                        'e' => Dir::SouthEast,
+                        'w' => Dir::SouthWest,
+                        _ => return Err(bad())
+                    }); Ok((tiles,None))},
+                }
+            })?;
+            if ns.is_some() { return Err(bad()); }
+            Ok(tiles)
+        }).collect::<Result<Vec<Vec<Dir>>,_>>()?;
+
+        let tiles_coords = t.iter().map(|dirs| {
+            dirs.iter().fold([0, 0], |[cx, cy], d| {
+                let (sx, sy) = coord(d);
+                [cx + sx as i64, cy + sy as i64]
+            })});
+
+        let mut blacks: HashSet<[i64;2]> = HashSet::new();
+        for c in tiles_coords { if !blacks.remove(&c) { blacks.insert(c); } }
+        Ok(blacks)
+    }
+
+    fn part1(blacks : &HashSet<[i64;2]>) -> Answer {
+        blacks.len().into()
+    }
+
+    fn part2(blacks : &HashSet<[i64;2]>) -> Answer {
+        Automaton::new(blacks.clone(), flip).run(100).len().into()
+    }
+}
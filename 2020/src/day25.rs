@@ -0,0 +1,40 @@
+use aoc::{Answer, ParseError, Solution};
+
+
+fn retrieve_loop_size(pk : usize) -> usize {
+    let subject_number = 7;
+    let mut value : usize = 1;
+    let mut loop_size = 0;
+    while value != pk {
+        value = (value*subject_number) % 20201227;
+        loop_size += 1;
+    }
+    loop_size
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (usize, usize);
+
+    fn parse(input : &str) -> Result<(usize, usize), ParseError> {
+        let n: Vec<usize> = input.lines().map(|x| aoc::parse_at(input, x, "a public key")).collect::<Result<_,_>>()?;
+        match n[..] {
+            [door_pk, card_pk] => Ok((door_pk, card_pk)),
+            _ => Err(ParseError::new("the door and card public keys"))
+        }
+    }
+
+    fn part1(&(door_pk, card_pk) : &(usize, usize)) -> Answer {
+        let door_sk = retrieve_loop_size(door_pk);
+        // let card_sk = retrieve_loop_size(card_pk);
+        let encryption_key = (0..door_sk).fold(1,|value,_| (value*card_pk) % 20201227);
+        // let encryption_key = (0..card_sk).fold(1,|value,_| (value*door_pk) % 20201227);
+        encryption_key.into()
+    }
+
+    // The last day has a single puzzle
+    fn part2(_ : &(usize, usize)) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,31 @@
+use aoc::example;
+use aoc2020::*;
+
+example!(day01: day01::Day01, "day01.txt", part1 = 514579, part2 = 241861950);
+example!(day02: day02::Day02, "day02.txt", part1 = 2, part2 = 1);
+example!(day03: day03::Day03, "day03.txt", part1 = 7, part2 = 336);
+example!(day04: day04::Day04, "day04.txt", part1 = 2);
+example!(day04_validation: day04::Day04, "day04-2.txt", part2 = 4);
+example!(day05: day05::Day05, "day05.txt", part1 = 820);
+example!(day06: day06::Day06, "day06.txt", part1 = 11, part2 = 6);
+example!(day07: day07::Day07, "day07.txt", part1 = 4, part2 = 32);
+example!(day07_nested: day07::Day07, "day07-2.txt", part2 = 126);
+example!(day08: day08::Day08, "day08.txt", part1 = 5, part2 = 8);
+example!(day09: day09::Day09, "day09.txt", part1 = 127, part2 = 62);
+example!(day10: day10::Day10, "day10.txt", part1 = 35, part2 = 8);
+example!(day10_larger: day10::Day10, "day10-2.txt", part1 = 220, part2 = 19208);
+example!(day11: day11::Day11, "day11.txt", part1 = 37, part2 = 26);
+example!(day12: day12::Day12, "day12.txt", part1 = 25, part2 = 286);
+example!(day13: day13::Day13, "day13.txt", part1 = 295, part2 = 1068781);
+example!(day14: day14::Day14, "day14.txt", part1 = 165);
+example!(day14_floating: day14::Day14, "day14-2.txt", part2 = 208);
+example!(day15: day15::Day15, "day15.txt", part1 = 436);
+example!(day16: day16::Day16, "day16.txt", part1 = 71);
+example!(day17: day17::Day17, "day17.txt", part1 = 112, part2 = 848);
+example!(day18: day18::Day18, "day18.txt", part1 = 26457, part2 = 694173);
+example!(day19: day19::Day19, "day19.txt", part1 = 3, part2 = 12);
+example!(day21: day21::Day21, "day21.txt", part1 = 5, part2 = "mxmxvkd,sqjhc,fvjkl");
+example!(day22: day22::Day22, "day22.txt", part1 = 306, part2 = 291);
+example!(day23: day23::Day23, "day23.txt", part1 = "67384529", part2 = 149245887792u64);
+example!(day24: day24::Day24, "day24.txt", part1 = 10, part2 = 2208);
+example!(day25: day25::Day25, "day25.txt", part1 = 14897079);
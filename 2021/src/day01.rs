@@ -0,0 +1,27 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(l : &Vec<u64>) -> usize {
+    l.iter().skip(1).zip(l).filter(|(a,b)| a>b).count()
+}
+
+fn star2(l : &Vec<u64>) -> usize {
+    l.iter().skip(3).zip(l).filter(|(a,b)| a>b).count()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+
+    fn parse(input : &str) -> Result<Vec<u64>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| aoc::parse_at(input, l, "a depth")).collect()
+    }
+
+    fn part1(l : &Vec<u64>) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Vec<u64>) -> Answer {
+        star2(l).into()
+    }
+}
@@ -0,0 +1,52 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(l : &[(i8,i64)]) -> i64 {
+    let (hor,ver):(Vec<&(i8,i64)>,Vec<_>)= l.iter().partition(|(n,_f)| *n == 0);
+    let hor_adv : i64 = hor.iter().map(|x| x.1).sum();
+    let ver_adv : i64 = ver.iter().map(|x| x.1*(x.0 as i64)).sum();
+    (hor_adv*ver_adv).abs()
+}
+
+fn star2(l : &[(i8,i64)]) -> i64 {
+    let (hor,depth,_aim) : (i64,i64,i64) = l.iter().fold(
+        (0,0,0), |(h,d,a),(p,n)| {
+            match p {
+                -1 => (h,d,a+n),
+                1 => (h,d,a-n),
+                0 => (h+n,d+a*n,a),
+                _ => (h,d,a)
+            }           
+        }
+    );
+    (hor*depth).abs()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(i8,i64)>;
+
+    fn parse(input : &str) -> Result<Vec<(i8,i64)>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let n : Vec<&str> = l.split_whitespace().collect();
+            let [command, units] = n[..] else {
+                return Err(ParseError::within(input, l, "a command and a number of units"));
+            };
+            let dir : i8 = match command {
+                "forward" => 0,
+                "up" => 1,
+                "down" => -1,
+                _ => return Err(ParseError::within(input, command, "forward, up or down"))
+            };
+            Ok((dir, aoc::parse_at(input, units, "a number of units")?))
+        }).collect()
+    }
+
+    fn part1(l : &Vec<(i8,i64)>) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Vec<(i8,i64)>) -> Answer {
+        star2(l).into()
+    }
+}
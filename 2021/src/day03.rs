@@ -1,31 +1,31 @@
-#![feature(drain_filter)]
+use aoc::{Answer, ParseError, Solution};
 
 fn bti(b:bool) -> i16 {if b {1} else {-1}}
 
-fn star1(l : &Vec<Vec<bool>>) {
+fn star1(l : &[Vec<bool>]) -> usize {
     let gamma : Vec<char> = l.iter().map(|e| {
         if e.iter().map(|b| bti(*b)).sum::<i16>() >= 0 {'1'} else {'0'}
     }).collect();
     let epsilon : Vec<char> = gamma.iter().map(|b| if *b == '1' {'0'} else {'1'}).collect();
     let g = usize::from_str_radix(&gamma.into_iter().collect::<String>(), 2).unwrap();
     let e = usize::from_str_radix(&epsilon.into_iter().collect::<String>(), 2).unwrap();
-    println!("{}", g*e);
+    g*e
 }
 
-fn get_common(l : &Vec<Vec<bool>>, sign: fn(i16) -> bool) -> usize {
-    let mut p : Vec<Vec<bool>> = l.clone();
+fn get_common(l : &[Vec<bool>], sign: fn(i16) -> bool) -> usize {
+    let mut p : Vec<Vec<bool>> = l.to_vec();
     for i in 0..l.len() {
         if p.len() == 1 {break}
         let common_bit = sign(p.iter().map(|e| bti(e[i])).sum::<i16>());
-        p = p.drain_filter(|e| e[i] == common_bit).collect();
+        p.retain(|e| e[i] == common_bit);
     }
     usize::from_str_radix(&p[0].iter().map(|b| if *b {'1'} else {'0'}).collect::<String>(), 2).unwrap()
 }
 
-fn star2(l : &Vec<Vec<bool>>) {
+fn star2(l : &[Vec<bool>]) -> usize {
     let oxygen = get_common(l, |x:i16| x>=0);
     let co2 = get_common(l, |x:i16| x<0);
-    println!("{}", oxygen*co2);
+    oxygen*co2
 }
 
 fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
@@ -42,16 +42,27 @@ fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
         .collect()
 }
 
+pub struct Day03;
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Vec<Vec<bool>> = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if l.len() > 0 {Some(l.chars().map(|x| x == '1').collect())} else {None}
-    }).collect();
-    star1(&transpose(l.clone()));
-    star2(&l);
+impl Solution for Day03 {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(input : &str) -> Result<Vec<Vec<bool>>, ParseError> {
+        let l : Vec<Vec<bool>> = input.lines().filter(|l| !l.is_empty()).map(|l| {
+            if l.chars().all(|x| x == '0' || x == '1') { Ok(l.chars().map(|x| x == '1').collect()) }
+            else { Err(ParseError::within(input, l, "a binary number")) }
+        }).collect::<Result<_,_>>()?;
+        match l.first() {
+            Some(first) if l.iter().all(|e| e.len() == first.len()) => Ok(l),
+            _ => Err(ParseError::new("binary numbers of the same length")),
+        }
+    }
+
+    fn part1(l : &Vec<Vec<bool>>) -> Answer {
+        star1(&transpose(l.clone())).into()
+    }
+
+    fn part2(l : &Vec<Vec<bool>>) -> Answer {
+        star2(l).into()
+    }
 }
@@ -0,0 +1,84 @@
+use aoc::{Answer, ParseError, Solution};
+
+type Line = Vec<(usize,bool)>;
+type Board = Vec<Line>;
+type Bingo = (Vec<usize>, Vec<Board>);
+
+fn cross(b : &mut Board, n : usize) {
+    for l in b {
+        for e in l {
+            if e.0 == n {
+                e.1 = true; }}}
+}
+
+fn win_board(b : &Board) -> bool { b.iter().any(|l| { l.iter().all(|e| e.1) }) }
+
+// The scores of the first and of the last board to win
+fn stars((said, t) : &Bingo) -> (Option<usize>, Option<usize>) {
+    let mut first = None;
+    let mut tables : Vec<Board> = t.clone();
+    let mut completed : Vec<bool> = vec![false; tables.len()];
+    for x in said {
+        for i in 0..tables.len() {
+            let b = tables.get_mut(i).unwrap();
+            cross(b, *x);
+            if completed[i] || win_board(b) || win_board(&transpose(b.clone())) {
+                if completed.iter().all(|x| !*x) {
+                    let board_sum = b.iter().map(|l2| l2.iter().map(|e| if !e.1 {e.0} else {0}).sum::<usize>()).sum::<usize>();
+                    first = Some(board_sum*x);
+                }
+                completed[i] = true;
+                if completed.iter().all(|x| *x) {
+                    let board_sum = b.iter().map(|l2| l2.iter().map(|e| if !e.1 {e.0} else {0}).sum::<usize>()).sum::<usize>();
+                    return (first, Some(board_sum*x));
+                }}}}
+    (first, None)
+}
+
+fn parse_line(s: &str, del: char) -> Vec<usize> {
+    s.split(del).filter_map(|n| n.parse().ok()).collect()
+}
+
+
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    assert!(!v.is_empty());
+    let len = v[0].len();
+    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().unwrap())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+
+    fn parse(input : &str) -> Result<Bingo, ParseError> {
+        let mut components = input.split("\n\n");
+        let nums : Vec<usize> = parse_line(components.next().unwrap_or_default(), ',');
+        let boards : Vec<Board> = components.map(|b| {b.lines().map(|line| {
+            parse_line(line, ' ').iter().map(|x| (*x,false)).collect()
+        }).collect()}).collect();
+        if nums.is_empty() { return Err(ParseError::new("the numbers drawn")); }
+        // Square boards, so that their columns can be read by transposing them
+        match boards.iter().all(|b : &Board| !b.is_empty() && b.iter().all(|l| l.len() == b.len())) {
+            true => Ok((nums, boards)),
+            false => Err(ParseError::new("square boards of numbers")),
+        }
+    }
+
+    fn part1(b : &Bingo) -> Answer {
+        stars(b).0.map_or(Answer::NoAnswer, Answer::from)
+    }
+
+    fn part2(b : &Bingo) -> Answer {
+        stars(b).1.map_or(Answer::NoAnswer, Answer::from)
+    }
+}
@@ -0,0 +1,76 @@
+use aoc::{Answer, ParseError, Solution};
+use aoc::trace::Level;
+
+type Point = (usize,usize);
+type Segment = (Point,Point);
+type Grid = Vec<Vec<usize>>;
+
+fn trace_grid(grid : &Grid) {
+    for l in grid {
+        aoc::trace!("{:?}",l.iter().map(|e| match e {
+            0 => ".".to_string(),
+            n => (*n).to_string()
+        }).collect::<Vec<String>>())
+    }
+}
+
+// The points where lines overlap, counting the straight lines only and then all of them
+fn stars(s:&[Segment]) -> (usize, usize) {
+    let (straight, diagonal) : (Vec<&Segment>,Vec<&Segment>) = s.iter().partition(|x| x.0.0 == x.1.0 || x.0.1 == x.1.1 );
+    let max_h : usize = s.iter().map(|x| x.0.0.max(x.1.0)).max().unwrap();
+    let max_v : usize = s.iter().map(|x| x.0.1.max(x.1.1)).max().unwrap();
+    let mut grid : Grid = vec![vec![0;max_v+1];max_h+1];
+    for (i,e) in straight {
+        if i.0 == e.0 {
+            let min = i.1.min(e.1);
+            let max = i.1.max(e.1);
+            for n in &mut grid[i.0][min..=max] {
+                *n += 1
+            }
+        } else if i.1 == e.1 {
+            let min = i.0.min(e.0);
+            let max = i.0.max(e.0);
+            for column in &mut grid[min..=max] {
+                column[i.1] += 1
+            }
+        }
+    }
+    let straight_overlaps = grid.iter().flatten().filter(|x| **x>1).count();
+    for (i,e) in diagonal {
+        let magn = (e.0 as isize - i.0 as isize).abs();
+        let step : (isize, isize) = (if i.0 > e.0 {-1} else {1}, if i.1 > e.1 {-1} else {1});
+        for inc in 0..=magn {
+            let x = (i.0 as isize +step.0*inc) as usize;
+            let y = (i.1 as isize +step.1*inc) as usize;
+            grid[x][y] += 1;
+        }
+    }
+    if aoc::trace::enabled(Level::Trace, module_path!()) { trace_grid(&grid) }
+    (straight_overlaps, grid.iter().flatten().filter(|x| **x>1).count())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Segment>;
+
+    fn parse(input : &str) -> Result<Vec<Segment>, ParseError> {
+        if input.trim().is_empty() { return Err(ParseError::new("lines of vents")); }
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let els : Vec<Vec<usize>> = l.split("->").map(
+                |e| e.split(",").map(|x| aoc::parse_at(input, x.trim(), "a coordinate")).collect::<Result<_,_>>()).collect::<Result<_,_>>()?;
+            match &els[..] {
+                [i, e] if i.len() == 2 && e.len() == 2 => Ok(((i[0],i[1]),(e[0],e[1]))),
+                _ => Err(ParseError::within(input, l, "a line x1,y1 -> x2,y2"))
+            }
+        }).collect()
+    }
+
+    fn part1(s : &Vec<Segment>) -> Answer {
+        stars(s).0.into()
+    }
+
+    fn part2(s : &Vec<Segment>) -> Answer {
+        stars(s).1.into()
+    }
+}
@@ -0,0 +1,62 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn simulate_day(fishs :&mut Vec<usize>) {
+    for i in 0..fishs.len() {
+        match fishs[i] {
+            0 => {
+                fishs[i] = 6;
+                fishs.push(8);
+            },
+            n => fishs[i] = n-1
+        }   
+    }
+}
+    
+fn star1(s:&[usize]) -> usize {
+    let mut fishs = s.to_vec();
+    for _ in 0..80 {
+        simulate_day(&mut fishs);
+    }
+    fishs.len()
+}
+
+fn simulate_inv_day(inv_fishs : &mut [usize;9]) {
+    let new_fishes = inv_fishs[0];
+    for x in 1..9 {
+        inv_fishs[x-1] = inv_fishs[x];
+    }
+    inv_fishs[6] += new_fishes;
+    inv_fishs[8] = new_fishes;
+}
+
+fn star2(s:&[usize]) -> usize {
+    let mut inv_fishs : [usize;9] = [0;9];
+    for (x, n) in inv_fishs.iter_mut().enumerate() {
+        *n = s.iter().filter(|f| **f == x).count();
+    }
+    for _ in 0..256 {
+        simulate_inv_day(&mut inv_fishs);
+    }
+    inv_fishs.iter().sum::<usize>()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+
+    fn parse(input : &str) -> Result<Vec<usize>, ParseError> {
+        input.trim().split(',').map(|n| match aoc::parse_at(input, n.trim(), "a timer")? {
+            t @ 0..=8 => Ok(t),
+            _ => Err(ParseError::within(input, n.trim(), "a timer from 0 to 8"))
+        }).collect()
+    }
+
+    fn part1(s : &Vec<usize>) -> Answer {
+        star1(s).into()
+    }
+
+    fn part2(s : &Vec<usize>) -> Answer {
+        star2(s).into()
+    }
+}
@@ -0,0 +1,33 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn calculate_fuel_crab(pos: usize, s:&[usize], f: fn(usize) -> usize) -> usize {
+    s.iter().map(|x| {
+        let dist = (*x as isize - pos as isize).unsigned_abs();
+        f(dist)
+    }).sum()
+}
+
+fn star(s:&[usize], f: fn(usize) -> usize) -> usize {
+    let min : usize = *s.iter().min().unwrap();
+    let max : usize = *s.iter().max().unwrap();
+    (min..max).map(|p| calculate_fuel_crab(p, s, f)).min().unwrap_or(0)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<usize>;
+
+    fn parse(input : &str) -> Result<Vec<usize>, ParseError> {
+        input.trim().split(',').map(|n| aoc::parse_at(input, n.trim(), "a position")).collect()
+    }
+
+    fn part1(s : &Vec<usize>) -> Answer {
+        star(s, |x| x).into()
+    }
+
+    fn part2(s : &Vec<usize>) -> Answer {
+        // Gauss summation
+        star(s, |x| (x*(x+1))/2).into()
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use aoc::{Answer, ParseError, Solution};
+
+#[derive(Debug,Copy,Clone,PartialOrd,PartialEq,Eq,Hash)]
+pub enum Segment { A,B,C,D,E,F,G }
+type Wires = HashSet<Segment>;
+type Display = Vec<(Vec<Wires>,Vec<Wires>)>;
+
+fn star1(d : &Display) -> usize {
+    d.iter().map(|(_,s)| s.iter().filter(|c| matches!(c.len(), 2|3|4|7)).count()).sum::<usize>()
+}
+
+fn star2(d : &Display) -> Option<usize> {
+    d.iter().map(|(i,e)| {
+        let one = i.iter().find(|n| n.len() == 2)?;
+        let seven = i.iter().find(|n| n.len() == 3)?;
+        let four = i.iter().find(|n| n.len() == 4)?;
+        let eight = i.iter().find(|n| n.len() == 7)?;
+        let three = i.iter().find(|n| n.len() == 5 && seven.is_subset(n))?;
+        let nine = i.iter().find(|n| n.len() == 6 && four.is_subset(n))?;
+        let zero = i.iter().find(|n| n.len() == 6 && seven.is_subset(n) && n != &nine)?;
+        let six = i.iter().find(|n| n.len() == 6 && n != &nine && n != &zero)?;
+        let five = i.iter().find(|n| n.len() == 5 && n.is_subset(six))?;
+        let two = i.iter().find(|n| n.len() == 5 && n != &three && n != &five)?;
+        e.iter().map(|x| {
+            if x == one {'1'}
+            else if x == two { '2' }
+            else if x == three { '3' }
+            else if x == four { '4' }
+            else if x == five { '5' }
+            else if x == six { '6' }
+            else if x == seven { '7' }
+            else if x == eight { '8' }
+            else if x == nine { '9' }
+            else if x == zero { '0' }
+            else { ' ' }
+        }).collect::<String>().parse::<usize>().ok()
+    }).sum()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Display;
+
+    fn parse(input : &str) -> Result<Display, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let wires : Vec<Vec<Wires>> = l.split("|").map(|c| c.trim().split(" ").map(|w| {
+                w.chars().map(|ch| match ch {
+                    'a' => Ok(Segment::A),
+                    'b' => Ok(Segment::B),
+                    'c' => Ok(Segment::C),
+                    'd' => Ok(Segment::D),
+                    'e' => Ok(Segment::E),
+                    'f' => Ok(Segment::F),
+                    'g' => Ok(Segment::G),
+                    _ => Err(ParseError::within(input, w, "segments a to g"))
+                }).collect()
+            }).collect()).collect::<Result<_,_>>()?;
+            match &wires[..] {
+                [patterns, output] => Ok((patterns.clone(), output.clone())),
+                _ => Err(ParseError::within(input, l, "the signal patterns | the output value"))
+            }
+        }).collect()
+    }
+
+    fn part1(d : &Display) -> Answer {
+        star1(d).into()
+    }
+
+    fn part2(d : &Display) -> Answer {
+        star2(d).map_or(Answer::NoAnswer, Answer::from)
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+use aoc::{Answer, ParseError, Solution};
+
+fn adjs((i,j): (usize,usize), s : &[Vec<usize>]) -> Vec<(usize,usize)> {
+    let x_size = s.len();
+    let y_size = s[0].len();
+    [(i.wrapping_sub(1),j),(i,j.wrapping_sub(1)),(i,j+1),(i+1,j)].iter().filter(|(x,y)| *x < x_size && *y < y_size).copied().collect()
+}
+
+fn basin((i,j): (usize,usize), s : &Vec<Vec<usize>>) -> HashSet<(usize,usize)> {
+    let mut higher : HashSet<(usize,usize)> = HashSet::new();
+    let low = s[i][j];
+    for c in adjs((i,j), s).iter().filter(|c| { s[c.0][c.1] > low  && s[c.0][c.1] !=9 }) {
+        higher.insert(*c);
+        higher.extend(&basin(*c, s));
+    }
+    higher
+}
+
+fn low_points(s : &[Vec<usize>]) -> Vec<(usize,usize)> {
+    let mut low_points : Vec<(usize,usize)> = vec![];
+    for i in 0..s.len() {
+        for j in 0..s[i].len() {
+            let low = s[i][j];
+            if adjs((i,j), s).iter().all(|c| {s[c.0][c.1] > low}) {
+                low_points.push((i,j));
+            }
+        }
+    }
+    low_points
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(input : &str) -> Result<Vec<Vec<usize>>, ParseError> {
+        let s : Vec<Vec<usize>> = input.lines().filter(|l| !l.is_empty()).map(|l| {
+            l.chars().map(|n| n.to_digit(10).map(|i| i as usize).ok_or_else(|| ParseError::within(input, l, "heights from 0 to 9"))).collect()
+        }).collect::<Result<_,_>>()?;
+        match s.first() {
+            Some(first) if s.iter().all(|l| l.len() == first.len()) => Ok(s),
+            _ => Err(ParseError::new("rows of heights of the same length")),
+        }
+    }
+
+    fn part1(s : &Vec<Vec<usize>>) -> Answer {
+        low_points(s).iter().map(|x| s[x.0][x.1]+1).sum::<usize>().into()
+    }
+
+    fn part2(s : &Vec<Vec<usize>>) -> Answer {
+        let mut basins : Vec<usize> = low_points(s).iter().map(|p| basin(*p, s).len()+1).collect();
+        basins.sort_by(|a,b| b.cmp(a));
+        if basins.len() < 3 { return Answer::NoAnswer; }
+        basins[0..3].iter().product::<usize>().into()
+    }
+}
@@ -1,3 +1,5 @@
+use aoc::{Answer, ParseError, Solution};
+
 fn points(c : char) -> usize {
     match c {        
         ')' => 3,
@@ -55,43 +57,39 @@ fn value_line(l : &Vec<char>, pos : usize) -> Result<(usize,usize),usize> {
     }
 }
 
-fn stars(s : &Vec<String>) {
-    let values : Vec<Result<(usize,usize),usize>> = s.iter().map(
+fn values(s : &[String]) -> Vec<Result<(usize,usize),usize>> {
+    s.iter().map(
         |l| value_line(&l.chars().collect(), 0)
-    ).collect();
-    // We sum the punctuation of all malformed lines 
-    let star1 = values.iter().filter_map(|n| match n {
-        Err(i) => Some(i),
-        _ => None
-    });
-    println!("{:?}", star1.sum::<usize>());
-    // We get all incomplete lines and access the value in the middle
-    let mut star2 : Vec<usize> = values.iter().filter_map(|n| match n {
-        Ok((_a,b)) => Some(*b),
-        _ => None
-    }).collect();
-    star2.sort();
-    println!("{:?}", star2[star2.len()/2]);
+    ).collect()
 }
 
+pub struct Day10;
 
-fn parse_input(s : String) -> Vec<String> {
-    s.lines().filter_map(|l| {
-        let r = l.to_string();
-        match r.len() {
-            0 => None,
-            _ => Some(r)
-        }
-    }).collect()
-}
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input : &str) -> Result<Vec<String>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            match l.chars().all(|c| "([{<>}])".contains(c)) {
+                true => Ok(l.to_string()),
+                false => Err(ParseError::within(input, l, "a line of brackets"))
+            }
+        }).collect()
+    }
 
+    fn part1(s : &Vec<String>) -> Answer {
+        // We sum the punctuation of all malformed lines
+        let star1 = values(s).into_iter().filter_map(|n| n.err());
+        star1.sum::<usize>().into()
+    }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let s: Vec<String> = parse_input(std::fs::read_to_string(args[1].clone()).unwrap());
-    stars(&s);
+    fn part2(s : &Vec<String>) -> Answer {
+        // We get all incomplete lines and access the value in the middle
+        let mut star2 : Vec<usize> = values(s).iter().filter_map(|n| match n {
+            Ok((_a,b)) => Some(*b),
+            _ => None
+        }).collect();
+        star2.sort();
+        star2.get(star2.len()/2).map_or(Answer::NoAnswer, |m| (*m).into())
+    }
 }
@@ -0,0 +1,49 @@
+use aoc::automaton::Automaton;
+use aoc::grid::{Grid, Pos};
+use aoc::{Answer, ParseError, Solution};
+
+/// Every octopus gains a level, then those past 9 flash onto their neighbours, once each, and end at 0.
+fn step(octopuses : &Grid<u8>) -> Grid<u8> {
+    let mut next = octopuses.map(|c| c + 1);
+    let mut flashing : Vec<Pos> = next.positions().filter(|p| next[*p] > 9).collect();
+    while let Some(p) = flashing.pop() {
+        let around : Vec<Pos> = next.neighbours8(p).collect();
+        for q in around {
+            if next[q] <= 9 {
+                next[q] += 1;
+                if next[q] > 9 { flashing.push(q); }
+            }
+        }
+    }
+    next.map(|c| if *c > 9 { 0 } else { *c })
+}
+
+fn flashes(octopuses : &Grid<u8>) -> usize {
+    octopuses.values().filter(|c| **c == 0).count()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+
+    fn parse(input : &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|n| n as u8), "an energy level")
+    }
+
+    fn part1(s : &Grid<u8>) -> Answer {
+        let mut octopuses = Automaton::new(s.clone(), step);
+        let mut total = 0;
+        for _ in 0..100 {
+            total += flashes(octopuses.step());
+        }
+        total.into()
+    }
+
+    fn part2(s : &Grid<u8>) -> Answer {
+        let mut octopuses = Automaton::new(s.clone(), step);
+        let all = octopuses.state.width() * octopuses.state.height();
+        octopuses.run_until(|o| flashes(o) == all);
+        octopuses.generation.into()
+    }
+}
@@ -0,0 +1,72 @@
+use aoc::{Answer, ParseError, Solution};
+
+fn cont_to_end(s: &Vec<(String,String)>, current_path : Vec<&str>, current : &str) -> usize {
+    let mut paths = 0;
+    for (a,b) in s.iter().filter(|(a,b)| a == current || b == current) {
+        let next = if a==current {b.as_str()} else {a.as_str()};
+        if next == "end" {
+            paths += 1;
+        } else {
+            if !(next.chars().all(|c| c.is_lowercase()) && current_path.contains(&next)) {
+                let mut new_path = current_path.clone();
+                new_path.push(next);
+                paths += cont_to_end(s, new_path, next);
+            }
+        }
+    }
+    paths
+}
+
+fn star1(s: &Vec<(String,String)>) -> usize {
+    cont_to_end(s, vec!["start"], "start")
+}
+
+fn cont_to_end2(s: &Vec<(String,String)>, current_path : Vec<&str>, current : &str, chosen_twice : Option<&str>) -> usize {
+    let mut paths = 0;
+    for (a,b) in s.iter().filter(|(a,b)| a == current || b == current) {
+        let next = if a==current {b.as_str()} else {a.as_str()};
+        if next == "end" {
+            paths += 1;
+        } else {
+            if !(next.chars().all(|c| c.is_lowercase()) && current_path.contains(&next)) {
+                let mut new_path = current_path.clone();
+                new_path.push(next);
+                paths += cont_to_end2(s, new_path, next, chosen_twice);
+            } else {
+                if chosen_twice.is_none() && next != "start"  {
+                    let mut new_path = current_path.clone();
+                    new_path.push(next);
+                    paths += cont_to_end2(s, new_path, next, Some(next));
+                }
+            }
+        }
+    }
+    paths
+}
+
+fn star2(s: &Vec<(String,String)>) -> usize {
+    cont_to_end2(s, vec!["start"], "start", None)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(String,String)>;
+
+    fn parse(input : &str) -> Result<Vec<(String,String)>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            match l.split_once("-") {
+                Some((a, b)) => Ok((a.to_string(), b.to_string())),
+                None => Err(ParseError::within(input, l, "a connection cave-cave"))
+            }
+        }).collect()
+    }
+
+    fn part1(s : &Vec<(String,String)>) -> Answer {
+        star1(s).into()
+    }
+
+    fn part2(s : &Vec<(String,String)>) -> Answer {
+        star2(s).into()
+    }
+}
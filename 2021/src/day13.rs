@@ -0,0 +1,72 @@
+use aoc::grid::Grid;
+use aoc::{Answer, ParseError, Solution};
+
+type Fold = (bool,usize);
+type Point = (usize,usize);
+
+// The letters drawn by the dots, or the dots themselves if they cannot be read
+fn read_points(points : &Vec<Point>) -> Answer {
+    let width = points.iter().map(|(x,_)| x+1).max().unwrap_or(0);
+    let height = points.iter().map(|(_,y)| y+1).max().unwrap_or(0);
+    let mut paper = Grid::new(width, height, false);
+    for (x,y) in points { paper[(*x as isize,*y as isize)] = true; }
+    aoc::ocr::answer(&paper)
+}
+
+fn fold(points : &mut Vec<Point>, foldings : &[Fold]) {
+    for &(fold_x, n) in foldings {
+        for (x,y) in points.iter_mut() {
+            if fold_x {
+                if *x > n {
+                    *x = n - (*x - n)
+                }
+            } else {
+                if *y > n {
+                    *y = n - (*y - n)
+                }                
+            }
+        }
+    }
+    points.sort();
+    points.dedup();
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<Point>, Vec<Fold>);
+
+    fn parse(input : &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+        let (dots, instructions) = input.split_once("\n\n").ok_or_else(|| ParseError::new("dots, a blank line and fold instructions"))?;
+        let x = dots.lines().map(|l| {
+            let t : Vec<usize> = l.split(",").map(|n| aoc::parse_at(input, n, "a coordinate")).collect::<Result<_,_>>()?;
+            match t[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(ParseError::within(input, l, "a dot x,y"))
+            }
+        }).collect::<Result<_,_>>()?;
+        let y = instructions.lines().filter(|l| !l.is_empty()).map(|l| {
+            let t : &str = l.split(" ").nth(2).ok_or_else(|| ParseError::within(input, l, "fold along x=N or y=N"))?;
+            let (axis, n) = t.split_once("=").ok_or_else(|| ParseError::within(input, t, "x=N or y=N"))?;
+            let n = aoc::parse_at(input, n, "a fold line")?;
+            match axis {
+                "x" => Ok((true,n)),
+                "y" => Ok((false,n)),
+                _ => Err(ParseError::within(input, axis, "x or y"))
+            }
+        }).collect::<Result<_,_>>()?;
+        Ok((x,y))
+    }
+
+    fn part1((points, foldings) : &(Vec<Point>, Vec<Fold>)) -> Answer {
+        let mut points = points.clone();
+        fold(&mut points, &foldings[..foldings.len().min(1)]);
+        points.len().into()
+    }
+
+    fn part2((points, foldings) : &(Vec<Point>, Vec<Fold>)) -> Answer {
+        let mut points = points.clone();
+        fold(&mut points, foldings);
+        read_points(&points)
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, ParseError, Solution};
+
+type Rule = (String,String);
+
+fn star1((p,rules) : &(String, Vec<Rule>)) -> usize {
+    let mut polymer = p.to_string();
+    for _ in 0..10 {
+        for (from, to) in rules {
+            while polymer.contains(from.as_str()) {
+                polymer = polymer.replace(from, to);
+            }
+        }
+        polymer = polymer.to_uppercase();
+    }
+    let letters : Vec<char> = polymer.chars().collect();
+    let mut keys = letters.clone();
+    keys.sort();
+    keys.dedup();
+    let ocurrences : Vec<usize> = keys.iter().map(|c| letters.iter().filter(|e| *e == c).count()).collect();
+    let max = ocurrences.iter().max().unwrap_or(&0);
+    let min = ocurrences.iter().min().unwrap_or(&0);
+    max-min
+}
+
+fn star2((p,rules) : &(String, Vec<Rule>)) -> usize {
+    let fixed_rules : Vec<Rule> = rules.iter().map(|(a,b)| (a.clone(),b.to_uppercase())).collect();
+    let mut polymer : HashMap<&str, usize> = HashMap::new();
+    for i in 1..p.len() {
+        let pair = &p[i-1..=i];
+        polymer.entry(pair).or_insert(0);
+        let n : &mut usize = polymer.get_mut(pair).unwrap();
+        *n += 1;
+    }
+    for _ in 0..40 {
+        let mut new_polymer : HashMap<&str, usize> = HashMap::new();
+        for (from, to) in &fixed_rules {
+            let existing = *polymer.get(from.as_str()).unwrap_or(&0);
+            for key in [&to[1..],&to[..2]] {
+                new_polymer.entry(key).or_insert(0);
+                let n : &mut usize = new_polymer.get_mut(key).unwrap();
+                *n += existing;
+            }
+        }
+        polymer = new_polymer;
+    }
+    let mut ocurrences_double : HashMap<char,usize> = HashMap::new();
+    for (k,v) in polymer {
+        for c in k.chars() {
+            ocurrences_double.entry(c).or_insert(0);
+            let n : &mut usize = ocurrences_double.get_mut(&c).unwrap();
+            *n += v;
+        }
+    }
+    // First and last ocurrences must be counted twice as well
+    let n = ocurrences_double.get_mut(&p.chars().next().unwrap()).unwrap();
+    *n += 1;
+    let n = ocurrences_double.get_mut(&p.chars().nth(p.len()-1).unwrap()).unwrap();
+    *n += 1;
+    
+    let max = ocurrences_double.values().max().unwrap()/2;
+    let min = ocurrences_double.values().min().unwrap()/2;
+    max-min
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (String, Vec<Rule>);
+
+    fn parse(input : &str) -> Result<(String, Vec<Rule>), ParseError> {
+        let (x, rules) = input.split_once("\n\n").ok_or_else(|| ParseError::new("a template, a blank line and insertion rules"))?;
+        if x.len() < 2 || !x.chars().all(|c| c.is_ascii_uppercase()) { return Err(ParseError::within(input, x, "a polymer template")); }
+        let y = rules.lines().filter(|l| !l.is_empty()).map(|l| {
+            match l.split_once(" -> ") {
+                Some((from, new)) if from.len() == 2 && new.len() == 1 => {
+                    let mut to = from.to_string();
+                    to.insert_str(1, &new.to_lowercase());
+                    Ok((from.to_string(),to))
+                },
+                _ => Err(ParseError::within(input, l, "a rule AB -> C"))
+            }
+        }).collect::<Result<_,_>>()?;
+        Ok((x.to_string(),y))
+    }
+
+    fn part1(s : &(String, Vec<Rule>)) -> Answer {
+        star1(s).into()
+    }
+
+    fn part2(s : &(String, Vec<Rule>)) -> Answer {
+        star2(s).into()
+    }
+}
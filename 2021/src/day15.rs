@@ -0,0 +1,39 @@
+use aoc::grid::Grid;
+use aoc::search;
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(s: &Grid<u16>) -> Answer {
+    // The lowest total risk from the top left to the bottom right
+    let end = (s.width() as isize - 1, s.height() as isize - 1);
+    let found = search::dijkstra([(0,0)], |p| s.neighbours4(*p).map(|q| (q, s[q] as u64)), |p| *p == end);
+    found.goal_cost().map_or(Answer::NoAnswer, Answer::from)
+}
+
+
+fn new_map(m : &Grid<u16>) -> Grid<u16> {
+    let (w, h) = (m.width() as isize, m.height() as isize);
+    Grid::from_fn(m.width()*5, m.height()*5, |(x,y)| {
+        let n = m[(x%w,y%h)] + (x/w + y/h) as u16;
+        if n >= 10 { n%10 + 1} else {n}
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u16>;
+
+    fn parse(input : &str) -> Result<Grid<u16>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|n| n as u16), "a risk level")
+    }
+
+    fn part1(s : &Grid<u16>) -> Answer {
+        star1(s)
+    }
+
+    fn part2(s : &Grid<u16>) -> Answer {
+        let new_map : Grid<u16> = new_map(s);
+        aoc::trace!("{}", new_map);
+        star1(&new_map)
+    }
+}
@@ -1,4 +1,5 @@
 // use std::fmt;
+use aoc::{Answer, ParseError, Solution};
 
 fn hex_to_bin(h : char) -> [bool;4] {
     match h {
@@ -33,7 +34,7 @@ fn bin_to_hex(acc : u64, n : &[bool]) -> u64 {
 type Stream<'a> = &'a [bool];
 
 #[derive(Debug)]
-enum Operator {
+pub enum Operator {
     Sum,
     Multiply,
     Min,
@@ -43,75 +44,69 @@ enum Operator {
     Equal
 }
 
-fn to_operator(n: u8) -> Operator {
+// The operator with the number of packets it takes, at least and at most
+fn to_operator(n: u8) -> (Operator, usize, usize) {
     match n {
-        0 => Operator::Sum,
-        1 => Operator::Multiply,
-        2 => Operator::Min,
-        3 => Operator::Max,
-        5 => Operator::Greater,
-        6 => Operator::Less,
-        7 => Operator::Equal,
-        _ => panic!()
+        0 => (Operator::Sum, 0, usize::MAX),
+        1 => (Operator::Multiply, 0, usize::MAX),
+        2 => (Operator::Min, 1, usize::MAX),
+        3 => (Operator::Max, 1, usize::MAX),
+        5 => (Operator::Greater, 2, 2),
+        6 => (Operator::Less, 2, 2),
+        _ => (Operator::Equal, 2, 2),
     }
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version : u8,
     value : Result<Vec<bool>,(Operator, Vec<Packet>)>
 }
 
-fn parse_input<'a>(s : &'a String) -> Vec<Vec<bool>> {
-    s.lines().filter_map(|l| {
-        match l.len() {
-            0 => None,
-            _ => Some(l.chars().map(|c| hex_to_bin(c)).flatten().collect())
-        }
-    }).collect()
-}
-
-fn decode_packet(base : Stream, offset : &mut usize) -> Packet {
-    let v = &base[*offset..];
-    let version = bin_to_hex(0, &v[0..3]) as u8;
-    let operator = bin_to_hex(0, &v[3..6]) as u8;
+// `None` when the stream ends inside the packet or an operator gets the wrong number of packets
+fn decode_packet(base : Stream, offset : &mut usize) -> Option<Packet> {
+    let v = base.get(*offset..)?;
+    let version = bin_to_hex(0, v.get(0..3)?) as u8;
+    let operator = bin_to_hex(0, v.get(3..6)?) as u8;
     let value : Result<Vec<bool>,(Operator, Vec<Packet>)> = match operator {
         4 => {
             let mut num : Vec<bool> = Vec::new();
             let mut i : usize = 6;
             let mut keep_reading = true;
             while keep_reading {
-                keep_reading = v[i];
-                num.append(&mut v[i+1..i+5].to_vec());
+                keep_reading = *v.get(i)?;
+                num.extend_from_slice(v.get(i+1..i+5)?);
                 i += 5;
             }
             *offset += i;
             Ok(num)
         },
         _ => {
-            let nest : Vec<Packet> = if v[6] {
+            let nest : Vec<Packet> = if *v.get(6)? {
                 let mut packets = Vec::new();
-                let num_packets = bin_to_hex(0, &v[7..18]);
+                let num_packets = bin_to_hex(0, v.get(7..18)?);
                 *offset += 18;
                 for _ in 0..num_packets {
-                    let p = decode_packet(base, offset);
+                    let p = decode_packet(base, offset)?;
                     packets.push(p);
                 }
                 packets
             } else {
                 let mut packets = Vec::new();
-                let length_packets = bin_to_hex(0, &v[7..22]);
+                let length_packets = bin_to_hex(0, v.get(7..22)?);
                 *offset += 22;
                 let old_offset = *offset;
                 while old_offset + length_packets as usize > *offset {
-                    packets.push(decode_packet(base, offset));
+                    packets.push(decode_packet(base, offset)?);
                 }
                 packets
             };
-            Err((to_operator(operator),nest))
+            let (operator, min, max) = to_operator(operator);
+            if nest.len() < min || nest.len() > max { return None }
+            Err((operator,nest))
         }
     };
-    Packet { version: version, value: value }
+    Some(Packet { version, value })
 }
 
 impl Packet {
@@ -126,7 +121,7 @@ impl Packet {
 
     fn get_value(&self) -> u64 {
         match &self.value {
-            Ok(n) => bin_to_hex(0, &n),
+            Ok(n) => bin_to_hex(0, n),
             Err((operator, n)) => match operator {
                 Operator::Sum => 
                     n.iter().map(|l| l.get_value()).sum(),
@@ -137,30 +132,35 @@ impl Packet {
                 Operator::Max => 
                     n.iter().map(|l| l.get_value()).max().unwrap(),
                 Operator::Greater => 
-                    if &n[0].get_value() > &n[1].get_value() {1} else {0},
+                    if n[0].get_value() > n[1].get_value() {1} else {0},
                 Operator::Less => 
-                    if &n[0].get_value() < &n[1].get_value() {1} else {0},
+                    if n[0].get_value() < n[1].get_value() {1} else {0},
                 Operator::Equal => 
-                    if &n[0].get_value() == &n[1].get_value() {1} else {0},
+                    if n[0].get_value() == n[1].get_value() {1} else {0},
             }
         }
     }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let filename = std::fs::read_to_string(args[1].clone()).unwrap();
-    let s: Vec<Vec<bool>> = parse_input(&filename);
-    // star1(&s);
-    // let new_map : Vec<Vec<u16>> = new_map(s);
-    for l in &s {
-        let p = &decode_packet(l.as_slice(), &mut 0);
-        println!("{:?}", p.sum_versions());
-        println!("{:?}", p.get_value());
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(input : &str) -> Result<Packet, ParseError> {
+        let l = input.trim();
+        if l.is_empty() || !l.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_lowercase()) {
+            return Err(ParseError::within(input, l, "a hexadecimal transmission"));
+        }
+        let s : Vec<bool> = l.chars().flat_map(hex_to_bin).collect();
+        decode_packet(s.as_slice(), &mut 0).ok_or_else(|| ParseError::within(input, l, "a complete packet"))
+    }
+
+    fn part1(p : &Packet) -> Answer {
+        p.sum_versions().into()
+    }
+
+    fn part2(p : &Packet) -> Answer {
+        p.get_value().into()
     }
-    // star1(&new_map);
 }
@@ -0,0 +1,59 @@
+use aoc::{Answer, ParseError, Solution};
+
+type Pos = (isize,isize);
+type Velocity = (isize,isize);
+type Range = ((isize,isize),(isize,isize));
+
+fn falls_in(p : Pos,  v : Velocity, zone : Range) -> bool {
+    if (v.0 == 0 && p.0 < zone.0.0) || p.0 > zone.0.1 || p.1 < zone.1.0 {
+        false
+    } else if p.0 >= zone.0.0 && p.0 <= zone.0.1 &&
+        p.1 >= zone.1.0 && p.1 <= zone.1.1 {
+            true
+        }
+    else {
+        falls_in((p.0+v.0,p.1+v.1), (if v.0 == 0 {0} else {v.0-1}, v.1-1), zone)
+    }
+}
+
+fn max_height(n : u64) -> u64 { n*(n+1)/2 }
+
+fn in_range(zone : &Range) -> Vec<Velocity> {
+    let mut in_range = Vec::new();
+    for x in 0..=zone.0.1 {
+        for y in zone.1.0..-zone.1.0 {
+            if falls_in((0,0), (x,y), *zone) {
+                in_range.push((x,y));
+            }
+        }
+    }
+    in_range
+}
+
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Range;
+
+    fn parse(input : &str) -> Result<Range, ParseError> {
+        let area = input.trim().strip_prefix("target area: ").ok_or_else(|| ParseError::within(input, input.trim(), "target area: x=A..B, y=C..D"))?;
+        let (x, y) = area.split_once(", ").ok_or_else(|| ParseError::within(input, area, "x=A..B, y=C..D"))?;
+        let bounds = |r : &str, axis : &str| {
+            let (a, b) = r.strip_prefix(axis).and_then(|r| r.split_once("..")).ok_or_else(|| ParseError::within(input, r, format!("{}A..B", axis)))?;
+            Ok::<_, ParseError>((aoc::parse_at(input, a, "a bound")?, aoc::parse_at(input, b, "a bound")?))
+        };
+        let (range_x, range_y) = (bounds(x, "x=")?, bounds(y, "y=")?);
+        // The probe is launched from the origin, towards a target ahead and below
+        if range_x.0 < 0 || range_y.1 >= 0 { return Err(ParseError::within(input, area, "a target ahead and below the launcher")); }
+        Ok((range_x, range_y))
+    }
+
+    fn part1(zone : &Range) -> Answer {
+        in_range(zone).iter().map(|n| max_height(n.1.max(0) as u64)).max().map_or(Answer::NoAnswer, Answer::from)
+    }
+
+    fn part2(zone : &Range) -> Answer {
+        in_range(zone).len().into()
+    }
+}
@@ -1,8 +1,9 @@
 use std::fmt;
 use std::mem;
+use aoc::{Answer, ParseError, Solution};
 
 #[derive(Debug,PartialEq,Clone)]
-enum Node {
+pub enum Node {
     Pair(Box<Node>,Box<Node>),
     Lit(u32)
 }
@@ -21,9 +22,9 @@ struct Tree {
 }
 
 impl Tree {
-    fn append(&mut self, elem: &Box<Node>) {
+    fn append(&mut self, elem: &Node) {
         let prev_root : Box<Node> = mem::replace(&mut self.root, Box::new(Node::Lit(0)));
-        self.root = Box::new(Node::Pair(prev_root, Box::clone(elem)));
+        *self.root = Node::Pair(prev_root, Box::new(elem.clone()));
     }
 }
 
@@ -86,7 +87,7 @@ fn split(n : &mut Node) -> bool {
 }
 
 fn reduce (n : &mut Node) {
-    if find_explosion(n, 0) == None {
+    if find_explosion(n, 0).is_none() {
         if split(n) {
             reduce(n)
         }
@@ -98,22 +99,22 @@ fn reduce (n : &mut Node) {
 fn magnitude(n : &Node) -> u32 {
     match n {
         Node::Lit(e) => *e,
-        Node::Pair(a,b) => 3*magnitude(&a) + 2*magnitude(&b)
+        Node::Pair(a,b) => 3*magnitude(a) + 2*magnitude(b)
     }
 }
 
-fn star1 (mut s: Vec<Tree>) {
+fn star1 (mut s: Vec<Tree>) -> u32 {
     let mut i = s.iter_mut();
-    let a : &mut Tree = i.next().unwrap();
+    let Some(a) = i.next() else { return 0 };
     reduce(&mut a.root);
-    while let Some(to_insert) = i.next() {
-        a.append(&mut to_insert.root);
+    for to_insert in i {
+        a.append(&to_insert.root);
         reduce(&mut a.root);
     }
-    println!("{}", magnitude(&a.root));
+    magnitude(&a.root)
 }
 
-fn star2 (s: Vec<Tree>) {
+fn star2 (s: Vec<Tree>) -> Option<u32> {
     let mut magnitudes = vec![];
     for x in 0..s.len() {
         let base : &Tree = s.get(x).unwrap();
@@ -123,52 +124,52 @@ fn star2 (s: Vec<Tree>) {
                 let base2 = s.get(y).unwrap();
                 let t2 = &mut Tree { root : Box::clone(&base2.root) };
                 reduce (&mut t1.root);
-                t1.append(&mut t2.root);
+                t1.append(&t2.root);
                 reduce(&mut t1.root);
                 magnitudes.push(magnitude(&t1.root));
             }
         }
     }
-    println!("{}", magnitudes.iter().max().unwrap());
+    magnitudes.into_iter().max()
 }
 
-fn parse_pair<'a>(s : &'a str) -> (Node, &'a str) {
-    match s.chars().nth(0) {
+fn parse_pair(s : &str) -> Option<(Node, &str)> {
+    match s.chars().next() {
         Some('[') => {
-            let (left, s_r) = parse_pair(&s[1..]);
-            let (right, res) = parse_pair(s_r);
-            (Node::Pair(Box::new(left), Box::new(right)), res)
+            let (left, s_r) = parse_pair(&s[1..])?;
+            let (right, res) = parse_pair(s_r)?;
+            Some((Node::Pair(Box::new(left), Box::new(right)), res))
         },
         Some(']') | Some(',') => parse_pair(&s[1..]),
-        Some(c) => {
-            let n = match c.to_digit(10) {
-                Some(e) => e,
-                None => panic!()
-            };
-            (Node::Lit(n),&s[1..])
-        },
-        None => panic!()
+        Some(c) => Some((Node::Lit(c.to_digit(10)?),&s[1..])),
+        None => None
     }
 }
 
-fn parse_input<'a>(s : &'a String) -> Vec<Tree> {
-    s.lines().filter_map(|l| {
-        match l.len() {
-            0 => None,
-            _ => Some(Tree { root : Box::new(parse_pair(l).0)})
-        }
-    }).collect()
+fn trees(s : &[Node]) -> Vec<Tree> {
+    s.iter().map(|n| Tree { root : Box::new(n.clone())}).collect()
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let filename = std::fs::read_to_string(args[1].clone()).unwrap();
-    let s1: Vec<Tree> = parse_input(&filename);
-    let s2: Vec<Tree> = parse_input(&filename);
-    star1(s1);
-    star2(s2);
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Node>;
+
+    fn parse(input : &str) -> Result<Vec<Node>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            match parse_pair(l) {
+                // The closing brackets are left over
+                Some((n, rest)) if rest.chars().all(|c| c == ']') => Ok(n),
+                _ => Err(ParseError::within(input, l, "a snailfish number"))
+            }
+        }).collect()
+    }
+
+    fn part1(s : &Vec<Node>) -> Answer {
+        star1(trees(s)).into()
+    }
+
+    fn part2(s : &Vec<Node>) -> Answer {
+        star2(trees(s)).map_or(Answer::NoAnswer, Answer::from)
+    }
 }
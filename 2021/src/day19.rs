@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use aoc::geometry::{Point3, Rotation};
+use aoc::{Answer, ParseError, Solution};
+
+type Scanner = Vec<Point3>;
+
+// Match happens when there is
+// The beacons, each with the number of scanners seeing it, and where the scanners are
+fn locate(s: &[Scanner]) -> (HashMap<Point3,u8>, Vec<Point3>) {
+    let rotations = Rotation::all();
+    let mut positions : HashMap<Point3,u8> = s[0].iter().map(|p| (*p,1)).collect();
+    let mut scanners : Vec<Point3> = vec![Point3::ORIGIN];
+    let mut pending : Vec<usize> = (1..s.len()).collect();
+
+    while let Some(k) = pending.pop() {
+        let scanner = s.get(k).unwrap();
+        let perms : Vec<Scanner>  = scanner.iter().map(|e| rotations.iter().map(|r| r.apply(*e)).collect()).collect();
+        let l = rotations.len();
+        let mut possible_locations : Vec<HashMap<Point3,u8>> =  vec![HashMap::new();l];
+        for j in 0..l {
+            for perm in &perms {
+                let e2 = perm[j];
+                for e1 in positions.keys() {
+                    let pos = *e1 - e2;
+                    match possible_locations[j].get_mut(&pos) {
+                        Some(n) => *n += 1,
+                        None => {let _= possible_locations[j].insert(pos, 1);}
+                    }
+                }
+            }
+        }
+        let mut scanner_position : Option<(Point3,usize)> = None;
+        for index_permutation in 0..l { // possible_locations {
+            if let Some((possible_scanner,coincidences)) = possible_locations.get(index_permutation).unwrap().iter().max_by(|(_,a),(_,b)| a.cmp(b)) {
+                if *coincidences >= 12 {
+                    scanner_position = Some((*possible_scanner,index_permutation))
+                }
+            }
+        }
+        match scanner_position {
+            Some((scanner,index)) => {
+                scanners.push(scanner);
+                for p in perms {
+                    let pos = scanner + p[index];
+                    match positions.get_mut(&pos) {
+                        Some(n) => *n += 1,
+                        None => {let _= positions.insert(pos, 1);}
+                    }
+                }
+            },
+            None => {pending.insert(0,k)}
+        }
+    }
+    (positions, scanners)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+
+    fn parse(input : &str) -> Result<Vec<Scanner>, ParseError> {
+        let s : Vec<Scanner> = input.split("\n\n").filter(|sc| !sc.trim().is_empty()).map(|sc| {
+            sc.lines().skip(1).map(|l| {
+                let v : Vec<i64> = l.split(",").map(|n| aoc::parse_at(input, n, "a coordinate")).collect::<Result<_,_>>()?;
+                match v[..] {
+                    [x, y, z] => Ok(Point3::new(x, y, z)),
+                    _ => Err(ParseError::within(input, l, "a beacon x,y,z"))
+                }
+            }).collect()
+        }).collect::<Result<_,_>>()?;
+        match s.is_empty() {
+            true => Err(ParseError::new("the reports of the scanners")),
+            false => Ok(s)
+        }
+    }
+
+    fn part1(s : &Vec<Scanner>) -> Answer {
+        locate(s).0.len().into()
+    }
+
+    fn part2(s : &Vec<Scanner>) -> Answer {
+        let (_, scanners) = locate(s);
+        let mut distances = vec![];
+        for s1 in &scanners {
+            for s2 in &scanners {
+                distances.push((*s1 - *s2).manhattan());
+            }
+        }
+        distances.iter().max().map_or(Answer::NoAnswer, |d| (*d).into())
+    }
+}
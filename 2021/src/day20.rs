@@ -0,0 +1,60 @@
+use aoc::automaton::{Automaton, Dense};
+use aoc::grid::Grid;
+use aoc::{Answer, ParseError, Solution};
+
+type Image = Dense<bool>;
+
+// from day16
+fn bin_to_hex(acc : usize, n : &[bool]) -> usize {
+    match n {
+        [] => acc,
+        [false,..] => bin_to_hex(acc << 1, &n[1..]),
+        [true,..] => bin_to_hex((acc << 1) | 1 , &n[1..])
+    }
+}
+
+/// The image grows a pixel on each side every step, the infinite rest of it lit or not all at once.
+fn enhance(alg: &[bool], image: &Image) -> Image {
+    image.step(1, |image, (x,y)| {
+        let index = [(x-1,y-1),(x,y-1),(x+1,y-1),
+                     (x-1,y  ),(x,y  ),(x+1,y  ),
+                     (x-1,y+1),(x,y+1),(x+1,y+1)]
+            .map(|p| *image.get(p));
+        alg[bin_to_hex(0,&index)]
+    })
+}
+
+fn stars(alg: &[bool], image: Image, n : usize) -> usize {
+    let mut enhanced = Automaton::new(image, |image : &Image| enhance(alg, image));
+    let image = enhanced.run(n);
+    image.grid.values().filter(|p| **p).count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+
+    fn parse(input : &str) -> Result<(Vec<bool>, Image), ParseError> {
+        let (algorithm, image) = input.split_once("\n\n").ok_or_else(|| ParseError::new("an algorithm, a blank line and an image"))?;
+        let algorithm = algorithm.trim();
+        if algorithm.len() != 512 {
+            return Err(ParseError::within(input, algorithm, "an algorithm of 512 pixels"));
+        }
+        let algorithm = algorithm.chars().map(|c| c == '#').collect();
+        let image = Grid::parse(image, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        }, "a pixel '#' or '.'")?;
+        Ok((algorithm, Dense::new(image, false)))
+    }
+
+    fn part1((alg, image) : &(Vec<bool>, Image)) -> Answer {
+        stars(alg, image.clone(), 2).into()
+    }
+
+    fn part2((alg, image) : &(Vec<bool>, Image)) -> Answer {
+        stars(alg, image.clone(), 50).into()
+    }
+}
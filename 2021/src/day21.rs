@@ -1,9 +1,22 @@
+use aoc::{Answer, ParseError, Solution};
+
+// Winning scores of the practice game and of the Dirac dice game, and the faces of the
+// deterministic die
+const SCORE : u64 = 1000;
+const DIRAC_SCORE : u8 = 21;
+const DICE : u64 = 100;
+
+pub struct Game {
+    p1: u64,
+    p2: u64,
+}
+
 fn increment_dice (dice : &mut u64, dice_limit : u64) {
     *dice += 1;
     if *dice > dice_limit { *dice -= dice_limit }
 }
     
-fn star1 (mut p1 : u64, mut p2 : u64, limit_score : u64, dice_limit : u64) {
+fn star1 (mut p1 : u64, mut p2 : u64, limit_score : u64, dice_limit : u64) -> u64 {
     let mut dice : u64 = 1;
     let mut dice_rolled = 0;
     let mut p1_score : u64 = 0;
@@ -31,9 +44,10 @@ fn star1 (mut p1 : u64, mut p2 : u64, limit_score : u64, dice_limit : u64) {
         turn_p1 = !turn_p1;
     }
     let min_punctuation = p1_score.min(p2_score);
-    println!("{}", min_punctuation * dice_rolled)
+    min_punctuation * dice_rolled
 }
 
+#[allow(clippy::too_many_arguments)]
 fn star2_aux (p1 : u8, p2 : u8,
               p1_score : u8, p2_score : u8,
               won_p1 : &mut usize, won_p2 : &mut usize,
@@ -73,7 +87,7 @@ fn star2_aux (p1 : u8, p2 : u8,
     }
 }
 
-fn star2 (p1 : u64, p2 : u64) {
+fn star2 (p1 : u64, p2 : u64, limit_score : u8) -> usize {
     let mut possible_scores : Vec<u8> = vec![];
     for i in 1..=3 {
         for j in 1..=3 {
@@ -95,25 +109,39 @@ fn star2 (p1 : u64, p2 : u64) {
     star2_aux(p1 as u8, p2 as u8,
               0, 0,
               &mut won_p1, &mut won_p2,
-              true, 21,
+              true, limit_score,
               &cumulative_scores, 1);
-    println!("{}", won_p1.max(won_p2));
+    won_p1.max(won_p2)
 }
 
-fn parse_input<'a>(s : &'a String) -> (u64, u64) {
-    let mut i = s.lines();
-    (i.next().unwrap().split_whitespace().last().unwrap().parse().unwrap(),
-     i.next().unwrap().split_whitespace().last().unwrap().parse().unwrap())
+fn parse_position(input : &str, player : usize) -> Result<u64, ParseError> {
+    let line = input.lines().nth(player - 1)
+        .ok_or_else(|| ParseError::new(format!("a line for player {}", player)))?;
+    let position = line.split_whitespace().last()
+        .ok_or_else(|| ParseError::within(input, line, "a starting position"))?;
+    match aoc::parse_at(input, position, "a position from 1 to 10")? {
+        p @ 1..=10 => Ok(p),
+        _ => Err(ParseError::within(input, position, "a position from 1 to 10")),
+    }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let filename = std::fs::read_to_string(args[1].clone()).unwrap();
-    let (p1, p2): (u64, u64) = parse_input(&filename);
-    star1(p1, p2, 1000, 100);
-    star2(p1,p2);
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Game;
+
+    fn parse(input : &str) -> Result<Game, ParseError> {
+        Ok(Game {
+            p1: parse_position(input, 1)?,
+            p2: parse_position(input, 2)?,
+        })
+    }
+
+    fn part1(g : &Game) -> Answer {
+        star1(g.p1, g.p2, SCORE, DICE).into()
+    }
+
+    fn part2(g : &Game) -> Answer {
+        star2(g.p1, g.p2, DIRAC_SCORE).into()
+    }
 }
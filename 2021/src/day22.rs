@@ -0,0 +1,108 @@
+// use std::collections::HashSet;
+use std::collections::HashSet;
+use aoc::{Answer, ParseError, Solution};
+
+type Coord = (isize,isize,isize);
+
+fn volume((x0,y0,z0) : &Coord, (x1,y1,z1) : &Coord) -> isize {
+    (x1-x0) * (y1-y0) * (z1-z0)
+}
+
+fn volume_act (activated : &[Vec<Vec<bool>>], range_x : &[isize], range_y : &[isize], range_z : &[isize]) -> isize {
+    activated.iter().enumerate().map(|(i,a)| {
+        a.iter().enumerate().map( |(j,b)| {
+            b.iter().enumerate().map( |(k,v)| {
+                if *v {
+                    let down = (range_x[i], range_y[j], range_z[k]);
+                    let up = (range_x[i+1], range_y[j+1], range_z[k+1]);
+                    volume(&down,&up)
+                } else { 0 }
+            }).sum::<isize>()
+        }).sum::<isize>()
+    }).sum::<isize>()
+}
+
+type Step = (bool,Coord,Coord);
+
+// The cubes left on after the steps
+fn reboot(s: &[Step]) -> isize {
+    if s.is_empty() { return 0 }
+    let (range_x, xl) : (Vec<isize>, usize) = {
+        let limits_x : HashSet<isize> = s.iter().flat_map(|(_,(x0,_,_),(x1,_,_))| [*x0,*x1]).collect();
+        let mut r : Vec<isize> = limits_x.iter().copied().collect();
+        r.sort();
+        let l = r.len();
+        (r, l)
+    };
+    let (range_y, yl) : (Vec<isize>,usize) = {
+        let limits_y : HashSet<isize> = s.iter().flat_map(|(_,(_,y0,_),(_,y1,_))| [*y0,*y1]).collect();
+        let mut r : Vec<isize> = limits_y.iter().copied().collect();
+        r.sort();
+        let l = r.len();
+        (r, l)
+    };
+    let (range_z, zl) : (Vec<isize>,usize) = {
+        let limits_z : HashSet<isize> = s.iter().flat_map(|(_,(_,_,z0),(_,_,z1))| [*z0,*z1]).collect();
+        let mut r : Vec<isize> = limits_z.iter().copied().collect();
+        r.sort();
+        let l = r.len();
+        (r, l)
+    };
+    let mut activated : Vec<Vec<Vec<bool>>> = vec![vec![vec![false; zl-1]; yl-1]; xl-1];
+    for (on,(x0,y0,z0),(x1,y1,z1)) in s {
+        for ((i,_i0),_i1) in range_x.iter().enumerate().zip(
+            &range_x[1..]).filter(|((_,i0),i1)| *i0 >= x0 && *i1 <= x1) {
+            for ((j,_j0),_j1) in range_y.iter().enumerate().zip(
+                &range_y[1..]).filter(|((_,j0),j1)| *j0 >= y0 && *j1 <= y1) {
+                for ((k,_k0),_k1) in range_z.iter().enumerate().zip(
+                    &range_z[1..]).filter(|((_,k0),k1)| *k0 >= z0 && *k1 <= z1) {
+                    activated[i][j][k] = *on;
+                }
+            }
+        }
+    }
+    volume_act(&activated, &range_x, &range_y, &range_z)
+}
+
+// The upper limits are exclusive
+fn initialization((_,(x0,y0,z0),(x1,y1,z1)) : &&Step) -> bool {
+    [x0,y0,z0].iter().all(|c| **c >= -50) && [x1,y1,z1].iter().all(|c| **c <= 51)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Step>;
+
+    fn parse(input : &str) -> Result<Vec<Step>, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let bad = || ParseError::within(input, l, "on or off x=A..B,y=C..D,z=E..F");
+            let (on, cuboid) = l.split_once(' ').ok_or_else(bad)?;
+            let on = match on {
+                "on" => true,
+                "off" => false,
+                _ => return Err(bad())
+            };
+            let coords : Vec<(isize,isize)> = cuboid.split(",").map(
+                |c| {
+                    let (lo, hi) = c.split_once("=").and_then(|(_,r)| r.split_once("..")).ok_or_else(bad)?;
+                    Ok((aoc::parse_at(input, lo, "a bound")?,
+                     // Because it's the upper limit, we add 1 (coordinate)
+                     aoc::parse_at::<isize>(input, hi, "a bound")?+1))
+                }
+            ).collect::<Result<_,ParseError>>()?;
+            match coords[..] {
+                [x, y, z] => Ok((on,(x.0,y.0,z.0),(x.1,y.1,z.1))),
+                _ => Err(bad())
+            }
+        }).collect()
+    }
+
+    fn part1(s : &Vec<Step>) -> Answer {
+        reboot(&s.iter().take_while(initialization).cloned().collect::<Vec<Step>>()).into()
+    }
+
+    fn part2(s : &Vec<Step>) -> Answer {
+        reboot(s).into()
+    }
+}
@@ -1,12 +1,13 @@
 use std::collections::HashMap;
+use aoc::{Answer, ParseError, Solution};
 
 #[derive(Debug,Copy,Clone)]
-enum Reg {
+pub enum Reg {
     X, Y, Z, W, Lit(i64)
 }
 
 #[derive(Debug,Copy,Clone)]
-enum Inst {
+pub enum Inst {
     Inp(Reg),
     Add(Reg,Reg),
     Mul(Reg,Reg),
@@ -63,35 +64,35 @@ fn emulate_all(monad : &Monad) -> Vec<([i64;4],(usize,usize))> {
             }
             Inst::Add(r, v) => {
                 for (regs, _) in states.iter_mut() {
-                    let val : i64 = get_value(v,&regs);
+                    let val : i64 = get_value(v,regs);
                     let r_i = get_index(r);
                     regs[r_i] += val;
                 }
             },
             Inst::Mul(r, v) => {
                 for (regs, _) in states.iter_mut() {
-                    let val : i64 = get_value(v,&regs);
+                    let val : i64 = get_value(v,regs);
                     let r_i = get_index(r);
                     regs[r_i] *= val;
                 }
             },
             Inst::Mod(r, v) => {
                 for (regs, _) in states.iter_mut() {
-                    let val : i64 = get_value(v,&regs);
+                    let val : i64 = get_value(v,regs);
                     let r_i = get_index(r);
                     regs[r_i] %= val;
                 }
             },
             Inst::Div(r, v) => {
                 for (regs, _) in states.iter_mut() {
-                    let val : i64 = get_value(v,&regs);
+                    let val : i64 = get_value(v,regs);
                     let r_i = get_index(r);
                     regs[r_i] /= val;
                 }
             },
             Inst::Eql(r, v) => {
                 for (regs, _) in states.iter_mut() {
-                    let val : i64 = get_value(v,&regs);
+                    let val : i64 = get_value(v,regs);
                     let r_i = get_index(r);
                     regs[r_i] = if regs[r_i] == val {1} else {0};
                 }
@@ -101,49 +102,51 @@ fn emulate_all(monad : &Monad) -> Vec<([i64;4],(usize,usize))> {
     states
 }
 
-fn stars(monad : &Monad) {
+// The smallest and largest model numbers leading to each valid end state
+fn valid(monad : &Monad) -> Vec<(usize,usize)> {
     let all_results : Vec<([i64;4],(usize,usize))> = emulate_all(monad);
-    let r : Vec<(usize,usize)> = all_results.iter().filter(|([_,_,z,_],_)| *z == 0).map(|(_,bounds)| *bounds).collect();
-    
-    println!("{:?}", r.iter().map(|(_,max)| max).max().unwrap());
-    println!("{:?}", r.iter().map(|(min,_)| min).min().unwrap());
+    all_results.iter().filter(|([_,_,z,_],_)| *z == 0).map(|(_,bounds)| *bounds).collect()
 }
 
-fn parse_input<'a>(s : &'a String) -> Monad {
-    s.lines().filter_map(|l| {
-        if l.len() == 0 {return None}
-        let mut i = l.split_whitespace();
-        let mnemo = i.next();
-        let regs : Vec<Reg> = i.map(|r| { match r {
-            "x" => Reg::X,
-            "y" => Reg::Y,
-            "z" => Reg::Z,
-            "w" => Reg::W,
-            _ => match r.parse() {
-                Ok(n) => Reg::Lit(n),
-                _ => panic!()
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+
+    fn parse(input : &str) -> Result<Monad, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let mut i = l.split_whitespace();
+            let mnemo = i.next();
+            let regs : Vec<Reg> = i.map(|r| { match r {
+                "x" => Ok(Reg::X),
+                "y" => Ok(Reg::Y),
+                "z" => Ok(Reg::Z),
+                "w" => Ok(Reg::W),
+                _ => aoc::parse_at(input, r, "a register or a number").map(Reg::Lit)
+            }}).collect::<Result<_,_>>()?;
+            let inst = match (mnemo, &regs[..]) {
+                (Some("inp"), [r]) => Inst::Inp(*r),
+                (Some("add"), [r, v]) => Inst::Add(*r,*v),
+                (Some("mul"), [r, v]) => Inst::Mul(*r,*v),
+                (Some("mod"), [r, v]) => Inst::Mod(*r,*v),
+                (Some("div"), [r, v]) => Inst::Div(*r,*v),
+                (Some("eql"), [r, v]) => Inst::Eql(*r,*v),
+                _ => return Err(ParseError::within(input, l, "an instruction inp, add, mul, mod, div or eql"))
+            };
+            match inst {
+                Inst::Inp(Reg::Lit(_)) | Inst::Add(Reg::Lit(_),_) | Inst::Mul(Reg::Lit(_),_) |
+                Inst::Mod(Reg::Lit(_),_) | Inst::Div(Reg::Lit(_),_) | Inst::Eql(Reg::Lit(_),_) =>
+                    Err(ParseError::within(input, l, "a register to write to")),
+                _ => Ok(inst)
             }
-        }}).collect();
-        match mnemo {
-            Some("inp") => Some(Inst::Inp(regs[0])),
-            Some("add") => Some(Inst::Add(regs[0],regs[1])),
-            Some("mul") => Some(Inst::Mul(regs[0],regs[1])),
-            Some("mod") => Some(Inst::Mod(regs[0],regs[1])),
-            Some("div") => Some(Inst::Div(regs[0],regs[1])),
-            Some("eql") => Some(Inst::Eql(regs[0],regs[1])),
-            _ => None
-        }
-    }).collect()
-}
+        }).collect()
+    }
+
+    fn part1(monad : &Monad) -> Answer {
+        valid(monad).iter().map(|(_,max)| *max).max().map_or(Answer::NoAnswer, Answer::from)
+    }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let filename = std::fs::read_to_string(args[1].clone()).unwrap();
-    let s: Monad = parse_input(&filename);
-    stars(&s);
-    // stars();
+    fn part2(monad : &Monad) -> Answer {
+        valid(monad).iter().map(|(min,_)| *min).min().map_or(Answer::NoAnswer, Answer::from)
+    }
 }
@@ -0,0 +1,49 @@
+use aoc::automaton::Automaton;
+use aoc::grid::{Grid, Pos};
+use aoc::render::{Colour, Frame, Legend};
+use aoc::{Answer, ParseError, Solution};
+
+/// `Some(true)` moves east, `Some(false)` south.
+type Cucumbers = Grid<Option<bool>>;
+
+/// The herd `east` moves forward where the cell ahead, wrapping around, is free.
+fn move_herd(s : &Cucumbers, east : bool) -> Cucumbers {
+    let (w, h) = (s.width() as isize, s.height() as isize);
+    let ahead = |(x, y) : Pos, d : isize| if east { ((x + d).rem_euclid(w), y) } else { (x, (y + d).rem_euclid(h)) };
+    Grid::from_fn(s.width(), s.height(), |p| match s[p] {
+        None if s[ahead(p, -1)] == Some(east) => Some(east),
+        Some(c) if c == east && s[ahead(p, 1)].is_none() => None,
+        c => c
+    })
+}
+
+fn stars(s : Cucumbers) -> usize {
+    let legend = Legend::new().entry('>', Colour::Cyan, "east").entry('v', Colour::Green, "south");
+    let mut cucumbers = Automaton::new(s, |s : &Cucumbers| move_herd(&move_herd(s, true), false))
+        .observe(|step, s| aoc::animate!(legend, Frame::new().grid(s, |c| c.map(|east| if east { '>' } else { 'v' })).bounds((0, 0), (s.width() as isize - 1, s.height() as isize - 1)), "step {}", step));
+    cucumbers.run_until_stable()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Cucumbers;
+
+    fn parse(input : &str) -> Result<Cucumbers, ParseError> {
+        Grid::parse(input, |c| match c {
+            '>' => Some(Some(true)),
+            'v' => Some(Some(false)),
+            '.' => Some(None),
+            _ => None
+        }, "a sea cucumber '>' or 'v', or '.'")
+    }
+
+    fn part1(s : &Cucumbers) -> Answer {
+        stars(s.clone()).into()
+    }
+
+    // The last day has a single puzzle
+    fn part2(_ : &Cucumbers) -> Answer {
+        Answer::Unsolved
+    }
+}
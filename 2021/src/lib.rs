@@ -0,0 +1,55 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day24;
+pub mod day25;
+
+pub const YEAR: aoc::Year = aoc::Year {
+    year: 2021,
+    dir: env!("CARGO_MANIFEST_DIR"),
+    days: &[
+        (1, aoc::solution::<day01::Day01>()),
+        (2, aoc::solution::<day02::Day02>()),
+        (3, aoc::solution::<day03::Day03>()),
+        (4, aoc::solution::<day04::Day04>()),
+        (5, aoc::solution::<day05::Day05>()),
+        (6, aoc::solution::<day06::Day06>()),
+        (7, aoc::solution::<day07::Day07>()),
+        (8, aoc::solution::<day08::Day08>()),
+        (9, aoc::solution::<day09::Day09>()),
+        (10, aoc::solution::<day10::Day10>()),
+        (11, aoc::solution::<day11::Day11>()),
+        (12, aoc::solution::<day12::Day12>()),
+        (13, aoc::solution::<day13::Day13>()),
+        (14, aoc::solution::<day14::Day14>()),
+        (15, aoc::solution::<day15::Day15>()),
+        (16, aoc::solution::<day16::Day16>()),
+        (17, aoc::solution::<day17::Day17>()),
+        (18, aoc::solution::<day18::Day18>()),
+        (19, aoc::solution::<day19::Day19>()),
+        (20, aoc::solution::<day20::Day20>()),
+        (21, aoc::solution::<day21::Day21>()),
+        (22, aoc::solution::<day22::Day22>()),
+        (24, aoc::solution::<day24::Day24>()),
+        (25, aoc::solution::<day25::Day25>()),
+    ],
+};
@@ -0,0 +1,33 @@
+use aoc::example;
+use aoc2021::*;
+
+example!(day01: day01::Day01, "day01.txt", part1 = 7, part2 = 5);
+example!(day02: day02::Day02, "day02.txt", part1 = 150, part2 = 900);
+example!(day03: day03::Day03, "day03.txt", part1 = 198, part2 = 230);
+example!(day04: day04::Day04, "day04.txt", part1 = 4512, part2 = 1924);
+example!(day05: day05::Day05, "day05.txt", part1 = 5, part2 = 12);
+example!(day06: day06::Day06, "day06.txt", part1 = 5934, part2 = 26984457539u64);
+example!(day07: day07::Day07, "day07.txt", part1 = 37, part2 = 168);
+example!(day08: day08::Day08, "day08.txt", part1 = 26, part2 = 61229);
+example!(day09: day09::Day09, "day09.txt", part1 = 15, part2 = 1134);
+example!(day10: day10::Day10, "day10.txt", part1 = 26397, part2 = 288957);
+example!(day11: day11::Day11, "day11.txt", part1 = 1656, part2 = 195);
+example!(day12: day12::Day12, "day12.txt", part1 = 10, part2 = 36);
+example!(
+    day13: day13::Day13,
+    "day13.txt",
+    part1 = 17,
+    part2 = concat!("#####\n", "#...#\n", "#...#\n", "#...#\n", "#####\n")
+);
+example!(day14: day14::Day14, "day14.txt", part1 = 1588, part2 = 2188189693529u64);
+example!(day15: day15::Day15, "day15.txt", part1 = 40, part2 = 315);
+example!(day16: day16::Day16, "day16.txt", part1 = 16);
+example!(day16_product: day16::Day16, "day16-2.txt", part2 = 3);
+example!(day16_equal: day16::Day16, "day16-3.txt", part2 = 1);
+example!(day17: day17::Day17, "day17.txt", part1 = 45, part2 = 112);
+example!(day18: day18::Day18, "day18.txt", part1 = 4140, part2 = 3993);
+example!(day18_explode: day18::Day18, "day18-2.txt", part1 = 1384);
+example!(day20: day20::Day20, "day20.txt", part1 = 35, part2 = 3351);
+example!(day21: day21::Day21, "day21.txt", part1 = 739785, part2 = 444356092776315u64);
+example!(day22: day22::Day22, "day22.txt", part1 = 39, part2 = 39);
+example!(day25: day25::Day25, "day25.txt", part1 = 58);
@@ -1,24 +1,29 @@
-fn star1(l : &Vec<Vec<u64>>) {
-    println!("{:?}", l.iter().map(|s| s.iter().sum::<u64>()).max());
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(l : &[Vec<u64>]) -> u64 {
+    l.iter().map(|s| s.iter().sum::<u64>()).max().unwrap_or(0)
 }
 
-fn star2(l : &Vec<Vec<u64>>) {
+fn star2(l : &[Vec<u64>]) -> u64 {
     let mut set : Vec<u64> = l.iter().map(|s| s.iter().sum::<u64>()).collect();
     set.sort_by(|a,b| b.cmp(a));
-    println!("{:?}", set.iter().take(3).sum::<u64>());
+    set.iter().take(3).sum::<u64>()
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Vec<Vec<u64>> = std::fs::read_to_string(args[1].clone()).unwrap().split("\n\n").map(|s| s.lines().filter_map(|l| match l.parse() {
-        Ok(n) => Some(n),
-        _ => None
-    }).collect()).collect();
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input : &str) -> Result<Vec<Vec<u64>>, ParseError> {
+        Ok(input.split("\n\n").map(|s| s.lines().filter_map(|l| l.parse().ok()).collect()).collect())
+    }
+
+    fn part1(l : &Vec<Vec<u64>>) -> Answer {
+        star1(l).into()
+    }
 
-    star1(&l);
-    star2(&l);
+    fn part2(l : &Vec<Vec<u64>>) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,33 +1,42 @@
-type Struct = Vec<Vec<u64>>;
+use aoc::{Answer, ParseError, Solution};
 
-fn star1(l : &Struct) {
+pub type Struct = Vec<Vec<u64>>;
+
+fn star1(l : &Struct) -> usize {
     let ov = |a,b,c,d| {
         a <= c && b >= d
     };
-    println!("{:?}", l.iter().filter(|v| ov(v[0], v[1], v[2], v[3]) || ov(v[2], v[3], v[0], v[1])).count());
+    l.iter().filter(|v| ov(v[0], v[1], v[2], v[3]) || ov(v[2], v[3], v[0], v[1])).count()
 }
 
 
-fn star2(l : &Struct) {
+fn star2(l : &Struct) -> usize {
     let ov = |a,b,c,_d| {
         a <= c && b >= c
     };
-    println!("{:?}", l.iter().filter(|v| ov(v[0], v[1], v[2], v[3]) || ov(v[2], v[3], v[0], v[1])).count());
+    l.iter().filter(|v| ov(v[0], v[1], v[2], v[3]) || ov(v[2], v[3], v[0], v[1])).count()
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            Some(l.split(',').map(|s| s.split('-').map(|e| e.parse().unwrap())).flatten().collect())
-        }
-        else { None }
-    }).collect();
-
-    star1(&l);
-    star2(&l);
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            let v : Vec<u64> = l.split([',', '-']).map(|e| aoc::parse_at(input, e, "a section id")).collect::<Result<_,_>>()?;
+            if v.len() != 4 {
+                return Err(ParseError::within(input, l, "a pair of ranges A-B,C-D"));
+            }
+            Ok(v)
+        }).collect()
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,17 +1,19 @@
-type Stacks = Vec<Vec<char>>;
-type Moves = Vec<(u64,usize,usize)>;
+use aoc::{Answer, ParseError, Solution};
 
-fn star1(mut s : Stacks, m : &Moves) {
+pub type Stacks = Vec<Vec<char>>;
+pub type Moves = Vec<(u64,usize,usize)>;
+
+fn star1(mut s : Stacks, m : &Moves) -> String {
     for (a,b,c) in m {
         for _ in 0..*a {
             let elem = s[*b].pop().unwrap();
             s[*c].push(elem);
         }
     }
-    println!("{}", s.iter().map(|l| l.last().unwrap()).collect::<String>());
+    s.iter().map(|l| l.last().unwrap()).collect::<String>()
 }
 
-fn star2(mut s : Stacks, m : &Moves) {
+fn star2(mut s : Stacks, m : &Moves) -> String {
     for (a,b,c) in m {
         let mut tmp_stack : Vec<char> = vec![]; // for reversing, ez pz
         for _ in 0..*a {
@@ -22,44 +24,53 @@ fn star2(mut s : Stacks, m : &Moves) {
             s[*c].push(*e)
         }
     }
-    println!("{}", s.iter().map(|l| l.last().unwrap()).collect::<String>());
+    s.iter().map(|l| l.last().unwrap()).collect::<String>()
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let binding = std::fs::read_to_string(args[1].clone()).unwrap();
-    let mut input = binding.split("\n\n");
-    let a : Stacks = input.next().unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            Some(l.chars().collect())
-        }
-        else { None }
-    }).collect();
+pub struct Day05;
 
-    let moves : Moves = input.next().unwrap().lines().filter_map(|l| {
-        let elems : Vec<&str> = l.split(" ").collect();
-        if elems.len() != 6 {
-            None
-        } else {
-            Some((elems[1].parse().unwrap(), elems[3].parse::<usize>().unwrap()-1, elems[5].parse::<usize>().unwrap()-1))
-        }
-    }).collect();
+impl Solution for Day05 {
+    type Input = (Stacks, Moves);
 
-    let n_stacks = (a.last().unwrap().len()+1)/4;
-    let mut l : Vec<Vec<char>> = vec![vec![];n_stacks];
-    for i in (0..a.len()-1).rev() {
-        for j in 0..n_stacks {
-            let elem = a[i][j*4+1];
-            if elem != ' ' {
-                l[j].push(elem);
+    fn parse(input : &str) -> Result<(Stacks, Moves), ParseError> {
+        let mut sections = input.split("\n\n");
+        let a : Stacks = sections.next().ok_or_else(|| ParseError::new("the stacks"))?.lines().filter_map(|l| {
+            if !l.is_empty() {
+                Some(l.chars().collect())
+            }
+            else { None }
+        }).collect();
+
+        let moves : Moves = sections.next().ok_or_else(|| ParseError::new("the moves after the stacks"))?.lines().filter(|l| !l.is_empty()).map(|l| {
+            let elems : Vec<&str> = l.split(' ').collect();
+            let [_, n, _, from, _, to] = elems[..] else {
+                return Err(ParseError::within(input, l, "move N from A to B"));
+            };
+            let stack = |e| match aoc::parse_at::<usize>(input, e, "a stack number")? {
+                0 => Err(ParseError::within(input, e, "a stack number from 1")),
+                s => Ok(s-1)
+            };
+            Ok((aoc::parse_at(input, n, "a number of crates")?, stack(from)?, stack(to)?))
+        }).collect::<Result<_,_>>()?;
+
+        let n_stacks = (a.last().ok_or_else(|| ParseError::new("the stacks"))?.len()+1)/4;
+        let mut l : Vec<Vec<char>> = vec![vec![];n_stacks];
+        for i in (0..a.len()-1).rev() {
+            for j in 0..n_stacks {
+                let elem = a[i][j*4+1];
+                if elem != ' ' {
+                    l[j].push(elem);
+                }
             }
         }
+        Ok((l, moves))
+    }
+
+    fn part1(l : &(Stacks, Moves)) -> Answer {
+        star1(l.0.clone(), &l.1).into()
     }
 
-    star1(l.clone(), &moves);
-    star2(l.clone(), &moves);
+    fn part2(l : &(Stacks, Moves)) -> Answer {
+        star2(l.0.clone(), &l.1).into()
+    }
 }
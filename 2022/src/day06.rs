@@ -1,28 +1,35 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashSet;
 
-type Struct = Vec<char>;
+pub type Struct = Vec<char>;
 
-fn star(l : &Struct, j : usize) {
-    let n = (j..l.len()).filter(|i| {
+fn star(l : &Struct, j : usize) -> usize {
+    let n = (j..l.len()).find(|i| {
         let a : HashSet<&char> = l[i-j..*i].iter().collect();
         a.len() == j
-    }).next();
-    println!("{:?}", n.unwrap());
+    });
+    n.unwrap()
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            Some(l.chars())
-        }
-        else { None }
-    }).flatten().collect();
+pub struct Day06;
 
-    star(&l, 4);
-    star(&l, 14);
+impl Solution for Day06 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        Ok(input.lines().filter_map(|l| {
+            if !l.is_empty() {
+                Some(l.chars())
+            }
+            else { None }
+        }).flatten().collect())
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        star(l, 4).into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        star(l, 14).into()
+    }
 }
@@ -1,10 +1,12 @@
+use aoc::{Answer, ParseError, Solution};
+
 #[derive(Debug)]
-enum FS {
+pub enum FS {
     Dir(String, Vec<FS>),
     File(String, usize),
 }
 
-use crate::FS::*;
+use self::FS::*;
 
 impl FS {
     fn weight(&self) -> u64 {
@@ -33,27 +35,23 @@ impl FS {
     }
 }
 
-type Struct = FS;
+pub type Struct = FS;
 
 fn star1(l : &Struct) -> u64 {
     let w = l.weight();
     let mut final_weight : u64 = 0;
-    match l {
-        Dir(_,v) => {
-            final_weight += v.iter().map(|e| star1(e)).sum::<u64>();
-            if w < 100000 {
-                final_weight += w;
-            }
-        },
-        _ => {}
+    if let Dir(_,v) = l {
+        final_weight += v.iter().map(star1).sum::<u64>();
+        if w < 100000 {
+            final_weight += w;
+        }
     }
     final_weight
 }
 
-fn star2(l : &Struct) {
+fn star2(l : &Struct) -> u64 {
     let needed = 30000000 - (70000000 - l.weight());
-    let min = l.find_min(needed, l.weight());
-    println!("{:?}", min);
+    l.find_min(needed, l.weight())
 }
 
 fn parse(input: &Vec<&str>, mut index: usize) -> (Option<FS>, usize) {
@@ -92,14 +90,20 @@ fn parse(input: &Vec<&str>, mut index: usize) -> (Option<FS>, usize) {
     }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = parse(&std::fs::read_to_string(&args[1].clone()).unwrap().lines().collect(), 0).0.unwrap();
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Struct;
 
-    println!("{:?}", star1(&l));
-    star2(&l);
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        parse(&input.lines().collect(), 0).0.ok_or_else(|| ParseError::new("a root directory"))
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        star2(l).into()
+    }
 }
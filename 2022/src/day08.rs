@@ -1,9 +1,10 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashSet;
 use std::cmp::max;
 
-type Struct = Vec<Vec<i8>>;
+pub type Struct = Vec<Vec<i8>>;
 
-fn star1(l : &Struct) {
+fn star1(l : &Struct) -> usize {
     let mut coords : HashSet<(usize, usize)> = HashSet::new();
 
     let mut f = |i,j,last| {
@@ -27,7 +28,7 @@ fn star1(l : &Struct) {
         let _ = (0..l.len()).rev().fold(-1, |last1, i| f(i,j,last1));
     }
     
-    println!("{:?}", coords.len());
+    coords.len()
 }
 
 fn scenic_score(l : &Struct, (i, j) : (usize, usize)) -> u64 {
@@ -42,11 +43,7 @@ fn scenic_score(l : &Struct, (i, j) : (usize, usize)) -> u64 {
         };
         if k {
             (acc + 1,
-             if k && e < h {
-                 true
-             } else {
-                 false
-             },
+             k && e < h,
              dir)
         } else {
             (acc, k, dir)
@@ -60,29 +57,35 @@ fn scenic_score(l : &Struct, (i, j) : (usize, usize)) -> u64 {
     a*b*c*d
 }
 
-fn star2(l : &Struct) {
+fn star2(l : &Struct) -> u64 {
     let mut m = 0;
     for i in 1..l.len() {
         for j in 1..l[0].len() {
             m = max(scenic_score(l, (i,j)), m);
         }
     }
-    println!("{:?}", m);
+    m
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            Some(l.chars().map(|e| e.to_digit(10).unwrap() as i8).collect())
-        }
-        else { None }
-    }).collect();
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
+            l.char_indices().map(|(i, e)| match e.to_digit(10) {
+                Some(d) => Ok(d as i8),
+                None => Err(ParseError::within(input, &l[i..i+e.len_utf8()], "a tree height"))
+            }).collect()
+        }).collect()
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        star1(l).into()
+    }
 
-    star1(&l);
-    star2(&l);
+    fn part2(l : &Struct) -> Answer {
+        star2(l).into()
+    }
 }
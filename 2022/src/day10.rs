@@ -1,10 +1,13 @@
+use aoc::grid::Grid;
+use aoc::{Answer, ParseError, Solution};
+
 #[derive(PartialEq)]
-enum Ins {
+pub enum Ins {
     NOP,
     ADDX(i32),
 }
 
-use crate::Ins::*;
+use self::Ins::*;
 
 impl Ins {
     fn exec(&self, n : i32) -> i32 {
@@ -15,9 +18,10 @@ impl Ins {
     }
 }
 
-type Struct = Vec<Ins>;
+pub type Struct = Vec<Ins>;
 
-fn stars(l : &Struct) {
+// Value of the X register during each cycle
+fn states(l : &Struct) -> Vec<i32> {
     let mut state : Vec<i32> = vec![1,1,1];
     for x in l {
         let result = x.exec(*state.last().unwrap());
@@ -26,38 +30,46 @@ fn stars(l : &Struct) {
         }
         state.push(result);
     }
-    //Star 1
+    state
+}
+
+fn star1(l : &Struct) -> i32 {
+    let state = states(l);
     let n : Vec<i32> = (0..6).map(|i| {
         let n = i*40+20;
         state[n as usize]*n
     }).collect();
-    println!("{:?}", n.iter().sum::<i32>());
-    // Star2
-        for j in 0..6 {
-        for i in 0..40 {
-            let n = i+j*40;
-            print!("{}", if (i as i32 - state[n+1]).abs() <= 1 {'#'} else {' '});
-        }
-        println!();
-    }
+    n.iter().sum::<i32>()
+}
+
+// The letters on the CRT, or its image if they cannot be read
+fn star2(l : &Struct) -> Answer {
+    let state = states(l);
+    let screen = Grid::from_fn(40, 6, |(i, j)| (i as i32 - state[(i+j*40) as usize+1]).abs() <= 1);
+    aoc::ocr::answer(&screen)
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        input.lines().filter(|l| !l.is_empty()).map(|l| {
             let mut n = l.split_whitespace();
-            Some(match n.next() {
-                Some("addx") => ADDX(n.next().unwrap().parse().unwrap()),
-                _ => NOP
+            Ok(match n.next() {
+                Some("addx") => ADDX(aoc::parse_at(input, n.next().unwrap_or(l), "an addx operand")?),
+                Some("noop") => NOP,
+                _ => return Err(ParseError::within(input, l, "addx N or noop"))
             })
-        }
-        else { None }
-    }).collect();
+        }).collect()
+    }
 
-    stars(&l);
+    fn part1(l : &Struct) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        star2(l)
+    }
 }
@@ -1,18 +1,20 @@
+use aoc::{Answer, ParseError, Solution};
+
 #[derive(Debug)]
-enum OP {
+pub enum OP {
     SUM,
     MUL,
     SUB,
     DIV
 }
 
-use crate::OP::*;
+use self::OP::*;
 
 type Operation = (Option<u64>, OP, Option<u64>);
 type Items = Vec<u64>;
 
 #[derive(Debug)]
-struct Monkey {
+pub struct Monkey {
     operation : Operation, // None == old
     test : (u64, usize, usize) // 1st divisible by, 2nd true, 3rd false
 }
@@ -21,7 +23,7 @@ impl Monkey {
     fn process(&self, items : &Items,  modulo : u64, star1 : bool) -> Vec<(usize,u64)> {
         items.iter().map(|i| {
             let ni = (apply(*i, &self.operation)/ if star1 {3} else {1}) % modulo;
-            if ni % self.test.0 == 0 {
+            if ni.is_multiple_of(self.test.0) {
                 (self.test.1, ni)
             } else {
                 (self.test.2, ni)
@@ -42,10 +44,10 @@ fn apply(n : u64, (e1,op,e2) : &Operation) -> u64 {
     }
 }
 
-type Struct = Vec<(Items,Monkey)>; // Monkey = definitions, 2nd = current items
+pub type Struct = Vec<(Items,Monkey)>; // Monkey = definitions, 2nd = current items
 
-fn stars(l : &Struct, bound : usize) {
-    let modulo = l.iter().map(|(_,m)| m.test.0).fold(1, |acc,n| acc * n);
+fn stars(l : &Struct, bound : usize) -> usize {
+    let modulo = l.iter().map(|(_,m)| m.test.0).product::<u64>();
     let mut items : Vec<Vec<u64>> = l.iter().map(|(i,_)| i.to_vec()).collect();
     let mut freq : Vec<usize> = vec![0;l.len()];
     for _ in 0..bound {
@@ -58,54 +60,59 @@ fn stars(l : &Struct, bound : usize) {
         }
     }
     freq.sort_by(|a,b| b.cmp(a));
-    println!("{:?}", freq[0] * freq[1]);
+    freq[0] * freq[1]
 }
 
-fn parse_items(input : &str) -> Vec<u64> {
-    input.split_whitespace().skip(2).map(|e| e.split(",").next().unwrap().parse().unwrap()).collect()
+fn parse_items(input : &str, line : &str) -> Result<Vec<u64>, ParseError> {
+    line.split_whitespace().skip(2).map(|e| aoc::parse_at(input, e.trim_end_matches(','), "a worry level")).collect()
 }
 
-fn parse_op(input: &str)  -> (Option<u64>, OP, Option<u64>) {
-    let mut e = input.split("=").skip(1).next().unwrap().split_whitespace();
-    let e1 = match e.next() {
-        Some("old") => None,
-        Some(a) => Some(a.parse().unwrap()),
-        _ => None
-    };
-    let op = match e.next() {
-        Some("+") => SUM,
-        Some("*") => MUL,
-        Some("-") => SUB,
-        _ => DIV,
+fn parse_operand(input : &str, e : &str) -> Result<Option<u64>, ParseError> {
+    if e == "old" { Ok(None) } else { aoc::parse_at(input, e, "old or a number").map(Some) }
+}
+
+fn parse_op(input : &str, line : &str) -> Result<Operation, ParseError> {
+    let e : Vec<&str> = line.split('=').nth(1).unwrap_or_default().split_whitespace().collect();
+    let [e1, op, e2] = e[..] else {
+        return Err(ParseError::within(input, line, "an operation new = A op B"));
     };
-    let e2 = match e.next() {
-        Some("old") => None,
-        Some(a) => Some(a.parse().unwrap()),
-        _ => None
+    let op = match op {
+        "+" => SUM,
+        "*" => MUL,
+        "-" => SUB,
+        "/" => DIV,
+        _ => return Err(ParseError::within(input, op, "one of + * - /"))
     };
-    (e1, op, e2)
+    Ok((parse_operand(input, e1)?, op, parse_operand(input, e2)?))
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().split("\n\n").filter_map(|monkey| {
-        if !monkey.is_empty() {
-            let mut e = monkey.lines();
-            e.next();
-            let items = parse_items(e.next().unwrap());
-            let operation = parse_op(e.next().unwrap());
-            let div = e.next().unwrap().split_whitespace().last().unwrap().parse().unwrap();
-            let t = e.next().unwrap().split_whitespace().last().unwrap().parse().unwrap();
-            let f = e.next().unwrap().split_whitespace().last().unwrap().parse().unwrap();
-            Some((items, Monkey {operation, test : (div, t, f)}))
-        }
-        else { None }
-    }).collect();
+fn last_number<T : std::str::FromStr>(input : &str, line : &str) -> Result<T, ParseError> {
+    aoc::parse_at(input, line.split_whitespace().last().unwrap_or(line), "a number ending the line")
+}
+
+pub struct Day11;
 
-    stars(&l, 20);
-    stars(&l, 10000);
+impl Solution for Day11 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        input.split("\n\n").filter(|monkey| !monkey.is_empty()).map(|monkey| {
+            let mut e = monkey.lines().skip(1);
+            let mut line = || e.next().ok_or_else(|| ParseError::within(input, monkey, "a monkey described in 6 lines"));
+            let items = parse_items(input, line()?)?;
+            let operation = parse_op(input, line()?)?;
+            let div = last_number(input, line()?)?;
+            let t = last_number(input, line()?)?;
+            let f = last_number(input, line()?)?;
+            Ok((items, Monkey {operation, test : (div, t, f)}))
+        }).collect()
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        stars(l, 20).into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        stars(l, 10000).into()
+    }
 }
@@ -1,13 +1,15 @@
+use aoc::{Answer, ParseError, Solution};
+
 #[derive(Debug)]
-enum Elem {
+pub enum Elem {
     List(Vec<Elem>),
     Int(i32)
 }
 
-use crate::Elem::*;
+use self::Elem::*;
 use std::cmp::Ordering;
 
-type Struct = Vec<(Elem,Elem)>;
+pub type Struct = Vec<(Elem,Elem)>;
 
 impl PartialEq for Elem {
     fn eq(&self, other: &Self) -> bool {
@@ -33,7 +35,7 @@ impl Ord for Elem {
     fn cmp(&self, other: &Self) -> Ordering {
         let f = |v1 : &Vec<Elem>, v2 : &Vec<Elem>| {
             let r = v1.iter().zip(v2).fold(Ordering::Equal, |acc,(e1,e2)| {
-                if acc == Ordering::Equal {(*e1).cmp(&e2)} else {acc}});
+                if acc == Ordering::Equal {(*e1).cmp(e2)} else {acc}});
             if r == Ordering::Equal {
                 v1.len().cmp(&v2.len())
             } else {
@@ -49,7 +51,7 @@ impl Ord for Elem {
     }
 }
 
-fn star1(l : &Struct) {
+fn star1(l : &Struct) -> usize {
     let r = l.iter().enumerate().fold(0, |acc,(i,(a,b))| {
         if a < b {
             acc + i + 1
@@ -57,11 +59,11 @@ fn star1(l : &Struct) {
             acc
         }
     });
-    println!("{}", r);
+    r
 }
 
-fn star2(l : Struct) {
-    let mut l : Vec<&Elem> = l.iter().map(|(a,b)| [a,b]).flatten().collect();
+fn star2(l : &Struct) -> usize {
+    let mut l : Vec<&Elem> = l.iter().flat_map(|(a,b)| [a,b]).collect();
     let v1 = List(vec![List(vec![Int(2)])]);
     let v2 = List(vec![List(vec![Int(6)])]);
     l.push(&v1);
@@ -74,7 +76,7 @@ fn star2(l : Struct) {
             None
         }
     });
-    println!("{:?}", pos.fold(1, |acc,n| n*acc));
+    pos.product()
 }
 
 fn parse(input : &[char], index : usize) -> (Elem,usize) {
@@ -99,22 +101,27 @@ fn parse(input : &[char], index : usize) -> (Elem,usize) {
     }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().split("\n\n").filter_map(|l| {
-        if !l.is_empty() {
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        input.split("\n\n").filter(|l| !l.is_empty()).map(|l| {
             let mut n = l.lines();
-            let l1 : Vec<char> = n.next().unwrap().chars().collect();
-            let l2 : Vec<char> = n.next().unwrap().chars().collect();
-            Some((parse(&l1, 0).0, parse(&l2, 0).0))
-        }
-        else { None }
-    }).collect();
+            let mut packet = || -> Result<Elem, ParseError> {
+                let line : Vec<char> = n.next().ok_or_else(|| ParseError::within(input, l, "a pair of packets"))?.chars().collect();
+                Ok(parse(&line, 0).0)
+            };
+            Ok((packet()?, packet()?))
+        }).collect()
+    }
+
+    fn part1(l : &Struct) -> Answer {
+        star1(l).into()
+    }
 
-    star1(&l);
-    star2(l);
+    fn part2(l : &Struct) -> Answer {
+        star2(l).into()
+    }
 }
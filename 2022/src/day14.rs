@@ -1,18 +1,34 @@
+use aoc::render::{Colour, Frame, Legend};
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashSet;
 
-type Struct<'a> = HashSet<(usize,usize)>;
+pub type Struct = HashSet<(usize,usize)>;
 
 fn next((x,y) : (usize, usize)) -> [(usize,usize);3] {
     [(x,y+1),(x-1,y+1),(x+1,y+1)]
 }
 
-fn stars(mut l : Struct) {
+// The rocks and the sand at rest, without the floor, as far as the sand can spread
+fn frame(rocks : &Struct, l : &Struct, floor : usize) -> Frame {
+    let pos = |(x,y) : &(usize,usize)| (*x as isize, *y as isize);
+    let f = floor as isize;
+    Frame::new().bounds((500-f,0), (500+f,f-1))
+        .points(l.iter().filter(|c| c.1 != floor && !rocks.contains(c)).map(pos), 'o')
+        .points(rocks.iter().map(pos), '#')
+        .point((500,0), '+')
+}
+
+// Units of sand at rest before they fall into the void, and once the source is blocked
+fn stars(mut l : Struct) -> (usize, usize) {
+    let rocks = l.clone();
+    let legend = Legend::new().entry('#', Colour::Grey, "rock").entry('o', Colour::Yellow, "sand").entry('+', Colour::Red, "source");
     let to_void = l.iter().max_by(|x, y| x.1.cmp(&y.1)).unwrap().1;
     for i in 0..1000 { // Floor, for star2
         l.insert((i,to_void+2));
     }
 
     let mut star1 = false;
+    let mut resting = 0;
     let wall_size = l.len();
     
     loop {
@@ -28,24 +44,25 @@ fn stars(mut l : Struct) {
             }
             if !falling {
                 l.insert(sand);
+                aoc::animate!(legend, frame(&rocks, &l, to_void+2), "{} units of sand", l.len()-wall_size);
                 break;
             }
         }
         if !star1 && sand.1 > to_void {
             star1 = true;
-            println!("{:?}", l.len()-wall_size);
+            resting = l.len()-wall_size;
         }
         if sand == (500,0) { // Star2
             break;
         }
     }
-    println!("{:?}", l.len()-wall_size);
+    (resting, l.len()-wall_size)
 }
 
 fn parse(input : &str) -> Vec<(usize,usize)> {
-    let pos : Vec<(usize,usize)> = input.split(" -> ").filter_map(|e| {
+    let pos : Vec<(usize,usize)> = input.split(" -> ").map(|e| {
         let mut el = e.split(",");
-        Some((el.next().unwrap().parse().unwrap(), el.next().unwrap().parse().unwrap()))
+        (el.next().unwrap().parse().unwrap(), el.next().unwrap().parse().unwrap())
     }).collect();
     let mut grid = Vec::new();
     for ((x,y),(i,j)) in pos.iter().zip(&pos[1..]) {
@@ -68,19 +85,25 @@ fn parse(input : &str) -> Vec<(usize,usize)> {
     grid
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            Some(parse(l))
-        }
-        else { None }
-    }).flatten().collect();
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Struct;
+
+    fn parse(input : &str) -> Result<Struct, ParseError> {
+        Ok(input.lines().filter_map(|l| {
+            if !l.is_empty() {
+                Some(parse(l))
+            }
+            else { None }
+        }).flatten().collect())
+    }
 
-    stars(l);
-    // star2(l);
+    fn part1(l : &Struct) -> Answer {
+        stars(l.clone()).0.into()
+    }
+
+    fn part2(l : &Struct) -> Answer {
+        stars(l.clone()).1.into()
+    }
 }
@@ -1,7 +1,19 @@
+use aoc::{Answer, InputKind, Param, ParseError, Solution};
+
 type Coord = (i64,i64);
-type Struct = Vec<(Coord,i64)>; // Center + Radius
+pub type Struct = Vec<(Coord,i64)>; // Center + Radius
+
+// The row of part 1 and the largest coordinate of part 2
+const ROW : Param<i64> = Param { real: 2000000, example: 10 };
+const MAX : Param<i64> = Param { real: 4000000, example: 20 };
+
+pub struct Scan {
+    sensors: Struct,
+    row: i64,
+    max: i64,
+}
 
-fn merge(s : &Vec<(i64,i64)>) -> Vec<(i64, i64)> {
+fn merge(s : &[(i64,i64)]) -> Vec<(i64, i64)> {
     let (mut cl, mut cr) = s[0];
     let mut v = vec![];
     for (l,r) in &s[1..] {
@@ -24,38 +36,39 @@ fn row_stats(l : &Struct, row : i64) -> Vec<(i64,i64)> {
     let mut overlaps = Vec::new();
     for ((x,y),rad) in l {
         if row >= y-rad && row <= y+rad { // It overlaps with the row
-            if row < *y { // TOP
+            // The sensor's own row belongs to the top half, or no branch would cover it
+            if row <= *y { // TOP
                 let dif = ((y-rad) - row).abs();
-                // println!("{:?}", (((x,y),rad),(x-dif,x+dif)));
+                aoc::trace!("{:?}", (((x,y),rad),(x-dif,x+dif)));
                 overlaps.push((x-dif,x+dif));
             } else if row > *y { // BOTTOM
                 let dif = ((y+rad) - row).abs();
-                // println!("{:?}", (((x,y),rad),(x-dif,x+dif)));
+                aoc::trace!("{:?}", (((x,y),rad),(x-dif,x+dif)));
                 overlaps.push((x-dif,x+dif));
             }
         }
     }
     overlaps.sort();
-    let m = merge(&overlaps);
-    m
+    
+    merge(&overlaps)
 }
 
-fn star1(l : &Struct) {
-    println!("{:?}", row_stats(&l, 2000000).iter().map(|(x,y)| (x-y).abs()).sum::<i64>());
+fn star1(l : &Struct, row : i64) -> i64 {
+    row_stats(l, row).iter().map(|(x,y)| (x-y).abs()).sum::<i64>()
 }
 
-fn star2(l : &Struct) {
+fn star2(l : &Struct, max : i64) -> i64 {
     let mut found = (0,0);
-    for y in 0..4000000 {
+    for y in 0..max {
         let u = row_stats(l, y);
         for ((_,right),(next_left,_)) in u.iter().zip(u.iter().skip(1)) {
             // This may fail, there could be adjacent points in the vertical axises
-            if right+1 > 0 && right+1 < 4000000 && *next_left == right+2 {
+            if right+1 > 0 && right+1 < max && *next_left == right+2 {
                 found = (right+1,y);
             }
         }
     }
-    println!("{:?}", found.0*4000000+found.1);
+    found.0*4000000+found.1
 }
 
 fn parse(input: &str) -> Option<(Coord,i64)> {
@@ -70,19 +83,26 @@ fn parse(input: &str) -> Option<(Coord,i64)> {
     }
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let l : Struct = std::fs::read_to_string(args[1].clone()).unwrap().lines().filter_map(|l| {
-        if !l.is_empty() {
-            parse(l)
-        }
-        else { None }
-    }).collect();
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
 
-    star1(&l);
-    star2(&l);
+    fn parse_with(input : &str, kind : InputKind) -> Result<Scan, ParseError> {
+        let sensors = input.lines().filter_map(|l| {
+            if !l.is_empty() {
+                parse(l)
+            }
+            else { None }
+        }).collect();
+        Ok(Scan { sensors, row: ROW.get(kind), max: MAX.get(kind) })
+    }
+
+    fn part1(s : &Scan) -> Answer {
+        star1(&s.sensors, s.row).into()
+    }
+
+    fn part2(s : &Scan) -> Answer {
+        star2(&s.sensors, s.max).into()
+    }
 }
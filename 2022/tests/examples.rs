@@ -0,0 +1,33 @@
+use aoc::example;
+use aoc2022::*;
+
+example!(day01: day01::Day01, "day01.txt", part1 = 24000, part2 = 45000);
+example!(day02: day02::Day02, "day02.txt", part1 = 15, part2 = 12);
+example!(day03: day03::Day03, "day03.txt", part1 = 157, part2 = 70);
+example!(day04: day04::Day04, "day04.txt", part1 = 2, part2 = 4);
+example!(day05: day05::Day05, "day05.txt", part1 = "CMZ", part2 = "MCD");
+example!(day06: day06::Day06, "day06.txt", part1 = 7, part2 = 19);
+example!(day07: day07::Day07, "day07.txt", part1 = 95437, part2 = 24933642);
+example!(day08: day08::Day08, "day08.txt", part1 = 21, part2 = 8);
+example!(day09: day09::Day09, "day09.txt", part1 = 13, part2 = 1);
+example!(day09_larger: day09::Day09, "day09-2.txt", part2 = 36);
+example!(
+    day10: day10::Day10,
+    "day10.txt",
+    part1 = 13140,
+    part2 = concat!(
+        "##..##..##..##..##..##..##..##..##..##..\n",
+        "###...###...###...###...###...###...###.\n",
+        "####....####....####....####....####....\n",
+        "#####.....#####.....#####.....#####.....\n",
+        "######......######......######......####\n",
+        "#######.......#######.......#######.....\n",
+    )
+);
+example!(day11: day11::Day11, "day11.txt", part1 = 10605, part2 = 2713310158u64);
+example!(day12: day12::Day12, "day12.txt", part1 = 31, part2 = 29);
+example!(day13: day13::Day13, "day13.txt", part1 = 13, part2 = 140);
+example!(day14: day14::Day14, "day14.txt", part1 = 24, part2 = 93);
+example!(day15: day15::Day15, "day15.txt", part1 = 26, part2 = 56000011);
+// A sensor on the row of part 1 covers it too
+example!(day15_sensor_row: day15::Day15, "day15-2.txt", part1 = 4);
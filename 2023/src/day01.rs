@@ -1,16 +1,18 @@
-fn star1(l : &Vec<String>) {
+use aoc::{Answer, ParseError, Solution};
+
+fn star1(l : &[String]) -> u32 {
     let r : u32 = l.iter().filter_map(|code| {
         let v : Vec<u32> = code.chars().filter_map(|c| c.to_digit(10)).collect();
         Some(v.first()?*10 + v.last()?)
     }).sum();
-    println!("{:?}", r);
+    r
 }
 
 fn subs(s : &str, from : &str, to : &str) -> String {
     str::replace(s, from, to)
 }
 
-fn star2(l : &Vec<String>) {
+fn star2(l : &[String]) -> u32 {
     let new_l : Vec<String> = l.iter()
         .map(|line| subs(line, "one", "one1one"))
         .map(|line| subs(&line, "two", "two2two"))
@@ -24,15 +26,20 @@ fn star2(l : &Vec<String>) {
     star1(&new_l)
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Feed me with the input!");
-        std::process::exit(1);
-    };
-    let binding = std::fs::read_to_string(args[1].clone()).unwrap();
-    let l : Vec<String> = binding.lines().map(|s| s.to_string()).collect();
-
-    star1(&l);
-    star2(&l);
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input : &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
+
+    fn part1(l : &Vec<String>) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l : &Vec<String>) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,70 +1,73 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashMap;
 
-use aoc::input_file;
-
 #[derive(Debug, Hash, PartialEq, Eq)]
-enum COLOR {
+pub enum COLOR {
     RED,
     GREEN,
     BLUE,
 }
 
 #[derive(Debug)]
-struct Data(Vec<(u32, Vec<HashMap<COLOR, usize>>)>);
+pub struct Data(Vec<(u32, Vec<HashMap<COLOR, usize>>)>);
 
 // Implementation to parse input string into Data structure
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         Ok(Data(
             input
                 .lines()
                 .map(|line| {
-                    let mut parts = line.split(':');
-                    (
-                        parts
-                            .next()
-                            .expect("Missing ID number")
-                            .split_whitespace()
-                            .last()
-                            .expect("Game does not have a number")
-                            .parse()
-                            .unwrap(),
-                        parts
-                            .next()
-                            .expect("Missing operands")
-                            .split(";")
-                            .map(|subsets| {
-                                subsets
-                                    .split(",")
+                    let (game, subsets) = line
+                        .split_once(':')
+                        .ok_or_else(|| ParseError::within(input, line, "Game N: SUBSETS"))?;
+                    Ok((
+                        aoc::parse_at(
+                            input,
+                            game.split_whitespace().last().unwrap_or(game),
+                            "a game number",
+                        )?,
+                        subsets
+                            .split(';')
+                            .map(|subset| {
+                                subset
+                                    .split(',')
                                     .map(|n_balls| {
                                         let mut it = n_balls.split_whitespace();
-                                        let n = it
-                                            .next()
-                                            .expect("missing number of balls")
-                                            .parse()
-                                            .expect("cannot parse number of balls");
-                                        let color = match it.next().expect("missing color") {
+                                        let (Some(n), Some(color)) = (it.next(), it.next()) else {
+                                            return Err(ParseError::within(
+                                                input,
+                                                n_balls,
+                                                "a number of balls and a color",
+                                            ));
+                                        };
+                                        let color = match color {
                                             "red" => COLOR::RED,
                                             "green" => COLOR::GREEN,
                                             "blue" => COLOR::BLUE,
-                                            _ => panic!("unknown color"),
+                                            _ => {
+                                                return Err(ParseError::within(
+                                                    input,
+                                                    color,
+                                                    "red, green or blue",
+                                                ))
+                                            }
                                         };
-                                        (color, n)
+                                        Ok((color, aoc::parse_at(input, n, "a number of balls")?))
                                     })
                                     .collect()
                             })
-                            .collect(),
-                    )
+                            .collect::<Result<_, _>>()?,
+                    ))
                 })
-                .collect(),
+                .collect::<Result<_, ParseError>>()?,
         ))
     }
 }
 
-fn stars(Data(v): &Data) {
-    let c = v
-        .iter()
+fn star1(Data(v): &Data) -> u32 {
+    v.iter()
         .filter_map(|(id, game)| {
             game.iter()
                 .all(|subset| {
@@ -76,9 +79,10 @@ fn stars(Data(v): &Data) {
                 })
                 .then_some(id)
         })
-        .collect::<Vec<&u32>>();
-    println!("Part 1: {c:?}");
+        .sum()
+}
 
+fn star2(Data(v): &Data) -> usize {
     let c2 = v.iter().map(|(_, game)| {
         let mut m = HashMap::new();
         game.iter().for_each(|subset| {
@@ -94,15 +98,24 @@ fn stars(Data(v): &Data) {
         });
         m.values().product::<usize>()
     });
-    println!("Part 2: {}", c2.sum::<usize>());
+    c2.sum::<usize>()
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into Data structure
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
+pub struct Day02;
 
-    stars(&parsed_data);
-    Ok(())
+impl Solution for Day02 {
+    type Input = Data;
+
+    // Parse input into Data structure
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data).into()
+    }
 }
@@ -1,6 +1,5 @@
-use std::collections::{HashMap, HashSet};
-
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
+use std::collections::HashSet;
 
 /// Represents a 2D coordinate with row and column indices
 type Coord = (usize, usize);
@@ -9,14 +8,14 @@ type Coord = (usize, usize);
 /// symbols: Set of symbol characters and their coordinates
 /// nums: Set of numbers with their value, row, and start/end column positions
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     symbols: HashSet<(Coord, char)>,
     nums: HashSet<(u32, usize, (usize, usize))>,
 }
 
 /// Implementation to parse input string into Data structure
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut symbols = HashSet::new();
         let mut nums = HashSet::new();
@@ -63,7 +62,7 @@ impl std::str::FromStr for Data {
 /// Calculates solutions for both parts of the puzzle
 /// Part 1: Sum of all numbers adjacent to any symbol
 /// Part 2: Sum of gear ratios (product of exactly two numbers adjacent to '*')
-fn stars(Data { symbols, nums }: &Data) {
+fn stars(Data { symbols, nums }: &Data) -> (u32, u32) {
     let mut adjacent_numbers: HashSet<(u32, usize, (usize, usize))> = HashSet::new();
     let mut gear_ratio_sum = 0;
 
@@ -89,19 +88,26 @@ fn stars(Data { symbols, nums }: &Data) {
             gear_ratio_sum += current_ratio;
         }
     }
-    println!(
-        "Star1: {}",
-        adjacent_numbers.iter().map(|n| n.0).sum::<u32>()
-    );
-    println!("Star2: {gear_ratio_sum}")
+    (
+        adjacent_numbers.iter().map(|n| n.0).sum::<u32>(),
+        gear_ratio_sum,
+    )
 }
 
-/// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into Data structure
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
 
-    stars(&parsed_data);
-    Ok(())
+    fn part1(parsed_data: &Data) -> Answer {
+        stars(parsed_data).0.into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        stars(parsed_data).1.into()
+    }
 }
@@ -1,11 +1,13 @@
-pub fn input_file(caller: &str) -> String {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() >= 2 {
-        args[1].clone()
-    } else {
-        format!(
-            "input/{}",
-            caller.to_string()[caller.len() - 5..caller.len() - 3].to_string()
-        )
-    }
-}
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const YEAR: aoc::Year = aoc::Year {
+    year: 2023,
+    dir: env!("CARGO_MANIFEST_DIR"),
+    days: &[
+        (1, aoc::solution::<day01::Day01>()),
+        (2, aoc::solution::<day02::Day02>()),
+        (3, aoc::solution::<day03::Day03>()),
+    ],
+};
@@ -0,0 +1,7 @@
+use aoc::example;
+use aoc2023::*;
+
+example!(day01: day01::Day01, "day01.txt", part1 = 142);
+example!(day01_spelled: day01::Day01, "day01-2.txt", part2 = 281);
+example!(day02: day02::Day02, "day02.txt", part1 = 8, part2 = 2286);
+example!(day03: day03::Day03, "day03.txt", part1 = 4361, part2 = 467835);
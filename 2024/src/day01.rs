@@ -1,17 +1,17 @@
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
 
 type Data = (Vec<u32>, Vec<u32>);
 
-fn star1(l: &mut Data) {
-    l.0.sort();
-    l.1.sort();
-    let r = std::iter::zip(&l.0, &l.1)
-        .map(|(a, b)| (*a as i32 - *b as i32).abs() as u64)
-        .sum::<u64>();
-    println!("Star1: {}", r);
+fn star1(l: &Data) -> u64 {
+    let (mut left, mut right) = l.clone();
+    left.sort();
+    right.sort();
+    std::iter::zip(&left, &right)
+        .map(|(a, b)| (*a as i32 - *b as i32).unsigned_abs() as u64)
+        .sum::<u64>()
 }
 
-fn star2(l: &Data) {
+fn star2(l: &Data) -> u64 {
     let mut similarity_score: u64 = 0;
     // Count occurrences in first vector
     let mut counts0 = std::collections::HashMap::new();
@@ -31,23 +31,36 @@ fn star2(l: &Data) {
         }
     }
 
-    println!("Star2: {}", similarity_score);
+    similarity_score
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let mut l: Data = input_raw
-        .lines()
-        .map(|s| {
-            let mut parts = s.split_whitespace();
-            (
-                parts.next().unwrap_or("0").parse::<u32>().unwrap(),
-                parts.next().unwrap_or("0").parse::<u32>().unwrap(),
-            )
-        })
-        .unzip();
-
-    star1(&mut l);
-    star2(&l);
-    Ok(())
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        let pairs = input_raw
+            .lines()
+            .map(|s| {
+                let mut parts = s.split_whitespace();
+                let (Some(a), Some(b), None) = (parts.next(), parts.next(), parts.next()) else {
+                    return Err(ParseError::within(input_raw, s, "two location ids"));
+                };
+                Ok((
+                    aoc::parse_at::<u32>(input_raw, a, "a location id")?,
+                    aoc::parse_at::<u32>(input_raw, b, "a location id")?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1(l: &Data) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l: &Data) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,13 +1,12 @@
+use aoc::{Answer, ParseError, Solution};
 use std::cmp::Ordering;
 
-use aoc::input_file;
-
 // Type alias for a vector of vectors containing integers
 type Data = Vec<Vec<i32>>;
 
 // First star solution: Check if sequences follow a strictly increasing or decreasing pattern
 // with differences between consecutive elements in range [1,3] or [-3,-1] respectively
-fn star1(l: &Data) {
+fn star1(l: &Data) -> usize {
     let r: Vec<bool> =
         l.iter()
             .map(|v| {
@@ -18,18 +17,18 @@ fn star1(l: &Data) {
                 v.iter().zip(v.iter().skip(1)).map(|(a, b)| a - b).fold(
                     v[0].cmp(&v[1]),
                     |dir, diff| match dir {
-                        Ordering::Greater if diff >= 1 && diff <= 3 => Ordering::Greater,
-                        Ordering::Less if diff >= -3 && diff <= -1 => Ordering::Less,
+                        Ordering::Greater if (1..=3).contains(&diff) => Ordering::Greater,
+                        Ordering::Less if (-3..=-1).contains(&diff) => Ordering::Less,
                         _ => Ordering::Equal,
                     },
                 ) != Ordering::Equal
             })
             .collect();
-    println!("Star1: {}", r.iter().filter(|a| **a).count());
+    r.iter().filter(|a| **a).count()
 }
 
 // Second star solution: Similar to star1 but allows skipping one element to make pattern valid
-fn star2(l: &Data) {
+fn star2(l: &Data) -> usize {
     // Helper function that checks if a sequence follows the pattern, with option to skip one element
     // Returns true if sequence is valid after potentially skipping one element
     let solve = |v: &Vec<i32>, mut skipped: bool| {
@@ -38,7 +37,7 @@ fn star2(l: &Data) {
         for e in v.iter().skip(1) {
             let diff = (last - e).abs();
             // Check if current element breaks pattern
-            if last.cmp(e) != current_order || !(diff >= 1 && diff <= 3) {
+            if last.cmp(e) != current_order || !(1..=3).contains(&diff) {
                 if skipped {
                     return false; // Already skipped one element, sequence invalid
                 }
@@ -58,34 +57,47 @@ fn star2(l: &Data) {
             // 2. Skip first element
             // 3. Skip second element
             solve(v, false)
-                || solve(&v.iter().skip(1).map(|x| *x).collect(), true)
+                || solve(&v.iter().skip(1).copied().collect(), true)
                 || solve(
-                    &v.iter()
-                        .take(1)
-                        .chain(v.iter().skip(2))
-                        .map(|x| *x)
-                        .collect(),
+                    &v.iter().take(1).chain(v.iter().skip(2)).copied().collect(),
                     true,
                 )
         })
         .collect();
-    println!("Star2: {}", r.iter().filter(|a| **a).count());
+    r.iter().filter(|a| **a).count()
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into vector of integer vectors
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let l: Data = input_raw
-        .lines()
-        .map(|s| {
-            s.split_whitespace()
-                .map(|e: &str| e.parse::<i32>().unwrap())
-                .collect()
-        })
-        .collect();
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Data;
+
+    // Parse input into vector of integer vectors, each report having a direction to follow
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw
+            .lines()
+            .map(|s| {
+                let levels = s
+                    .split_whitespace()
+                    .map(|e| aoc::parse_at::<i32>(input_raw, e, "a level"))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if levels.len() < 2 {
+                    return Err(ParseError::within(
+                        input_raw,
+                        s,
+                        "a report of at least two levels",
+                    ));
+                }
+                Ok(levels)
+            })
+            .collect()
+    }
+
+    fn part1(l: &Data) -> Answer {
+        star1(l).into()
+    }
 
-    star1(&l);
-    star2(&l);
-    Ok(())
+    fn part2(l: &Data) -> Answer {
+        star2(l).into()
+    }
 }
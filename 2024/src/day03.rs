@@ -1,10 +1,10 @@
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
 
-struct Data(Vec<(u64, u64, bool)>);
+pub struct Data(Vec<(u64, u64, bool)>);
 
 // Associated regex mul\((\d{1,3}),(\d{1,3})\)
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut v = Vec::new();
@@ -36,7 +36,7 @@ impl std::str::FromStr for Data {
                 ) {
                     let mut x = String::new();
                     let mut counter = 0;
-                    'num: while let Some(n) = it.next() {
+                    'num: for n in it.by_ref() {
                         counter += 1;
                         if n == ',' {
                             break 'num;
@@ -46,13 +46,13 @@ impl std::str::FromStr for Data {
                         }
                         x.push(n);
                     }
-                    if x.len() < 1 || x.len() > 3 {
+                    if x.is_empty() || x.len() > 3 {
                         continue 'operation;
                     }
                     counter = 0;
 
                     let mut y = String::new();
-                    'num: while let Some(n) = it.next() {
+                    'num: for n in it.by_ref() {
                         if n == ')' {
                             break 'num;
                         }
@@ -62,7 +62,7 @@ impl std::str::FromStr for Data {
                         y.push(n);
                     }
 
-                    if y.len() < 1 || y.len() > 3 {
+                    if y.is_empty() || y.len() > 3 {
                         continue 'operation;
                     }
 
@@ -77,30 +77,26 @@ impl std::str::FromStr for Data {
     }
 }
 
-fn star1(l: &Data) {
-    let Data(ops) = l;
-    println!(
-        "Star1: {}",
-        ops.iter().fold(0, |acc, (n1, n2, _)| acc + (n1 * n2)),
-    );
-}
+pub struct Day03;
 
-fn star2(l: &Data) {
-    let Data(ops) = l;
-    println!(
-        "Star1: {}",
-        ops.iter()
-            .fold(0, |acc, (n1, n2, b)| acc + if *b { n1 * n2 } else { 0 }),
-    );
-}
+impl Solution for Day03 {
+    type Input = Data;
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into vector of integer vectors
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let l: Data = input_raw.parse()?;
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
 
-    star1(&l);
-    star2(&l);
-    Ok(())
+    fn part1(l: &Data) -> Answer {
+        let Data(ops) = l;
+        ops.iter()
+            .fold(0, |acc, (n1, n2, _)| acc + (n1 * n2))
+            .into()
+    }
+
+    fn part2(l: &Data) -> Answer {
+        let Data(ops) = l;
+        ops.iter()
+            .fold(0, |acc, (n1, n2, b)| acc + if *b { n1 * n2 } else { 0 })
+            .into()
+    }
 }
@@ -1,82 +1,87 @@
+use aoc::{Answer, ParseError, Solution};
 use std::cmp::Ordering::*;
 use std::collections::{HashMap, HashSet};
 
-use aoc::input_file;
-
 type PreOrder = HashMap<u32, HashSet<u32>>;
 type PostOrder = HashMap<u32, HashSet<u32>>;
 #[derive(Debug)]
-struct Data((PreOrder, PostOrder), Vec<Vec<u32>>);
+pub struct Data((PreOrder, PostOrder), Vec<Vec<u32>>);
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut i = s.split("\n\n");
-        let ordering = i.next().expect("page ordering");
-        let update = i.next().expect("page update");
+        let (ordering, update) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("page updates after the ordering rules"))?;
+        let rules = ordering
+            .lines()
+            .map(|line| {
+                let (x, y) = line
+                    .split_once('|')
+                    .ok_or_else(|| ParseError::within(s, line, "an ordering rule X|Y"))?;
+                Ok((
+                    aoc::parse_at(s, x, "a page number")?,
+                    aoc::parse_at(s, y, "a page number")?,
+                ))
+            })
+            .collect::<Result<Vec<(u32, u32)>, ParseError>>()?;
+        let update = update
+            .lines()
+            .map(|line| {
+                line.split(',')
+                    .map(|x| aoc::parse_at(s, x, "a page number"))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Data(
-            ordering
-                .lines()
-                .map(|line| {
-                    let mut l = line.split("|");
-                    let x = l.next().expect("x").parse().expect("x");
-                    let y = l.next().expect("y").parse().expect("y");
-                    (x, y)
-                })
-                .fold(
-                    (HashMap::new(), HashMap::new()),
-                    |(mut preorder, mut postorder), (x, y)| {
-                        preorder
-                            .entry(x)
-                            .and_modify(|e| {
-                                e.insert(y);
-                            })
-                            .or_insert_with(|| {
-                                let mut v = HashSet::new();
-                                v.insert(y);
-                                v
-                            });
-                        postorder
-                            .entry(y)
-                            .and_modify(|e| {
-                                e.insert(x);
-                            })
-                            .or_insert_with(|| {
-                                let mut v = HashSet::new();
-                                v.insert(x);
-                                v
-                            });
-                        (preorder, postorder)
-                    },
-                ),
-            update
-                .lines()
-                .map(|line| {
-                    line.split(",")
-                        .map(|x| x.parse().expect("parse num"))
-                        .collect()
-                })
-                .collect(),
+            rules.into_iter().fold(
+                (HashMap::new(), HashMap::new()),
+                |(mut preorder, mut postorder), (x, y)| {
+                    preorder
+                        .entry(x)
+                        .and_modify(|e| {
+                            e.insert(y);
+                        })
+                        .or_insert_with(|| {
+                            let mut v = HashSet::new();
+                            v.insert(y);
+                            v
+                        });
+                    postorder
+                        .entry(y)
+                        .and_modify(|e| {
+                            e.insert(x);
+                        })
+                        .or_insert_with(|| {
+                            let mut v = HashSet::new();
+                            v.insert(x);
+                            v
+                        });
+                    (preorder, postorder)
+                },
+            ),
+            update,
         ))
     }
 }
 
-fn stars(l: &Data) {
+// Sum of the middle pages of the correctly ordered updates, and the badly ordered ones
+fn check_order(l: &Data) -> (u32, Vec<Vec<u32>>) {
     let Data((preorder, postorder), update) = l;
     let mut r = 0;
     let mut badly_ordered = Vec::new();
 
     'outer: for v in update {
         let mut checked = Vec::new();
-        'inner: for e in v {
+        for e in v {
             for i in &checked {
-                if let Some(x) = preorder.get(&i) {
+                if let Some(x) = preorder.get(i) {
                     if !x.contains(e) {
                         badly_ordered.push(v.clone());
                         continue 'outer;
                     }
-                } else if let Some(x) = postorder.get(&e) {
+                } else if let Some(x) = postorder.get(e) {
                     if !x.contains(i) {
                         badly_ordered.push(v.clone());
                         continue 'outer;
@@ -90,8 +95,16 @@ fn stars(l: &Data) {
         }
         r += v[v.len() / 2];
     }
-    println!("Star1: {r:?}");
+    (r, badly_ordered)
+}
 
+fn star1(l: &Data) -> u32 {
+    check_order(l).0
+}
+
+fn star2(l: &Data) -> u32 {
+    let Data((preorder, postorder), _) = l;
+    let (_, badly_ordered) = check_order(l);
     let mut r2 = 0;
     for mut v in badly_ordered {
         v.sort_by(|x, y| match preorder.get(x) {
@@ -115,15 +128,23 @@ fn stars(l: &Data) {
         });
         r2 += v[v.len() / 2];
     }
-    println!("Star2: {r2:?}");
+    r2
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into vector of integer vectors
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let l: Data = input_raw.parse()?;
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Data;
 
-    stars(&l);
-    Ok(())
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(l: &Data) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l: &Data) -> Answer {
+        star2(l).into()
+    }
 }
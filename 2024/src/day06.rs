@@ -1,95 +1,79 @@
-use std::{cell::RefCell, collections::HashMap, collections::HashSet, rc::Rc};
-
-use aoc::input_file;
+use aoc::cycle::{self, History};
+use aoc::geometry::{Dir4, Point2};
+use aoc::grid::{Grid, Pos};
+use aoc::{Answer, ParseError, Solution};
+use std::collections::HashSet;
 
 #[derive(Debug)]
-struct Data(
-    std::collections::HashMap<(isize, isize), char>,
-    (isize, isize),
-);
+pub struct Data(Grid<char>, Pos);
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let st = Rc::new(RefCell::new(None));
-        let r = s
-            .lines()
-            .enumerate()
-            .map(|(line_n, line)| {
-                let st: Rc<RefCell<Option<(isize, isize)>>> = Rc::clone(&st);
-                line.chars().enumerate().map(move |(column_n, c)| {
-                    if c == '^' {
-                        st.replace(Some((line_n as isize, column_n as isize)));
-                    }
-                    ((line_n as isize, column_n as isize), c)
-                })
-            })
-            .flatten()
-            .collect::<std::collections::HashMap<(isize, isize), char>>();
-
-        Ok(Data(r, Rc::try_unwrap(st).unwrap().into_inner().unwrap()))
+        let map: Grid<char> = s.parse()?;
+        let start = map
+            .find(|c| *c == '^')
+            .ok_or_else(|| ParseError::new("a guard '^' on the map"))?;
+        Ok(Data(map, start))
     }
 }
 
-fn solve(
-    map: &HashMap<(isize, isize), char>,
-    (mut x, mut y): (isize, isize),
-) -> Option<HashSet<((isize, isize), (isize, isize))>> {
-    let mut next = (-1, 0);
-    let mut met: HashSet<((isize, isize), (isize, isize))> = HashSet::from([((x, y), next)]);
-    let obstacle_found = |cur| match cur {
-        (0, -1) => (-1, 0),
-        (1, 0) => (0, -1),
-        (0, 1) => (1, 0),
-        (-1, 0) => (0, 1),
-        _ => panic!("Unknown cur {cur:?}"),
-    };
-
-    // Navigate the map, if an obstacle is found do a clockwise turn
-    while let Some(mut c) = map.get(&(x + next.0, y + next.1)) {
-        while *c == '#' {
-            next = obstacle_found(next);
-            c = map.get(&(x + next.0, y + next.1)).unwrap();
+// The guard's walk, until leaving the map or coming back to a position facing the same way
+fn solve(map: &Grid<char>, start: Pos) -> History<(Pos, Dir4)> {
+    cycle::hashed((start, Dir4::Up), |&(pos, mut dir)| {
+        // Navigate the map, if an obstacle is found do a clockwise turn
+        loop {
+            let ahead = Point2::from(pos) + dir.delta();
+            match map.get(ahead.into())? {
+                '#' => dir = dir.turn_right(),
+                _ => return Some((ahead.into(), dir)),
+            }
         }
-        (x, y) = (x + next.0, y + next.1);
+    })
+}
 
-        // Detect loops and continue otherwise
-        if met.contains(&((x, y), next)) {
-            // Loops are invalid paths
-            return None;
-        } else {
-            met.insert(((x, y), next));
-        }
-    }
-    Some(met)
+// Positions the guard walks through before leaving the map
+fn passed(map: &Grid<char>, start: Pos) -> HashSet<Pos> {
+    solve(map, start).states().iter().map(|(a, _)| *a).collect()
 }
 
-fn stars(Data(mut map, start): Data) {
-    let r = solve(&map, start).unwrap();
-    let passed: HashSet<(isize, isize)> = r.iter().map(|(a, _)| *a).collect();
-    println!("Star1: {}", passed.len());
+fn star1(Data(map, start): &Data) -> usize {
+    passed(map, *start).len()
+}
 
+fn star2(Data(map, start): &Data) -> usize {
+    let (mut map, start) = (map.clone(), *start);
     let mut c = 0;
 
-    for k in passed {
+    for k in passed(&map, start) {
         if k == start {
             continue;
         }
-        map.insert(k, '#');
-        if solve(&map, start) == None {
+        map[k] = '#';
+        // Loops are what the obstacle should cause
+        if solve(&map, start).cycle().is_some() {
             c += 1;
         }
-        map.insert(k, '.');
+        map[k] = '.';
     }
 
-    println!("Star2: {c}");
+    c
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into vector of integer vectors
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let l: Data = input_raw.parse()?;
-    stars(l);
-    Ok(())
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(l: &Data) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l: &Data) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,36 +1,29 @@
-// Import required standard library modules and custom aoc module
-use std::{cell::RefCell, collections::HashMap, collections::HashSet, rc::Rc};
-
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
 
 // Data structure to hold puzzle input - each line contains a target number and list of operands
 #[derive(Debug)]
-struct Data(Vec<(u64, Vec<u64>)>);
+pub struct Data(Vec<(u64, Vec<u64>)>);
 
 // Implementation to parse input string into Data structure
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         Ok(Data(
             input
                 .lines()
                 .map(|line| {
-                    let mut parts = line.split(':');
-                    (
-                        parts
-                            .next()
-                            .expect("Missing target number")
-                            .parse()
-                            .unwrap(),
-                        parts
-                            .next()
-                            .expect("Missing operands")
+                    let (target, operands) = line
+                        .split_once(':')
+                        .ok_or_else(|| ParseError::within(input, line, "TARGET: OPERANDS"))?;
+                    Ok((
+                        aoc::parse_at(input, target, "a target number")?,
+                        operands
                             .split_whitespace()
-                            .map(|num| num.parse().unwrap())
-                            .collect(),
-                    )
+                            .map(|num| aoc::parse_at(input, num, "an operand"))
+                            .collect::<Result<_, _>>()?,
+                    ))
                 })
-                .collect(),
+                .collect::<Result<_, ParseError>>()?,
         ))
     }
 }
@@ -63,7 +56,7 @@ fn check_op(
                 && check_op(
                     target,
                     remaining,
-                    accumulated_sum * 10_u64.pow(current.ilog10() + 1) as u64 + current,
+                    accumulated_sum * 10_u64.pow(current.ilog10() + 1) + current,
                     allow_concatenation,
                 ))
         }
@@ -71,44 +64,38 @@ fn check_op(
     }
 }
 
-// Solve both parts of the puzzle
-fn stars(Data(input_lines): Data) {
-    let mut result = 0;
-
-    // Part 1: Find sum of target numbers that can be formed using + and * operations
-    result = input_lines
-        .iter()
-        .filter_map(|(target, operands)| {
-            let is_possible = check_op(*target, operands, 0, false);
-            if is_possible {
-                Some(target)
-            } else {
-                None
-            }
-        })
-        .sum();
-    println!("Star1 {result}");
-
-    // Part 2: Find sum of target numbers that can be formed using +, * and digit concatenation
-    result = input_lines
+// Sum of the target numbers that can be formed from their operands
+fn calibration(Data(input_lines): &Data, allow_concatenation: bool) -> u64 {
+    input_lines
         .iter()
         .filter_map(|(target, operands)| {
-            let is_possible = check_op(*target, operands, 0, true);
+            let is_possible = check_op(*target, operands, 0, allow_concatenation);
             if is_possible {
                 Some(target)
             } else {
                 None
             }
         })
-        .sum();
-    println!("Star2 {result}");
+        .sum()
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into Data structure
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Data;
+
+    // Parse input into Data structure
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    // Part 1: Find sum of target numbers that can be formed using + and * operations
+    fn part1(parsed_data: &Data) -> Answer {
+        calibration(parsed_data, false).into()
+    }
+
+    // Part 2: Find sum of target numbers that can be formed using +, * and digit concatenation
+    fn part2(parsed_data: &Data) -> Answer {
+        calibration(parsed_data, true).into()
+    }
 }
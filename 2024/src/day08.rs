@@ -1,14 +1,12 @@
-// Import required standard library modules and custom aoc module
+use aoc::{Answer, ParseError, Solution};
 use std::{collections::HashMap, collections::HashSet};
 
-use aoc::input_file;
-
 // Type alias for 2D coordinates represented as (row, column)
 type Coord = (isize, isize);
 
 // Data structure to store antenna frequencies and grid bounds
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     // Maps antenna frequencies (chars) to their coordinates in the grid
     antenna_freq: HashMap<char, Vec<Coord>>,
     // Grid dimensions as (num_rows, num_cols)
@@ -17,13 +15,17 @@ struct Data {
 
 // Implementation to parse input string into Data structure
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut antenna_freq: HashMap<char, Vec<(isize, isize)>> = HashMap::new();
         // Get grid dimensions from input
         let bounds = (
             input.lines().count() as isize,
-            input.lines().next().expect("missing first line").len() as isize,
+            input
+                .lines()
+                .next()
+                .ok_or_else(|| ParseError::new("a non-empty map"))?
+                .len() as isize,
         );
         // Parse each character position in the grid
         for (row_num, line) in input.lines().enumerate() {
@@ -31,7 +33,7 @@ impl std::str::FromStr for Data {
                 if char.is_alphanumeric() {
                     antenna_freq
                         .entry(char)
-                        .or_insert(Vec::new())
+                        .or_default()
                         .push((row_num as isize, col_num as isize));
                 }
             }
@@ -112,15 +114,13 @@ fn calculate_antinodes2(antenna_coords: Vec<Coord>, grid_bounds: Coord) -> HashS
     antinode_positions
 }
 
-// Solve both parts of the puzzle
-fn stars(
+fn star1(
     Data {
         antenna_freq,
         bounds: grid_bounds,
-    }: Data,
-) {
-    // Get set of all antenna positions
-    let antenna_positions: HashSet<Coord> = antenna_freq.values().flatten().copied().collect();
+    }: &Data,
+) -> usize {
+    let grid_bounds = *grid_bounds;
 
     // Part 1: Calculate antinodes using reflection points
     let mut part1_antinodes: HashSet<Coord> = HashSet::new();
@@ -136,7 +136,18 @@ fn stars(
             })
             .collect();
     }
-    println!("Star1: {}", part1_antinodes.len());
+    part1_antinodes.len()
+}
+
+fn star2(
+    Data {
+        antenna_freq,
+        bounds: grid_bounds,
+    }: &Data,
+) -> usize {
+    let grid_bounds = *grid_bounds;
+    // Get set of all antenna positions
+    let antenna_positions: HashSet<Coord> = antenna_freq.values().flatten().copied().collect();
 
     // Part 2: Calculate antinodes using vector extension
     let mut part2_antinodes: HashSet<Coord> = HashSet::new();
@@ -152,17 +163,23 @@ fn stars(
             })
             .collect();
     }
-    println!(
-        "Star2: {}",
-        part2_antinodes.union(&antenna_positions).count()
-    );
+    part2_antinodes.union(&antenna_positions).count()
 }
 
-// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    // Read input file and parse into Data structure
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(l: &Data) -> Answer {
+        star1(l).into()
+    }
+
+    fn part2(l: &Data) -> Answer {
+        star2(l).into()
+    }
 }
@@ -1,10 +1,9 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashMap;
 
-use aoc::input_file;
-
 /// Represents the state of a disk with files and empty spaces
 #[derive(Debug, Clone)]
-struct Data {
+pub struct Data {
     // Vector representing disk blocks, Some(id) for files, None for empty space
     disk: Vec<Option<usize>>,
     // Maps file ID to (size, position) tuple
@@ -14,14 +13,21 @@ struct Data {
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut disk_blocks = Vec::new();
         let mut file_sizes = HashMap::new();
         let mut empty_spaces = Vec::new();
         let mut is_file_block = true;
-        for (block_index, character) in input.lines().next().unwrap().chars().enumerate() {
-            let block_size = character.to_digit(10).expect("Not numeric char") as usize;
+        let disk_map = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("a disk map"))?;
+        for (block_index, (offset, character)) in disk_map.char_indices().enumerate() {
+            let block_size = character.to_digit(10).ok_or_else(|| {
+                let text = &disk_map[offset..offset + character.len_utf8()];
+                ParseError::within(input, text, "a block size digit")
+            })? as usize;
             disk_blocks.append(&mut vec![
                 if is_file_block {
                     file_sizes.insert(block_index / 2, (block_size, disk_blocks.len()));
@@ -38,18 +44,18 @@ impl std::str::FromStr for Data {
         Ok(Data {
             disk: disk_blocks,
             sizes: file_sizes,
-            empty_spaces: empty_spaces,
+            empty_spaces,
         })
     }
 }
 
 /// Compresses disk by moving all files to the beginning, leaving empty spaces at the end
-fn compress(disk_blocks: &mut Vec<Option<usize>>) {
+fn compress(disk_blocks: &mut [Option<usize>]) {
     let mut left_index = 0;
     let mut right_index = disk_blocks.len() - 1;
     while left_index < right_index {
-        if disk_blocks[left_index] == None {
-            while disk_blocks[right_index] == None {
+        if disk_blocks[left_index].is_none() {
+            while disk_blocks[right_index].is_none() {
                 right_index -= 1;
             }
             disk_blocks[left_index] = disk_blocks[right_index];
@@ -61,15 +67,15 @@ fn compress(disk_blocks: &mut Vec<Option<usize>>) {
 
 /// Optimally compresses disk by moving larger files into empty spaces when possible
 fn _optimal_compress_without_fragmentation(
-    disk_blocks: &mut Vec<Option<usize>>,
+    disk_blocks: &mut [Option<usize>],
     file_metadata: &mut HashMap<usize, (usize, usize)>,
 ) {
     let mut current_pos = 0;
     while current_pos < disk_blocks.len() {
-        if disk_blocks[current_pos] == None {
+        if disk_blocks[current_pos].is_none() {
             let mut empty_block_size = 1;
             while (current_pos + empty_block_size) < disk_blocks.len()
-                && disk_blocks[current_pos + empty_block_size] == None
+                && disk_blocks[current_pos + empty_block_size].is_none()
             {
                 empty_block_size += 1;
             }
@@ -86,13 +92,9 @@ fn _optimal_compress_without_fragmentation(
                         continue 'search_file;
                     }
                     // Move file to empty space
-                    for block in current_pos..(current_pos + file_size) {
-                        disk_blocks[block] = Some(file_id);
-                    }
+                    disk_blocks[current_pos..current_pos + file_size].fill(Some(file_id));
                     // Clear original file location
-                    for block in *file_position..(file_position + file_size) {
-                        disk_blocks[block] = None;
-                    }
+                    disk_blocks[*file_position..file_position + file_size].fill(None);
                     file_metadata.remove(&file_id);
                     break 'search_file;
                 }
@@ -120,7 +122,7 @@ fn _optimal_compress_without_fragmentation(
 /// * `file_metadata` - HashMap mapping file ID to (size, position) tuple
 /// * `empty_spaces` - Vector of (position, size) tuples representing empty spaces
 fn compress2(
-    disk_blocks: &mut Vec<Option<usize>>,
+    disk_blocks: &mut [Option<usize>],
     file_metadata: &mut HashMap<usize, (usize, usize)>,
     empty_spaces: &mut Vec<(usize, usize)>,
 ) {
@@ -161,14 +163,11 @@ fn compress2(
             // Move file to new position if suitable empty space was found
             if let Some(new_file_position) = target_empty_position {
                 // Write file blocks to new position
-                for block_index in new_file_position..(new_file_position + file_size) {
-                    disk_blocks[block_index] = Some(current_file_id);
-                }
+                disk_blocks[new_file_position..new_file_position + file_size]
+                    .fill(Some(current_file_id));
 
                 // Clear original file location blocks
-                for block_index in *file_current_position..(file_current_position + file_size) {
-                    disk_blocks[block_index] = None;
-                }
+                disk_blocks[*file_current_position..file_current_position + file_size].fill(None);
 
                 // Remove processed file from metadata tracking
                 file_metadata.remove(&current_file_id);
@@ -179,14 +178,11 @@ fn compress2(
 }
 
 /// Calculates score for first star by compressing disk and summing position*file_id
-fn star1(Data { mut disk, .. }: Data) {
+fn star1(Data { mut disk, .. }: Data) -> usize {
     compress(&mut disk);
-    println!(
-        "Star1: {}",
-        disk.iter()
-            .enumerate()
-            .fold(0, |sum, (pos, id)| sum + pos * id.unwrap_or(0))
-    );
+    disk.iter()
+        .enumerate()
+        .fold(0, |sum, (pos, id)| sum + pos * id.unwrap_or(0))
 }
 
 /// Calculates score for second star using optimal compression algorithm
@@ -196,21 +192,28 @@ fn star2(
         mut sizes,
         mut empty_spaces,
     }: Data,
-) {
+) -> usize {
     compress2(&mut disk, &mut sizes, &mut empty_spaces);
     // We can calculate the checksum only with the sizes data structure, but this exercise is left to the reader
-    println!(
-        "Star2: {}",
-        disk.iter()
-            .enumerate()
-            .fold(0, |sum, (pos, id)| sum + pos * id.unwrap_or(0))
-    );
+    disk.iter()
+        .enumerate()
+        .fold(0, |sum, (pos, id)| sum + pos * id.unwrap_or(0))
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_contents = std::fs::read_to_string(input_file(file!()))?;
-    let disk_data: Data = input_contents.parse()?;
-    star1(disk_data.clone());
-    star2(disk_data);
-    Ok(())
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Data;
+
+    fn parse(input_contents: &str) -> Result<Data, ParseError> {
+        input_contents.parse()
+    }
+
+    fn part1(disk_data: &Data) -> Answer {
+        star1(disk_data.clone()).into()
+    }
+
+    fn part2(disk_data: &Data) -> Answer {
+        star2(disk_data.clone()).into()
+    }
 }
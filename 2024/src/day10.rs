@@ -1,15 +1,13 @@
-// Import required standard library modules and custom aoc module
+use aoc::{Answer, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
 
-use aoc::input_file;
-
 // Type alias for 2D coordinates represented as (row, column)
 type Coord = (usize, usize);
 
 /// Data structure representing a height map grid
 /// Contains heights at each coordinate and ground level coordinates
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     /// Maps coordinates to their height values (0-9)
     heights: HashMap<Coord, u8>,
     /// Vector of coordinates that are at ground level (height 0)
@@ -19,7 +17,7 @@ struct Data {
 /// Parses input string into Data structure
 /// Input format is a grid of digits 0-9 representing heights
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut heights: HashMap<Coord, u8> = HashMap::new();
         let mut ground: Vec<Coord> = Vec::new();
@@ -27,7 +25,7 @@ impl std::str::FromStr for Data {
         // Parse each character position in the grid
         for (row_num, line) in input.lines().enumerate() {
             for (col_num, char) in line.chars().enumerate() {
-                if char.is_digit(10) {
+                if char.is_ascii_digit() {
                     let height = char.to_digit(10).unwrap() as u8;
                     heights.insert((row_num, col_num), height);
                     if height == 0 {
@@ -67,34 +65,46 @@ fn find_path(
             return 1;
         }
 
-        // Recursively check all adjacent coordinates for next height
+        // Recursively check all adjacent coordinates for next height;
+        // off-grid neighbours wrap around and are simply absent from the map
         return find_path((x + 1, y), height + 1, heights, tops_reached)
-            + find_path((x - 1, y), height + 1, heights, tops_reached)
+            + find_path((x.wrapping_sub(1), y), height + 1, heights, tops_reached)
             + find_path((x, y + 1), height + 1, heights, tops_reached)
-            + find_path((x, y - 1), height + 1, heights, tops_reached);
+            + find_path((x, y.wrapping_sub(1)), height + 1, heights, tops_reached);
     }
     0
 }
 
-/// Solves both parts of the puzzle:
-/// Star1: Number of unique peaks reachable from ground level
-/// Star2: Total number of valid paths from ground to peaks
-fn stars(Data { heights, ground }: Data) {
+/// Walks every trail from the ground, returning:
+/// - the number of unique peaks reachable from ground level
+/// - the total number of valid paths from ground to peaks
+fn trails(Data { heights, ground }: &Data) -> (usize, u64) {
     let mut tops_reached = HashSet::new();
     let (r1, r2) = ground.iter().fold((0, 0), |(star1r, star2r), coord| {
-        let r2 = find_path(*coord, 0, &heights, &mut tops_reached);
+        let r2 = find_path(*coord, 0, heights, &mut tops_reached);
         let r = tops_reached.len();
         tops_reached.clear();
         (star1r + r, star2r + r2)
     });
-    println!("Star1 {r1}"); // Print number of unique peaks reached
-    println!("Star2 {r2}"); // Print total number of valid paths
+    (r1, r2)
 }
 
-/// Main function: Read input file and solve both parts
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    /// Number of unique peaks reachable from ground level
+    fn part1(parsed_data: &Data) -> Answer {
+        trails(parsed_data).0.into()
+    }
+
+    /// Total number of valid paths from ground to peaks
+    fn part2(parsed_data: &Data) -> Answer {
+        trails(parsed_data).1.into()
+    }
 }
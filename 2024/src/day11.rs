@@ -1,18 +1,17 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashMap;
 
-use aoc::input_file;
-
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     stones: HashMap<usize, usize>,
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut stones = HashMap::new();
         for stone_n in input.split_whitespace() {
-            let stone = stone_n.parse().unwrap();
+            let stone = aoc::parse_at(input, stone_n, "a stone number")?;
             *stones.entry(stone).or_default() += 1;
         }
 
@@ -26,7 +25,7 @@ fn evolve_stone(stones: &HashMap<usize, usize>) -> HashMap<usize, usize> {
         if *stone == 0 {
             *new_stones.entry(1).or_default() += count;
         } else {
-            let length_num = stone.ilog10() + 1 % 2;
+            let length_num = stone.ilog10() + 1;
             if length_num % 2 == 0 {
                 *new_stones
                     .entry(*stone / 10_usize.pow(length_num / 2))
@@ -42,19 +41,29 @@ fn evolve_stone(stones: &HashMap<usize, usize>) -> HashMap<usize, usize> {
     new_stones
 }
 
-fn stars(Data { mut stones }: Data) {
-    for n in 0..75 {
-        if n == 25 {
-            println!("Star1: {}", stones.values().sum::<usize>())
-        };
+// Number of stones after blinking `blinks` times
+fn blink(Data { stones }: &Data, blinks: usize) -> usize {
+    let mut stones = stones.clone();
+    for _ in 0..blinks {
         stones = evolve_stone(&stones);
     }
-    println!("Star2: {}", stones.values().sum::<usize>());
+    stones.values().sum::<usize>()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        blink(parsed_data, 25).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        blink(parsed_data, 75).into()
+    }
 }
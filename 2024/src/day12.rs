@@ -1,14 +1,12 @@
-// Import required standard library modules and custom aoc module for file handling
+use aoc::{Answer, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
 
-use aoc::input_file;
-
 // Type alias for 2D coordinates represented as (row, column) tuple
 type Coord = (usize, usize);
 
 /// Represents the garden layout with plants at different coordinates in a 2D grid
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     /// Maps plant types (chars) to sets of coordinates where they are located
     /// Key: Plant type character
     /// Value: Set of (row,col) coordinates where that plant type exists
@@ -16,7 +14,7 @@ struct Data {
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut plant_locations: HashMap<char, HashSet<Coord>> = HashMap::new();
 
@@ -51,17 +49,20 @@ fn add_corner_points(
         HashSet<Coord>,
     ),
 ) {
+    // Off-grid neighbours wrap around and are simply absent from the group
     // Check for external corners (no plants in adjacent positions)
     if !plant_group.contains(&(x + 1, y)) && !plant_group.contains(&(x, y + 1)) {
         corners.0.insert((x, y)); // Top-right corner
     }
-    if !plant_group.contains(&(x - 1, y)) && !plant_group.contains(&(x, y + 1)) {
+    if !plant_group.contains(&(x.wrapping_sub(1), y)) && !plant_group.contains(&(x, y + 1)) {
         corners.1.insert((x, y)); // Top-left corner
     }
-    if !plant_group.contains(&(x + 1, y)) && !plant_group.contains(&(x, y - 1)) {
+    if !plant_group.contains(&(x + 1, y)) && !plant_group.contains(&(x, y.wrapping_sub(1))) {
         corners.2.insert((x, y)); // Bottom-right corner
     }
-    if !plant_group.contains(&(x - 1, y)) && !plant_group.contains(&(x, y - 1)) {
+    if !plant_group.contains(&(x.wrapping_sub(1), y))
+        && !plant_group.contains(&(x, y.wrapping_sub(1)))
+    {
         corners.3.insert((x, y)); // Bottom-left corner
     }
 
@@ -72,21 +73,21 @@ fn add_corner_points(
     {
         corners.0.insert((x, y)); // Top-right internal corner
     }
-    if plant_group.contains(&(x - 1, y))
+    if plant_group.contains(&(x.wrapping_sub(1), y))
         && plant_group.contains(&(x, y + 1))
-        && !plant_group.contains(&(x - 1, y + 1))
+        && !plant_group.contains(&(x.wrapping_sub(1), y + 1))
     {
         corners.1.insert((x, y)); // Top-left internal corner
     }
     if plant_group.contains(&(x + 1, y))
-        && plant_group.contains(&(x, y - 1))
-        && !plant_group.contains(&(x + 1, y - 1))
+        && plant_group.contains(&(x, y.wrapping_sub(1)))
+        && !plant_group.contains(&(x + 1, y.wrapping_sub(1)))
     {
         corners.2.insert((x, y)); // Bottom-right internal corner
     }
-    if plant_group.contains(&(x - 1, y))
-        && plant_group.contains(&(x, y - 1))
-        && !plant_group.contains(&(x - 1, y - 1))
+    if plant_group.contains(&(x.wrapping_sub(1), y))
+        && plant_group.contains(&(x, y.wrapping_sub(1)))
+        && !plant_group.contains(&(x.wrapping_sub(1), y.wrapping_sub(1)))
     {
         corners.3.insert((x, y)); // Bottom-left internal corner
     }
@@ -119,10 +120,10 @@ fn get_connected_plant(
 
     // Check all orthogonally adjacent positions
     for adjacent_pos in [
-        (current_pos.0 + 1, current_pos.1), // Right
-        (current_pos.0 - 1, current_pos.1), // Left
-        (current_pos.0, current_pos.1 + 1), // Up
-        (current_pos.0, current_pos.1 - 1), // Down
+        (current_pos.0 + 1, current_pos.1),             // Right
+        (current_pos.0.wrapping_sub(1), current_pos.1), // Left
+        (current_pos.0, current_pos.1 + 1),             // Up
+        (current_pos.0, current_pos.1.wrapping_sub(1)), // Down
     ] {
         if plant_group.contains(&adjacent_pos) {
             if !visited.contains(&adjacent_pos) {
@@ -192,22 +193,30 @@ fn sum_scores(plant_group: &mut HashSet<Coord>) -> (usize, usize) {
 }
 
 /// Processes all plant types and calculates final scores for both parts
-fn stars(Data { mut plants }: Data) {
-    let final_scores = plants.values_mut().map(sum_scores).fold(
+fn scores(Data { plants }: &Data) -> (usize, usize) {
+    let mut plants = plants.clone();
+    plants.values_mut().map(sum_scores).fold(
         (0, 0),
         |(sum_perimeter, sum_corners), (perimeter, corners)| {
             (sum_perimeter + perimeter, sum_corners + corners)
         },
-    );
-
-    println!("Star1 {:?}", final_scores.0);
-    println!("Star2 {:?}", final_scores.1);
+    )
 }
 
-/// Main function: Read input file and solve both puzzle parts
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        scores(parsed_data).0.into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        scores(parsed_data).1.into()
+    }
 }
@@ -1,13 +1,13 @@
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
 
 /// Represents the input data structure containing vectors of 6 integers, representing the coefficients and target values of x and y for each of the buttons
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     claws: Vec<[i64; 6]>,
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     /// Parses input string into Data struct
     /// Input format is blocks of numbers separated by newlines,
@@ -17,12 +17,14 @@ impl std::str::FromStr for Data {
             .split("\n\n")
             .map(|block| {
                 let numbers: Vec<i64> = block
-                    .split(|c| c == '+' || c == ',' || c == '\n' || c == '=')
+                    .split(['+', ',', '\n', '='])
                     .filter_map(|number_str| number_str.parse::<i64>().ok())
                     .collect();
-                numbers.try_into().unwrap()
+                numbers
+                    .try_into()
+                    .map_err(|_| ParseError::within(input, block, "a claw machine with 6 numbers"))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(Data { claws })
     }
 }
@@ -33,6 +35,7 @@ impl std::str::FromStr for Data {
 /// - xb, yb: Second coin coefficients  
 /// - xr, yr: Target values
 /// - star2: Boolean flag for part 2 calculation
+///
 /// Returns: Option containing (a,b) coin values if solution exists
 fn coins(
     [a_x, a_y, b_x, b_y, mut target_x, mut target_y]: &[i64; 6],
@@ -63,30 +66,29 @@ fn coins(
     }
 }
 
-/// Calculates and prints solutions for both star1 and star2
-fn stars(Data { claws }: Data) {
-    println!(
-        "Star1: {:?}",
-        claws
-            .iter()
-            .filter_map(|coefficients| coins(coefficients, false))
-            .map(|(coin_a, coin_b)| coin_a * 3 + coin_b)
-            .sum::<i64>()
-    );
-    println!(
-        "Star2: {:?}",
-        claws
-            .iter()
-            .filter_map(|coefficients| coins(coefficients, true))
-            .map(|(coin_a, coin_b)| coin_a * 3 + coin_b)
-            .sum::<i64>()
-    );
+/// Calculates the tokens needed to win every winnable prize
+fn tokens(Data { claws }: &Data, star2: bool) -> i64 {
+    claws
+        .iter()
+        .filter_map(|coefficients| coins(coefficients, star2))
+        .map(|(coin_a, coin_b)| coin_a * 3 + coin_b)
+        .sum::<i64>()
 }
 
-/// Main function that reads input file and processes the data
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        tokens(parsed_data, false).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        tokens(parsed_data, true).into()
+    }
 }
@@ -1,114 +1,141 @@
-use aoc::input_file;
+use aoc::render::{Colour, Frame, Legend};
+use aoc::{Answer, InputKind, Param, ParseError, Solution};
 
 // Represents a 2D coordinate with x,y positions
 type Coord = (i64, i64);
 
-// Maximum grid dimensions for the robot movement space
-const MAX: Coord = (103, 101);
-// Test grid dimensions
-// const MAX: Coord = (7, 11);
+// Dimensions of the robot movement space, 101 wide and 103 tall in the real input
+const HEIGHT: Param<i64> = Param {
+    real: 103,
+    example: 7,
+};
+const WIDTH: Param<i64> = Param {
+    real: 101,
+    example: 11,
+};
 
 // Holds the robot positions and velocities
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     // Vec of (position, velocity) tuples for each robot
     robots: Vec<(Coord, Coord)>,
+    // Grid dimensions as (height, width)
+    size: Coord,
 }
 
-impl std::str::FromStr for Data {
-    type Err = std::io::Error;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
+impl Data {
+    fn parse(input: &str, kind: InputKind) -> Result<Self, ParseError> {
         // Parse input lines into robot position and velocity data
         let robots = input
             .lines()
             .map(|block| {
                 let numbers: Vec<i64> = block
-                    .split(|c| c == ' ' || c == ',' || c == '=')
+                    .split([' ', ',', '='])
                     .filter_map(|number_str| number_str.parse::<i64>().ok())
                     .collect();
+                if numbers.len() != 4 {
+                    return Err(ParseError::within(input, block, "a robot p=X,Y v=X,Y"));
+                }
                 // Format: ((y_pos, x_pos), (y_vel, x_vel))
-                ((numbers[1], numbers[0]), (numbers[3], numbers[2]))
+                Ok(((numbers[1], numbers[0]), (numbers[3], numbers[2])))
             })
-            .collect();
-        Ok(Data { robots })
+            .collect::<Result<_, _>>()?;
+        let size = (HEIGHT.get(kind), WIDTH.get(kind));
+        Ok(Data { robots, size })
     }
 }
 
-// Displays robot positions and checks for message formation
-fn display_robots(robot_positions: Vec<Coord>) -> bool {
-    // Create grid with padding around MAX dimensions
-    let mut display_grid = vec![vec!['.'; 10 + MAX.0 as usize]; 10 + MAX.1 as usize];
-
-    // Mark robot positions on grid
-    for (row, col) in robot_positions {
-        display_grid[row as usize][col as usize] = '#';
-    }
+// Draws robot positions and checks for message formation
+fn display_robots(robot_positions: Vec<Coord>, size: Coord, seconds: i64) -> bool {
+    let frame = Frame::new()
+        .points(
+            robot_positions
+                .iter()
+                .map(|(row, col)| (*col as isize, *row as isize)),
+            '#',
+        )
+        .bounds((0, 0), (size.1 as isize - 1, size.0 as isize - 1));
+    aoc::animate!(
+        Legend::new().entry('#', Colour::Green, "robot"),
+        frame,
+        "after {seconds} seconds"
+    );
 
     // Check for message formation - continuous '#' in specific range
-    for grid_line in &display_grid {
-        if (43..67).all(|column| grid_line[column] == '#') {
-            // Print grid when message is found
-            for line in display_grid {
-                println!("{}", line.iter().collect::<String>());
-            }
-            return true;
-        }
+    let found =
+        (0..size.0 as isize).any(|row| (43..67).all(|column| frame.get((column, row)) == '#'));
+    if found {
+        // Print grid when message is found, on stderr to keep stdout for the answers
+        eprint!("{frame}");
     }
-    false
+    found
 }
 
 // Calculate robot positions after given number of iterations
-fn after_iteration(robots: &Vec<(Coord, Coord)>, iteration_count: i64) -> Vec<Coord> {
+fn after_iteration(robots: &[(Coord, Coord)], size: Coord, iteration_count: i64) -> Vec<Coord> {
     robots
         .iter()
         .map(|((pos_y, pos_x), (vel_y, vel_x))| {
             (
-                (pos_y + vel_y * iteration_count).rem_euclid(MAX.0),
-                (pos_x + vel_x * iteration_count).rem_euclid(MAX.1),
+                (pos_y + vel_y * iteration_count).rem_euclid(size.0),
+                (pos_x + vel_x * iteration_count).rem_euclid(size.1),
             )
         })
         .collect()
 }
 
-fn stars(Data { robots }: Data) {
+fn star1(Data { robots, size }: &Data) -> usize {
+    let (max_y, max_x) = *size;
     // Calculate positions after 100 iterations
-    let positions_at_100: Vec<Coord> = after_iteration(&robots, 100);
+    let positions_at_100: Vec<Coord> = after_iteration(robots, *size, 100);
 
     // Star 1: Calculate product of robots in each quadrant
-    println!(
-        "Star1: {:?}",
-        positions_at_100
-            .iter()
-            .fold([0; 4], |mut quadrant_counts, (y, x)| {
-                if *y < MAX.0 / 2 && *x < MAX.1 / 2 {
-                    quadrant_counts[0] += 1; // Top-left quadrant
-                } else if *y > MAX.0 / 2 && *x < MAX.1 / 2 {
-                    quadrant_counts[1] += 1; // Bottom-left quadrant
-                } else if *y < MAX.0 / 2 && *x > MAX.1 / 2 {
-                    quadrant_counts[2] += 1; // Top-right quadrant
-                } else if *y > MAX.0 / 2 && *x > MAX.1 / 2 {
-                    quadrant_counts[3] += 1; // Bottom-right quadrant
-                }
-                quadrant_counts
-            })
-            .iter()
-            .product::<usize>()
-    );
+    positions_at_100
+        .iter()
+        .fold([0; 4], |mut quadrant_counts, (y, x)| {
+            if *y < max_y / 2 && *x < max_x / 2 {
+                quadrant_counts[0] += 1; // Top-left quadrant
+            } else if *y > max_y / 2 && *x < max_x / 2 {
+                quadrant_counts[1] += 1; // Bottom-left quadrant
+            } else if *y < max_y / 2 && *x > max_x / 2 {
+                quadrant_counts[2] += 1; // Top-right quadrant
+            } else if *y > max_y / 2 && *x > max_x / 2 {
+                quadrant_counts[3] += 1; // Bottom-right quadrant
+            }
+            quadrant_counts
+        })
+        .iter()
+        .product::<usize>()
+}
 
-    // Star 2: Find iteration when message appears
-    for current_iteration in 0.. {
-        let message_found = display_robots(after_iteration(&robots, current_iteration));
-        if message_found {
-            println!("Star2: {:?}", current_iteration);
-            break;
-        }
-    }
+fn star2(Data { robots, size }: &Data) -> Answer {
+    // Star 2: Find iteration when message appears; positions repeat after height * width
+    // iterations, so inputs without a message (like the example) give up then
+    (0..size.0 * size.1)
+        .find(|current_iteration| {
+            display_robots(
+                after_iteration(robots, *size, *current_iteration),
+                *size,
+                *current_iteration,
+            )
+        })
+        .map_or(Answer::NoAnswer, Answer::from)
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Data;
+
+    fn parse_with(input_raw: &str, kind: InputKind) -> Result<Data, ParseError> {
+        Data::parse(input_raw, kind)
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data)
+    }
 }
@@ -1,23 +1,46 @@
+use aoc::geometry::Dir4;
+use aoc::grid::Pos;
+use aoc::render::{Colour, Frame, Legend};
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashSet;
 
-use aoc::input_file;
-
-// Represents a 2D coordinate with x,y positions
+// Represents a 2D coordinate as (row, column)
 type Coord = (i64, i64);
 
-#[derive(Debug)]
-struct Data {
+// The grid position (x, y) of a coordinate, for drawing
+fn to_pos((row, column): Coord) -> Pos {
+    (column as isize, row as isize)
+}
+
+// Boxes are drawn as `O`, or `[]` in the wide warehouse
+fn legend() -> Legend {
+    Legend::new()
+        .entry('#', Colour::Grey, "wall")
+        .entry('O', Colour::Yellow, "box")
+        .entry('[', Colour::Yellow, "")
+        .entry(']', Colour::Yellow, "")
+        .entry('@', Colour::Red, "robot")
+}
+
+// The position one step from `(row, column)` towards `dir`
+fn step((row, column): Coord, dir: Dir4) -> Coord {
+    let delta = dir.delta();
+    (row + delta.y, column + delta.x)
+}
+
+#[derive(Debug, Clone)]
+pub struct Data {
     wall1: HashSet<Coord>,
     boxes1: HashSet<Coord>,
     wall2: HashSet<Coord>,
     boxes2: HashSet<Coord>,
     pos1: Coord,
     pos2: Coord,
-    dirs: Vec<Coord>,
+    dirs: Vec<Dir4>,
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut wall1 = HashSet::new();
@@ -28,9 +51,9 @@ impl std::str::FromStr for Data {
         let mut pos2 = None;
         let mut dirs = Vec::new();
         let mut it = input.split("\n\n");
-        let map = it.next().unwrap();
+        let map = it.next().unwrap_or_default();
         for (y, line) in map.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
+            for (x, (offset, c)) in line.char_indices().enumerate() {
                 let coord = (y as i64, x as i64);
                 let coord_l = (y as i64, 2 * x as i64);
                 let coord_r: (i64, i64) = (y as i64, 1 + 2 * x as i64);
@@ -49,44 +72,52 @@ impl std::str::FromStr for Data {
                         pos1 = Some(coord);
                         pos2 = Some(coord_l);
                     }
-                    _ => {}
+                    '.' => {}
+                    _ => {
+                        let text = &line[offset..offset + c.len_utf8()];
+                        return Err(ParseError::within(input, text, "one of '#', 'O', '@', '.'"));
+                    }
                 }
             }
         }
-        for dir in it.next().unwrap().chars() {
+        let moves = it
+            .next()
+            .ok_or_else(|| ParseError::new("the robot moves after the map"))?;
+        for (offset, dir) in moves.char_indices() {
             match dir {
-                '<' => dirs.push((0, -1)),
-                '>' => dirs.push((0, 1)),
-                '^' => dirs.push((-1, 0)),
-                'v' => dirs.push((1, 0)),
-                _ => {}
+                '<' | '>' | '^' | 'v' => dirs.extend(Dir4::from_char(dir)),
+                '\n' => {}
+                _ => {
+                    let text = &moves[offset..offset + dir.len_utf8()];
+                    return Err(ParseError::within(
+                        input,
+                        text,
+                        "a move '<', '>', '^' or 'v'",
+                    ));
+                }
             }
         }
+        let (Some(pos1), Some(pos2)) = (pos1, pos2) else {
+            return Err(ParseError::new("a robot '@' on the map"));
+        };
         Ok(Data {
             wall1,
             wall2,
             boxes1,
             boxes2,
-            pos1: pos1.unwrap(),
-            pos2: pos2.unwrap(),
+            pos1,
+            pos2,
             dirs,
         })
     }
 }
 
-fn print_grid1(wall: &HashSet<Coord>, boxes: &HashSet<Coord>, pos: Coord) {
-    let max = wall.iter().max().unwrap();
-    let mut map = vec![vec!['.'; 1 + max.0 as usize]; 1 + max.1 as usize];
-    for (x, y) in wall.iter() {
-        map[*x as usize][*y as usize] = '#';
-    }
-    for (x, y) in boxes.iter() {
-        map[*x as usize][*y as usize] = 'O';
-    }
-    map[pos.0 as usize][pos.1 as usize] = '@';
-    for line in map {
-        println!("{}", line.iter().collect::<String>());
-    }
+// The warehouse with single-cell boxes
+fn frame1(wall: &HashSet<Coord>, boxes: &HashSet<Coord>, pos: Coord) -> Frame {
+    Frame::new()
+        .points(wall.iter().map(|c| to_pos(*c)), '#')
+        .points(boxes.iter().map(|c| to_pos(*c)), 'O')
+        .point(to_pos(pos), '@')
 }
 
 // Returns if it's empty
@@ -94,14 +125,18 @@ fn move_robot1(
     wall: &HashSet<Coord>,
     boxes: &mut HashSet<Coord>,
     pos: Coord,
-    dirs: &[Coord],
+    dirs: &[Dir4],
 ) -> bool {
-    // print_grid1(&wall, &boxes, pos);
-    // println!("moving robot");
+    aoc::animate!(
+        legend(),
+        frame1(wall, boxes, pos),
+        "{} moves left",
+        dirs.len()
+    );
     let mut free_space = false;
     if let [head, tail @ ..] = dirs {
-        let mut new_pos = (pos.0 + head.0, pos.1 + head.1);
-        // println!("{new_pos:?} {head:?}");
+        let mut new_pos = step(pos, *head);
+        aoc::trace!("{new_pos:?} {head:?}");
         if wall.contains(&new_pos) {
             free_space = false;
             move_robot1(wall, boxes, pos, tail);
@@ -109,12 +144,12 @@ fn move_robot1(
             free_space = true;
             move_robot1(wall, boxes, new_pos, tail);
         } else {
-            // println!("Moving box");
+            aoc::trace!("moving box");
             // Move the box (do the)
             free_space = move_robot1(wall, boxes, new_pos, &[*head]);
             if free_space {
                 boxes.remove(&new_pos);
-                let new_box_pos = (new_pos.0 + head.0, new_pos.1 + head.1);
+                let new_box_pos = step(new_pos, *head);
                 boxes.insert(new_box_pos);
             } else {
                 new_pos = pos;
@@ -125,20 +160,13 @@ fn move_robot1(
     free_space
 }
 
-fn print_grid2(wall: &HashSet<Coord>, boxes: &HashSet<Coord>, pos: Coord) {
-    let max = wall.iter().max().unwrap();
-    let mut map = vec![vec!['.'; 1 + max.1 as usize]; 1 + max.0 as usize];
-    for (x, y) in wall.iter() {
-        map[*x as usize][*y as usize] = '#';
-    }
-    for (x, y) in boxes.iter() {
-        map[*x as usize][*y as usize] = '[';
-        map[*x as usize][1 + *y as usize] = ']';
-    }
-    map[pos.0 as usize][pos.1 as usize] = '@';
-    for line in map {
-        println!("{}", line.iter().collect::<String>());
-    }
+// The warehouse with boxes two cells wide, stored by their left half
+fn frame2(wall: &HashSet<Coord>, boxes: &HashSet<Coord>, pos: Coord) -> Frame {
+    Frame::new()
+        .points(wall.iter().map(|c| to_pos(*c)), '#')
+        .points(boxes.iter().map(|c| to_pos(*c)), '[')
+        .points(boxes.iter().map(|c| to_pos(step(*c, Dir4::Right))), ']')
+        .point(to_pos(pos), '@')
 }
 
 // Returns the boxes to move in the direction of the caller
@@ -146,43 +174,44 @@ fn move_robot2(
     wall: &HashSet<Coord>,
     boxes: &mut HashSet<Coord>,
     pos: Coord,
-    dirs: &[Coord],
+    dirs: &[Dir4],
     is_box: bool,
 ) -> Option<Vec<Coord>> {
-    // To debug, provides nice visualization :)
-    // if !is_box {
-    //     print_grid2(&wall, &boxes, pos);
-    //     println!("{pos:?} {:?}", dirs.first());
-    // }
+    if !is_box {
+        aoc::animate!(
+            legend(),
+            frame2(wall, boxes, pos),
+            "{} moves left",
+            dirs.len()
+        );
+    }
     if let [head, tail @ ..] = dirs {
         // By default there would be a wall without letting to progress
         let mut to_move: Option<Vec<(i64, i64)>> = None;
-        let pos_to_check = (pos.0 + head.0, pos.1 + head.1);
-        let pos_to_check_l: (i64, i64) = (pos.0 + head.0, pos.1 + head.1 - 1);
-        let pos_to_check_r: (i64, i64) = (pos.0 + head.0, pos.1 + head.1 + 1);
+        let pos_to_check = step(pos, *head);
+        let pos_to_check_l: (i64, i64) = step(pos_to_check, Dir4::Left);
+        let pos_to_check_r: (i64, i64) = step(pos_to_check, Dir4::Right);
         // Check for the adjacent position that the current robot (or box) should move to
         if wall.contains(&pos_to_check) || (is_box && wall.contains(&pos_to_check_r)) {
             move_robot2(wall, boxes, pos, tail, is_box);
-        } else if {
-            match head {
-                (0, 1) if !is_box => boxes.contains(&(pos_to_check)),
-                (0, 1) if is_box => boxes.contains(&(&pos_to_check_r)),
-                (0, -1) => boxes.contains(&(pos_to_check_l)),
-                (1, 0) | (-1, 0) if is_box => {
-                    boxes.contains(&(&pos_to_check))
-                        || boxes.contains(&(&pos_to_check_l))
-                        || boxes.contains(&(&pos_to_check_r))
-                }
-                _ => boxes.contains(&(pos_to_check)) || boxes.contains(&(pos_to_check_l)),
+        } else if match head {
+            Dir4::Right if !is_box => boxes.contains(&(pos_to_check)),
+            Dir4::Right if is_box => boxes.contains(&pos_to_check_r),
+            Dir4::Left => boxes.contains(&(pos_to_check_l)),
+            Dir4::Up | Dir4::Down if is_box => {
+                boxes.contains(&pos_to_check)
+                    || boxes.contains(&pos_to_check_l)
+                    || boxes.contains(&pos_to_check_r)
             }
+            _ => boxes.contains(&(pos_to_check)) || boxes.contains(&(pos_to_check_l)),
         } {
             match head {
                 // Try to move left or right
-                (0, -1) | (0, 1) => {
+                Dir4::Left | Dir4::Right => {
                     to_move = move_robot2(
                         wall,
                         boxes,
-                        if *head == (0, -1) {
+                        if *head == Dir4::Left {
                             pos_to_check_l
                         } else if is_box {
                             pos_to_check_r
@@ -199,7 +228,7 @@ fn move_robot2(
                     }
                 }
                 // Try to move up or down
-                (-1, 0) | (1, 0) => {
+                Dir4::Up | Dir4::Down => {
                     // For vertical movements, propagate the effect for the corresponding box and the one inmediately to its right
                     let adjacent_box: (i64, i64) = if boxes.contains(&pos_to_check) {
                         pos_to_check
@@ -219,14 +248,12 @@ fn move_robot2(
                     } else {
                         Some(Vec::new())
                     };
-                    if on_left.is_some() && on_right.is_some() {
-                        let mut stacked_boxes = on_left.unwrap();
-                        stacked_boxes.append(&mut on_right.unwrap());
+                    if let (Some(mut stacked_boxes), Some(mut on_right)) = (on_left, on_right) {
+                        stacked_boxes.append(&mut on_right);
                         stacked_boxes.push(pos);
                         to_move = Some(stacked_boxes);
                     }
                 }
-                _ => {}
             };
 
             // Move boxes (if possible) and do the next iteration
@@ -234,8 +261,8 @@ fn move_robot2(
                 if !is_box {
                     let mut to_insert = HashSet::new();
                     for box_pos in boxes_to_move {
-                        if boxes.remove(&box_pos) {
-                            let new_box_pos = (box_pos.0 + head.0, box_pos.1 + head.1);
+                        if boxes.remove(box_pos) {
+                            let new_box_pos = step(*box_pos, *head);
                             to_insert.insert(new_box_pos);
                         }
                     }
@@ -249,45 +276,56 @@ fn move_robot2(
             }
         } else {
             // If the robot (or box) is in an occupied position, it becomes free
-            let mut r = Vec::new();
-            r.push(pos);
-            to_move = Some(r);
+            to_move = Some(vec![pos]);
             move_robot2(wall, boxes, pos_to_check, tail, is_box);
         }
-        //println!("Returning {pos:?} {to_move:?}");
+        aoc::trace!("returning {pos:?} {to_move:?}");
         to_move
     } else {
         Some(Vec::new())
     }
 }
 
-fn stars(
+fn star1(
     Data {
         wall1,
-        wall2,
         mut boxes1,
-        mut boxes2,
         pos1,
-        pos2,
         dirs,
+        ..
     }: Data,
-) {
+) -> i64 {
     move_robot1(&wall1, &mut boxes1, pos1, &dirs);
-    println!(
-        "Star1: {:?}",
-        boxes1.iter().map(|(x, y)| x * 100 + y).sum::<i64>()
-    );
+    boxes1.iter().map(|(x, y)| x * 100 + y).sum::<i64>()
+}
 
+fn star2(
+    Data {
+        wall2,
+        mut boxes2,
+        pos2,
+        dirs,
+        ..
+    }: Data,
+) -> i64 {
     move_robot2(&wall2, &mut boxes2, pos2, &dirs, false);
-    println!(
-        "Star2: {:?}",
-        boxes2.iter().map(|(x, y)| x * 100 + y).sum::<i64>()
-    );
+    boxes2.iter().map(|(x, y)| x * 100 + y).sum::<i64>()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data.clone()).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data.clone()).into()
+    }
 }
@@ -1,29 +1,27 @@
-use std::char;
-
-use aoc::input_file;
+use aoc::{Answer, ParseError, Solution};
 
 type Lit = usize;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Combo {
     Lit(Lit),
     Reg(usize),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Inst {
-    ADV(Combo),
-    BXL(Lit),
-    BST(Combo),
-    JNZ(Lit),
-    BXC,
-    OUT(Combo),
-    BDV(Combo),
-    CDV(Combo),
+    Adv(Combo),
+    Bxl(Lit),
+    Bst(Combo),
+    Jnz(Lit),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
 }
 
-#[derive(Debug)]
-struct Data {
+#[derive(Debug, Clone)]
+pub struct Data {
     registers: [usize; 3],
     instructions: Vec<Inst>,
     pc: usize,
@@ -31,66 +29,81 @@ struct Data {
     text_program: Vec<Lit>,
 }
 
-fn parse_combo(c: char) -> Combo {
-    match c {
-        '0'..='3' => Combo::Lit(parse_lit(c)),
-        '4'..='7' => Combo::Reg(parse_reg(c)),
-        _ => panic!("Invalid operand"),
+// `text` is a slice of `input`, which locates errors
+fn parse_combo(input: &str, text: &str) -> Result<Combo, ParseError> {
+    match parse_lit(input, text)? {
+        n @ 0..=3 => Ok(Combo::Lit(n)),
+        n @ 4..=6 => Ok(Combo::Reg(n - 4)),
+        _ => Err(ParseError::within(
+            input,
+            text,
+            "a combo operand, 7 is reserved",
+        )),
     }
 }
 
-fn parse_reg(c: char) -> usize {
-    match c {
-        '4' => 0,
-        '5' => 1,
-        '6' => 2,
-        _ => panic!("Invalid register"),
+fn parse_lit(input: &str, text: &str) -> Result<Lit, ParseError> {
+    match text.parse() {
+        Ok(n @ 0..=7) => Ok(n),
+        _ => Err(ParseError::within(input, text, "a 3-bit number")),
     }
 }
 
-fn parse_lit(c: char) -> Lit {
-    c.to_digit(8).unwrap() as Lit
-}
-
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut registers = [0, 0, 0];
         let mut instructions = Vec::new();
         let mut it = input.split("\n\n");
-        let mut reg_index = 0;
-        for line in it.next().unwrap().lines() {
-            registers[reg_index] = line.split_whitespace().last().unwrap().parse().unwrap();
-            reg_index += 1;
+        let register_lines = it.next().unwrap_or_default();
+        let lines = register_lines.lines().collect::<Vec<_>>();
+        if lines.len() != 3 {
+            return Err(ParseError::within(
+                input,
+                register_lines,
+                "registers A, B and C",
+            ));
+        }
+        for (register, line) in registers.iter_mut().zip(lines) {
+            let value = line.split_whitespace().last().unwrap_or(line);
+            *register = aoc::parse_at(input, value, "a register value")?;
         }
 
-        let text_program = &it.next().unwrap()[9..];
-        for chars in text_program.chars().collect::<Vec<char>>().chunks(4) {
-            match chars {
-                [ins, _, op, _] | [ins, _, op] => match ins {
-                    '0' => instructions.push(Inst::ADV(parse_combo(*op))),
-                    '1' => instructions.push(Inst::BXL(parse_lit(*op))),
-                    '2' => instructions.push(Inst::BST(parse_combo(*op))),
-                    '3' => instructions.push(Inst::JNZ(parse_lit(*op))),
-                    '4' => instructions.push(Inst::BXC),
-                    '5' => instructions.push(Inst::OUT(parse_combo(*op))),
-                    '6' => instructions.push(Inst::BDV(parse_combo(*op))),
-                    '7' => instructions.push(Inst::CDV(parse_combo(*op))),
-                    _ => {}
-                },
-                _ => {}
-            }
+        let text_program = it
+            .next()
+            .and_then(|program| program.strip_prefix("Program: "))
+            .ok_or_else(|| ParseError::new("a \"Program: \" line"))?
+            .trim_end();
+        let program = text_program.split(',').collect::<Vec<_>>();
+        for pair in program.chunks(2) {
+            let [ins, op] = *pair else {
+                return Err(ParseError::within(
+                    input,
+                    pair[0],
+                    "an operand after the opcode",
+                ));
+            };
+            instructions.push(match parse_lit(input, ins)? {
+                0 => Inst::Adv(parse_combo(input, op)?),
+                1 => Inst::Bxl(parse_lit(input, op)?),
+                2 => Inst::Bst(parse_combo(input, op)?),
+                3 => Inst::Jnz(parse_lit(input, op)?),
+                4 => Inst::Bxc,
+                5 => Inst::Out(parse_combo(input, op)?),
+                6 => Inst::Bdv(parse_combo(input, op)?),
+                _ => Inst::Cdv(parse_combo(input, op)?),
+            });
         }
         Ok(Data {
             registers,
             instructions,
             pc: 0,
             output: Vec::new(),
-            text_program: text_program
-                .split(",")
-                .map(|s| parse_lit(s.chars().next().unwrap()))
-                .collect(),
+            text_program: program
+                .iter()
+                .map(|s| parse_lit(input, s))
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -107,69 +120,62 @@ impl Data {
         use Inst::*;
 
         match &self.instructions[self.pc] {
-            ADV(op) => self.registers[0] /= 1 << self.combo_value(op),
-            BXL(op) => self.registers[1] ^= *op,
-            BST(op) => self.registers[1] = self.combo_value(op) % 8,
-            JNZ(op) => {
+            Adv(op) => self.registers[0] /= 1 << self.combo_value(op),
+            Bxl(op) => self.registers[1] ^= *op,
+            Bst(op) => self.registers[1] = self.combo_value(op) % 8,
+            Jnz(op) => {
                 if self.registers[0] != 0 {
-                    self.pc = *op - 1;
+                    self.pc = *op;
+                    return;
                 }
             }
-            BXC => self.registers[1] ^= self.registers[2],
-            OUT(op) => self.output.push(self.combo_value(op) % 8),
-            BDV(op) => self.registers[1] = self.registers[0] >> self.combo_value(op),
-            CDV(op) => self.registers[2] = self.registers[0] >> self.combo_value(op),
+            Bxc => self.registers[1] ^= self.registers[2],
+            Out(op) => self.output.push(self.combo_value(op) % 8),
+            Bdv(op) => self.registers[1] = self.registers[0] >> self.combo_value(op),
+            Cdv(op) => self.registers[2] = self.registers[0] >> self.combo_value(op),
         }
         self.pc += 1;
     }
 }
 
-fn stars(mut program: Data) {
-    let initial_registers = program.registers.clone();
+fn star1(mut program: Data) -> String {
     while program.pc < program.instructions.len() {
         program.execute_ins();
     }
-    println!(
-        "Star1: {:?}",
-        program
-            .output
-            .iter()
-            .map(|c| c.to_string())
-            .collect::<Vec<String>>()
-            .join(",")
-    );
+    program
+        .output
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
 
-    // println!(
-    //     "registers: {:?}, instructions: {:?}, output: {:?}, pc: {:?}, text_program: {:?}",
-    //     program.registers, program.instructions, program.output, program.pc, program.text_program
-    // );
-    // let mut a = 1;
-    // loop {
-    //     program.registers = initial_registers;
-    //     println!("a: {a}");
-    //     program.registers[0] = a;
-    //     program.pc = 0;
-    //     program.output = Vec::new();
-    //     while program.pc < program.instructions.len() {
-    //         program.execute_ins();
-    //     }
-    //     println!("output: {:?}", program.output);
-    //     println!("text_program: {:?}", program.text_program);
-    //     if program.output == program.text_program {
-    //         println!("Star2: {a}");
-    //         break;
-    //     }
-    //     if program.output.len() < program.text_program.len() {
-    //         a <<= 1;
-    //     } else {
-    //         a += 1;
-    //     }
-    // }
+fn star2(program: Data) -> Answer {
+    aoc::debug!(
+        "registers: {:?}, instructions: {:?}, output: {:?}, pc: {:?}, text_program: {:?}",
+        program.registers,
+        program.instructions,
+        program.output,
+        program.pc,
+        program.text_program
+    );
+    Answer::Unsolved
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data.clone()).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data.clone())
+    }
 }
@@ -1,82 +1,97 @@
+use aoc::grid::ORTHOGONAL;
+use aoc::search;
+use aoc::{Answer, InputKind, Param, ParseError, Solution};
 use std::collections::HashSet;
 
-use aoc::input_file;
-
 // Represents a 2D coordinate with x,y positions
 type Coord = (isize, isize);
 
+// The memory space runs from 0 to `size` on both axes, and part 1 looks at it after the
+// first `fallen` bytes
+const SIZE: Param<isize> = Param {
+    real: 70,
+    example: 6,
+};
+const FALLEN: Param<usize> = Param {
+    real: 1024,
+    example: 12,
+};
+
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     fallen: Vec<Coord>,
+    size: isize,
+    first_fallen: usize,
 }
 
-impl std::str::FromStr for Data {
-    type Err = std::io::Error;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut fallen = input
+impl Data {
+    fn parse(input: &str, kind: InputKind) -> Result<Self, ParseError> {
+        let fallen = input
             .lines()
-            .filter_map(|line| {
-                let mut it = line.split(",").filter_map(|n| n.parse().ok());
-                Some((it.next()?, it.next()?))
+            .map(|line| {
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::within(input, line, "a byte position X,Y"))?;
+                Ok((
+                    aoc::parse_at(input, x, "a coordinate")?,
+                    aoc::parse_at(input, y, "a coordinate")?,
+                ))
             })
-            .collect();
-        Ok(Data { fallen })
-    }
-}
-
-const SIZE_GRID: isize = 70;
-const FALLEN: usize = 1024;
-
-fn stars(Data { fallen }: Data) {
-    let mut to_check = HashSet::new();
-    to_check.insert((0, 0));
-
-    let mut iteration = 0;
-    'outer: while !to_check.is_empty() {
-        let mut new_to_check = HashSet::new();
-        for (x, y) in to_check.iter() {
-            if (*x, *y) == (SIZE_GRID, SIZE_GRID) {
-                break 'outer;
-            }
-            // Check all 4 adjacent positions
-            for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
-                let next = (x + dx, y + dy);
-                // Only consider positions within bounds and not fallen
-                if next.0 >= 0
-                    && next.0 <= SIZE_GRID
-                    && next.1 >= 0
-                    && next.1 <= SIZE_GRID
-                    && !fallen[..FALLEN].contains(&next)
-                {
-                    new_to_check.insert(next);
-                }
-            }
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let first_fallen = FALLEN.get(kind);
+        if fallen.len() < first_fallen {
+            return Err(ParseError::new(format!("at least {first_fallen} bytes")));
         }
-        to_check = new_to_check;
-        iteration += 1;
+        Ok(Data {
+            fallen,
+            size: SIZE.get(kind),
+            first_fallen,
+        })
     }
+}
 
-    println!("Star1: {:?}", iteration);
+fn star1(
+    Data {
+        fallen,
+        size,
+        first_fallen,
+    }: &Data,
+) -> Option<u64> {
+    let size = *size;
+    let blocked: HashSet<Coord> = fallen[..*first_fallen].iter().copied().collect();
+    let steps = |&(x, y): &Coord| {
+        // Only consider positions within bounds and not fallen
+        ORTHOGONAL
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|next| {
+                (0..=size).contains(&next.0)
+                    && (0..=size).contains(&next.1)
+                    && !blocked.contains(next)
+            })
+    };
+    search::bfs([(0, 0)], steps, |p| *p == (size, size)).goal_cost()
+}
 
+fn star2(Data { fallen, size, .. }: &Data) -> String {
+    let size = *size;
     let mut blocked: HashSet<Coord> = HashSet::new();
-    for i in 0.. {
-        blocked.insert(fallen[i]);
+    for &byte in fallen {
+        blocked.insert(byte);
         let mut visited = HashSet::new();
-        visited.insert(fallen[i]);
+        visited.insert(byte);
 
         // first element is bottom_left, second element is top_right
         let mut bounds = [false; 2]; // Tracks if we've hit each boundary
-        let mut deque = vec![fallen[i]];
+        let mut deque = vec![byte];
         while let Some((x, y)) = deque.pop() {
             // Check if we've hit any boundaries
-            bounds[0] |= x == 0 || y == SIZE_GRID;
-            bounds[1] |= x == SIZE_GRID || y == 0;
+            bounds[0] |= x == 0 || y == size;
+            bounds[1] |= x == size || y == 0;
 
             // If we hit boundaries at two ends, we found a path
             if bounds[0] && bounds[1] {
-                println!("Star2: {},{}", fallen[i].0, fallen[i].1);
-                return;
+                return format!("{},{}", byte.0, byte.1);
             }
 
             // Check adjacent coordinates
@@ -98,11 +113,23 @@ fn stars(Data { fallen }: Data) {
             }
         }
     }
+    unreachable!("the exit is never cut off")
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Data;
+
+    fn parse_with(input_raw: &str, kind: InputKind) -> Result<Data, ParseError> {
+        Data::parse(input_raw, kind)
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data).map_or(Answer::NoAnswer, Answer::from)
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data).into()
+    }
 }
@@ -1,26 +1,30 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashMap;
 
-use aoc::input_file;
-
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     towels: Vec<String>,
     combinations: Vec<String>,
 }
 
 impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut it = input.split("\n\n");
         Ok(Data {
             towels: it
                 .next()
-                .unwrap()
+                .unwrap_or_default()
                 .split(", ")
                 .map(|s| s.to_string())
                 .collect(),
-            combinations: it.next().unwrap().lines().map(|s| s.to_string()).collect(),
+            combinations: it
+                .next()
+                .ok_or_else(|| ParseError::new("the designs after the towel patterns"))?
+                .lines()
+                .map(|s| s.to_string())
+                .collect(),
         })
     }
 }
@@ -44,7 +48,7 @@ fn check_valid<'a>(
         }
     }
     cache.insert(s, r);
-    return r;
+    r
 }
 
 fn check_valid2<'a>(
@@ -66,37 +70,49 @@ fn check_valid2<'a>(
         }
     }
     cache.insert(s, r);
-    return r;
+    r
 }
 
-fn stars(
+fn star1(
     Data {
         towels,
         combinations,
-    }: Data,
-) {
+    }: &Data,
+) -> usize {
     let mut cache = HashMap::new();
-    println!(
-        "Star1: {:?}",
-        combinations
-            .iter()
-            .filter(|s| check_valid(s, &towels, &mut cache))
-            .count()
-    );
+    combinations
+        .iter()
+        .filter(|s| check_valid(s, towels, &mut cache))
+        .count()
+}
 
+fn star2(
+    Data {
+        towels,
+        combinations,
+    }: &Data,
+) -> usize {
     let mut cache2 = HashMap::new();
-    println!(
-        "Star2: {:?}",
-        combinations
-            .iter()
-            .map(|s| check_valid2(s, &towels, &mut cache2))
-            .sum::<usize>()
-    );
+    combinations
+        .iter()
+        .map(|s| check_valid2(s, towels, &mut cache2))
+        .sum::<usize>()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {
+        input_raw.parse()
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data).into()
+    }
 }
@@ -1,43 +1,50 @@
-use std::{
-    collections::{HashMap, HashSet},
-    i64::MAX,
-};
-
-use aoc::input_file;
+use aoc::grid::Grid;
+use aoc::search;
+use aoc::{Answer, InputKind, Param, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
 
 type Coord = (isize, isize);
 
 #[derive(Debug)]
-struct Data {
-    walls: HashSet<Coord>,
+pub struct Data {
+    // Whether each position is a wall
+    walls: Grid<bool>,
     start: Coord,
     end: Coord,
+    // Least picoseconds a cheat must save to count, for each part
+    saves: [i64; 2],
 }
 
-impl std::str::FromStr for Data {
-    type Err = std::io::Error;
+// The example has too few cheats saving 100 picoseconds, so its puzzle text counts lower
+const SAVES: [Param<i64>; 2] = [
+    Param {
+        real: 100,
+        example: 20,
+    },
+    Param {
+        real: 100,
+        example: 50,
+    },
+];
 
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut walls = HashSet::new();
-        let mut start = None;
-        let mut end = None;
-        for (row_n, line) in input.lines().enumerate() {
-            for (column_n, c) in line.chars().enumerate() {
-                let coord = (column_n as isize, row_n as isize);
-                match c {
-                    '#' => {
-                        walls.insert(coord);
-                    }
-                    'E' => end = Some(coord),
-                    'S' => start = Some(coord),
-                    _ => {}
-                }
-            }
-        }
+impl Data {
+    fn parse(input: &str, kind: InputKind) -> Result<Self, ParseError> {
+        let track = Grid::parse(
+            input,
+            |c| "#.SE".contains(c).then_some(c),
+            "a wall '#' or a track '.', 'S' or 'E'",
+        )?;
+        let start = track
+            .find(|c| *c == 'S')
+            .ok_or_else(|| ParseError::new("a start 'S' on the track"))?;
+        let end = track
+            .find(|c| *c == 'E')
+            .ok_or_else(|| ParseError::new("an end 'E' on the track"))?;
         Ok(Data {
-            walls,
-            start: start.unwrap(),
-            end: end.unwrap(),
+            walls: track.map(|c| *c == '#'),
+            start,
+            end,
+            saves: SAVES.map(|saves| saves.get(kind)),
         })
     }
 }
@@ -59,7 +66,7 @@ fn get_diffs2(steps: &HashMap<Coord, i64>) -> HashMap<i64, Vec<Coord>> {
     let mut r = HashMap::new();
     let mut potential_adj = HashSet::new();
     for it in 2..=20 {
-        for x in 0..it {
+        for x in 0..=it {
             let y: i32 = it - x;
             potential_adj.insert((x as isize, y as isize));
             potential_adj.insert((-x as isize, y as isize));
@@ -78,63 +85,74 @@ fn get_diffs2(steps: &HashMap<Coord, i64>) -> HashMap<i64, Vec<Coord>> {
     r
 }
 
-fn solve(walls: &HashSet<Coord>, start: Coord) -> HashMap<Coord, i64> {
-    let mut r = HashMap::new();
-    let mut queue = vec![(start, 0)];
-    let mut visited = HashSet::new();
-    while let Some((coord, steps)) = queue.pop() {
-        r.insert(coord, steps);
-        // if coord == end {
-        //     break;
-        //     return steps;
-        // }
-        if visited.contains(&coord) {
-            continue;
-        }
-        visited.insert(coord);
-        for (dx, dy) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
-            let next = (coord.0 + dx, coord.1 + dy);
-            if walls.contains(&next) || visited.contains(&next) {
-                continue;
-            }
-            queue.push((next, steps + 1));
-        }
-    }
-    return r;
+// Steps from the start to every position on the track
+fn solve(walls: &Grid<bool>, start: Coord, end: Coord) -> HashMap<Coord, i64> {
+    let track = |p: &Coord| walls.neighbours4(*p).filter(|next| !walls[*next]);
+    let found = search::bfs([start], track, |p| *p == end);
+    found
+        .costs()
+        .iter()
+        .map(|(p, steps)| (*p, *steps as i64))
+        .collect()
 }
 
-const SAVES: i64 = 100;
-const SAVES2: i64 = 100;
-
-fn stars(Data { walls, start, end }: Data) {
-    let steps = solve(&walls.clone(), start);
+fn star1(
+    Data {
+        walls,
+        start,
+        end,
+        saves,
+    }: &Data,
+) -> usize {
+    let steps = solve(walls, *start, *end);
     let diffs: HashMap<i64, Vec<(isize, isize)>> = get_diffs(&steps);
-    // println!("Star1: {:?}", start);
-    // println!("Star1: {:?}", steps);
-    // println!("Star1: {:?}", diffs);
+    aoc::debug!("start {start:?}");
+    aoc::trace!("steps {steps:?}");
+    aoc::trace!("diffs {diffs:?}");
     let mut r = 0;
     for k in diffs.keys() {
-        if k >= &SAVES {
-            // println!("Star1: {:?} {:?}", k, diffs[k].len());
+        if k >= &saves[0] {
+            aoc::debug!("{} cheats save {k}", diffs[k].len());
             r += diffs[k].len();
         }
     }
-    println!("Star1: {:?}", r);
+    r
+}
 
+fn star2(
+    Data {
+        walls,
+        start,
+        end,
+        saves,
+    }: &Data,
+) -> usize {
+    let steps = solve(walls, *start, *end);
     let diffs2: HashMap<i64, Vec<(isize, isize)>> = get_diffs2(&steps);
     let mut r2 = 0;
     for k in diffs2.keys() {
-        if k >= &SAVES2 {
-            // println!("Star1: {:?} {:?}", k, diffs[k].len());
+        if k >= &saves[1] {
+            aoc::debug!("{} cheats save {k}", diffs2[k].len());
             r2 += diffs2[k].len();
         }
     }
-    println!("Star2: {:?}", r2);
+    r2
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input_raw = std::fs::read_to_string(input_file(file!()))?;
-    let parsed_data: Data = input_raw.parse()?;
-    stars(parsed_data);
-    Ok(())
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Data;
+
+    fn parse_with(input_raw: &str, kind: InputKind) -> Result<Data, ParseError> {
+        Data::parse(input_raw, kind)
+    }
+
+    fn part1(parsed_data: &Data) -> Answer {
+        star1(parsed_data).into()
+    }
+
+    fn part2(parsed_data: &Data) -> Answer {
+        star2(parsed_data).into()
+    }
 }
@@ -0,0 +1,23 @@
+use aoc::example;
+use aoc2024::*;
+
+example!(day01: day01::Day01, "day01.txt", part1 = 11, part2 = 31);
+example!(day02: day02::Day02, "day02.txt", part1 = 2, part2 = 4);
+example!(day03: day03::Day03, "day03.txt", part1 = 161, part2 = 48);
+example!(day04: day04::Day04, "day04.txt", part1 = 18, part2 = 9);
+example!(day05: day05::Day05, "day05.txt", part1 = 143, part2 = 123);
+example!(day06: day06::Day06, "day06.txt", part1 = 41, part2 = 6);
+example!(day07: day07::Day07, "day07.txt", part1 = 3749, part2 = 11387);
+example!(day08: day08::Day08, "day08.txt", part1 = 14, part2 = 34);
+example!(day09: day09::Day09, "day09.txt", part1 = 1928, part2 = 2858);
+example!(day10: day10::Day10, "day10.txt", part1 = 36, part2 = 81);
+example!(day11: day11::Day11, "day11.txt", part1 = 55312, part2 = 65601038650482u64);
+example!(day12: day12::Day12, "day12.txt", part1 = 1930, part2 = 1206);
+example!(day13: day13::Day13, "day13.txt", part1 = 480, part2 = 875318608908u64);
+example!(day14: day14::Day14, "day14.txt", part1 = 12);
+example!(day15_small: day15::Day15, "day15.txt", part1 = 2028);
+example!(day15: day15::Day15, "day15-2.txt", part1 = 10092, part2 = 9021);
+example!(day17: day17::Day17, "day17.txt", part1 = "4,6,3,5,6,3,5,2,1,0");
+example!(day18: day18::Day18, "day18.txt", part1 = 22, part2 = "6,1");
+example!(day19: day19::Day19, "day19.txt", part1 = 6, part2 = 16);
+example!(day20: day20::Day20, "day20.txt", part1 = 5, part2 = 285);
@@ -0,0 +1,46 @@
+use aoc::{ParseError, Solution};
+use aoc2024::*;
+
+fn error<S: Solution>(input: &str) -> ParseError {
+    match S::parse(input) {
+        Ok(_) => panic!("{input:?} parsed"),
+        Err(e) => e,
+    }
+}
+
+#[test]
+fn bad_numbers_are_located() {
+    let e = error::<day01::Day01>("3   4\n4   x\n");
+    assert_eq!((e.line, e.column), (Some(2), Some(5)));
+    assert_eq!(e.text.as_deref(), Some("x"));
+
+    let e = error::<day02::Day02>("7 6 4\n1\n");
+    assert_eq!((e.line, e.column), (Some(2), Some(1)));
+    assert_eq!(e.expected, "a report of at least two levels");
+
+    let e = error::<day07::Day07>("190: 10 19\n3267 81 40 27\n");
+    assert_eq!((e.line, e.column), (Some(2), Some(1)));
+    assert_eq!(e.expected, "TARGET: OPERANDS");
+}
+
+#[test]
+fn bad_programs_are_diagnosed() {
+    let registers = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n";
+    let e = error::<day17::Day17>(&format!("{registers}Program: 0,7,5,4\n"));
+    assert_eq!((e.line, e.column), (Some(5), Some(12)));
+    assert_eq!(e.expected, "a combo operand, 7 is reserved");
+
+    let e = error::<day17::Day17>(&format!("{registers}Program: 0,1,5\n"));
+    assert_eq!(e.expected, "an operand after the opcode");
+    assert_eq!(e.text.as_deref(), Some("5"));
+}
+
+#[test]
+fn missing_sections_are_reported() {
+    assert_eq!(
+        error::<day15::Day15>("#@#\n").to_string(),
+        "parse error: expected the robot moves after the map"
+    );
+    let e = error::<day15::Day15>("#@#\n\n<>x\n");
+    assert_eq!((e.line, e.column), (Some(3), Some(3)));
+}
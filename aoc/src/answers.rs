@@ -0,0 +1,138 @@
+//! The expected answers of a year, kept in an `answers.toml` at the root of its crate:
+//!
+//! ```toml
+//! [1]
+//! part1 = 11
+//! part2 = "ABC"
+//! ```
+//!
+//! Only that subset of TOML is read: one table per day, integer or basic string values.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::toml::{parse_value, quote, strip_comment, Value};
+use crate::{Answer, ParseError, Part, Year};
+
+/// Expected answers by day, then part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub days: BTreeMap<u8, [Option<Answer>; 2]>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?[index(part)].as_ref()
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: Answer) {
+        self.days.entry(day).or_default()[index(part)] = Some(answer);
+    }
+
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        let mut day = None;
+        for line in input.lines() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let number = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| ParseError::within(input, line, "a table header [DAY]"))?;
+                day = Some(crate::parse_at(input, number.trim(), "a day number")?);
+                continue;
+            }
+            let day = day.ok_or_else(|| ParseError::within(input, line, "a [DAY] header"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::within(input, line, "part1 = ANSWER"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(ParseError::within(input, key, "part1 or part2")),
+            };
+            let answer = match parse_value(input, value.trim())? {
+                Value::Integer(n) => Answer::Number(n),
+                Value::String(s) => Answer::Text(s),
+            };
+            answers.set(day, part, answer);
+        }
+        Ok(answers)
+    }
+
+    /// Reads `path`, an absent file holding no answers.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_toml())
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        for (day, parts) in &self.days {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "[{day}]");
+            for (part, answer) in Part::BOTH.iter().zip(parts) {
+                let value = match answer {
+                    Some(Answer::Number(n)) => n.to_string(),
+                    Some(Answer::Text(s)) => quote(s),
+                    Some(Answer::Unsolved | Answer::NoAnswer) | None => continue,
+                };
+                let _ = writeln!(out, "part{} = {value}", part.number());
+            }
+        }
+        out
+    }
+}
+
+impl Year {
+    /// Where the expected answers of the year are kept.
+    pub fn answers_path(&self) -> PathBuf {
+        Path::new(self.dir).join("answers.toml")
+    }
+}
+
+fn index(part: Part) -> usize {
+    part.number() as usize - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "# 2022\n[1]\npart1 = 24_000 # elf calories\npart2 = 45000\n\n[10]\npart2 = \"#.\\n.# \\\"#\\\"\"\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Number(24000)));
+        assert_eq!(
+            answers.get(10, Part::Two),
+            Some(&Answer::from("#.\n.# \"#\""))
+        );
+        assert_eq!(answers.get(10, Part::One), None);
+        assert_eq!(answers.get(2, Part::One), None);
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn errors() {
+        let e = Answers::parse("[1]\npart3 = 4\n").unwrap_err();
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+        let e = Answers::parse("part1 = 4\n").unwrap_err();
+        assert_eq!(e.expected, "a [DAY] header");
+        let e = Answers::parse("[1]\npart1 = four\n").unwrap_err();
+        assert_eq!((e.line, e.column), (Some(2), Some(9)));
+    }
+}
@@ -0,0 +1,312 @@
+//! Cellular automata: a state stepped generation by generation, until a given generation,
+//! a condition, a fixpoint or a cycle.
+//!
+//! Two backends build the step functions. [`sparse`] keeps only the live cells of an
+//! unbounded space of any dimension, with [`moore`] or [`von_neumann`] neighbourhoods or
+//! the six neighbours of a [`hex`] tile. [`Dense`] holds any cell state on a
+//! [`Grid`], over an infinite background that follows the rule too.
+//!
+//! ```
+//! use aoc::automaton::{self, Automaton};
+//! use std::collections::HashSet;
+//!
+//! // A blinker in the Game of Life comes back every two generations.
+//! let blinker: HashSet<[i64; 2]> = [[0, 1], [1, 1], [2, 1]].into();
+//! let life = |live: &HashSet<[i64; 2]>| {
+//!     automaton::sparse(live, automaton::moore, |alive, n| n == 3 || alive && n == 2)
+//! };
+//! let mut world = Automaton::new(blinker, life);
+//! let sorted = |live: &HashSet<[i64; 2]>| {
+//!     let mut cells: Vec<_> = live.iter().copied().collect();
+//!     cells.sort();
+//!     cells
+//! };
+//! assert_eq!(world.find_cycle_by(sorted), automaton::Cycle { start: 0, length: 2 });
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::cycle;
+pub use crate::cycle::Cycle;
+use crate::grid::{Grid, Pos};
+
+/// A state and the function giving the next generation of it.
+pub struct Automaton<S, F, O = fn(usize, &S)> {
+    pub state: S,
+    /// Generations stepped since the initial state.
+    pub generation: usize,
+    step: F,
+    /// Called with each generation, to draw or log it.
+    observer: O,
+}
+
+impl<S, F: FnMut(&S) -> S> Automaton<S, F> {
+    pub fn new(state: S, step: F) -> Automaton<S, F> {
+        Automaton {
+            state,
+            generation: 0,
+            step,
+            observer: |_, _| {},
+        }
+    }
+}
+
+impl<S, F: FnMut(&S) -> S, O: FnMut(usize, &S)> Automaton<S, F, O> {
+    /// Calls `observer` with the generation and the state, now and after every step.
+    ///
+    /// ```
+    /// # use aoc::automaton::Automaton;
+    /// let mut seen = Vec::new();
+    /// Automaton::new(1, |n| n * 2)
+    ///     .observe(|generation, n| seen.push((generation, *n)))
+    ///     .run(2);
+    /// assert_eq!(seen, [(0, 1), (1, 2), (2, 4)]);
+    /// ```
+    pub fn observe<P: FnMut(usize, &S)>(self, mut observer: P) -> Automaton<S, F, P> {
+        observer(self.generation, &self.state);
+        Automaton {
+            state: self.state,
+            generation: self.generation,
+            step: self.step,
+            observer,
+        }
+    }
+
+    pub fn step(&mut self) -> &S {
+        self.state = (self.step)(&self.state);
+        self.generation += 1;
+        (self.observer)(self.generation, &self.state);
+        &self.state
+    }
+
+    /// Steps `generations` times.
+    pub fn run(&mut self, generations: usize) -> &S {
+        for _ in 0..generations {
+            self.step();
+        }
+        &self.state
+    }
+
+    /// Steps until the state satisfies `done`, which may already be the case.
+    pub fn run_until(&mut self, mut done: impl FnMut(&S) -> bool) -> &S {
+        while !done(&self.state) {
+            self.step();
+        }
+        &self.state
+    }
+
+    /// Steps until a step changes nothing, that step included, returning the generation
+    /// reached. Loops forever if the automaton never settles.
+    pub fn run_until_stable(&mut self) -> usize
+    where
+        S: PartialEq,
+    {
+        loop {
+            let next = (self.step)(&self.state);
+            self.generation += 1;
+            if next == self.state {
+                return self.generation;
+            }
+            self.state = next;
+            (self.observer)(self.generation, &self.state);
+        }
+    }
+
+    /// Steps until a state comes back, stopping on its second occurrence.
+    pub fn find_cycle(&mut self) -> Cycle
+    where
+        S: Clone + Eq + Hash,
+    {
+        self.find_cycle_by(S::clone)
+    }
+
+    /// [`find_cycle`](Automaton::find_cycle) for states told apart by `key`, such as the
+    /// sorted cells of a set, which is not `Hash` itself.
+    pub fn find_cycle_by<K: Clone + Eq + Hash>(&mut self, mut key: impl FnMut(&S) -> K) -> Cycle {
+        let first = self.generation;
+        let history = cycle::hashed(key(&self.state), |_| {
+            self.step();
+            Some(key(&self.state))
+        });
+        let Cycle { start, length } = history.cycle().expect("a run that never ends repeats");
+        Cycle {
+            start: first + start,
+            length,
+        }
+    }
+}
+
+/// The next generation of the `live` cells: each cell lives on if `rule` of whether it is
+/// alive and how many of its `neighbours` are says so. Only live cells and their
+/// neighbours are looked at, so a dead cell with no live neighbour stays dead.
+pub fn sparse<P, I>(
+    live: &HashSet<P>,
+    mut neighbours: impl FnMut(&P) -> I,
+    mut rule: impl FnMut(bool, usize) -> bool,
+) -> HashSet<P>
+where
+    P: Clone + Eq + Hash,
+    I: IntoIterator<Item = P>,
+{
+    let mut counts: HashMap<P, usize> = live.iter().map(|cell| (cell.clone(), 0)).collect();
+    for cell in live {
+        for neighbour in neighbours(cell) {
+            *counts.entry(neighbour).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|(cell, count)| rule(live.contains(cell), *count))
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+/// The `3^D - 1` cells around `cell`, diagonals included.
+pub fn moore<const D: usize>(cell: &[i64; D]) -> impl Iterator<Item = [i64; D]> {
+    let cell = *cell;
+    (0..3usize.pow(D as u32))
+        .map(move |mut code| {
+            let mut neighbour = cell;
+            for coordinate in &mut neighbour {
+                *coordinate += (code % 3) as i64 - 1;
+                code /= 3;
+            }
+            neighbour
+        })
+        .filter(move |neighbour| *neighbour != cell)
+}
+
+/// The `2 * D` cells sharing a face with `cell`.
+pub fn von_neumann<const D: usize>(cell: &[i64; D]) -> impl Iterator<Item = [i64; D]> {
+    let cell = *cell;
+    (0..2 * D).map(move |i| {
+        let mut neighbour = cell;
+        neighbour[i / 2] += if i % 2 == 0 { 1 } else { -1 };
+        neighbour
+    })
+}
+
+/// The steps to the six neighbours of a hexagonal tile, in axial coordinates where `[1, 0]`
+/// is east and `[1, 1]` south-east.
+pub const HEX: [[i64; 2]; 6] = [[1, 0], [1, 1], [0, 1], [-1, 0], [-1, -1], [0, -1]];
+
+/// The six tiles around `cell`, see [`HEX`].
+pub fn hex(cell: &[i64; 2]) -> impl Iterator<Item = [i64; 2]> {
+    let [x, y] = *cell;
+    HEX.into_iter().map(move |[dx, dy]| [x + dx, y + dy])
+}
+
+/// A grid of cells in an infinite background whose cells all share one state.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dense<T> {
+    pub grid: Grid<T>,
+    pub background: T,
+}
+
+impl<T> Dense<T> {
+    pub fn new(grid: Grid<T>, background: T) -> Dense<T> {
+        Dense { grid, background }
+    }
+
+    /// The cell at `pos`, the background outside the grid.
+    pub fn get(&self, pos: Pos) -> &T {
+        self.grid.get(pos).unwrap_or(&self.background)
+    }
+
+    /// How many cells `steps` away from `pos` satisfy `predicate`, counting the background.
+    pub fn count_around(&self, pos: Pos, steps: &[Pos], predicate: impl Fn(&T) -> bool) -> usize {
+        steps
+            .iter()
+            .filter(|(dx, dy)| predicate(self.get((pos.0 + dx, pos.1 + dy))))
+            .count()
+    }
+
+    /// The next generation, each cell becoming `rule` of this one and its position. The
+    /// grid grows by `grow` cells on every side, for patterns spreading into the background,
+    /// and the background becomes what `rule` gives far from the grid.
+    pub fn step(&self, grow: usize, mut rule: impl FnMut(&Dense<T>, Pos) -> T) -> Dense<T> {
+        let g = grow as isize;
+        let grid = Grid::from_fn(
+            self.grid.width() + 2 * grow,
+            self.grid.height() + 2 * grow,
+            |(x, y)| rule(self, (x - g, y - g)),
+        );
+        let far = isize::MIN / 4;
+        let background = rule(self, (far, far));
+        Dense { grid, background }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::ADJACENT;
+
+    #[test]
+    fn neighbourhoods() {
+        assert_eq!(moore(&[0, 0, 0]).count(), 26);
+        assert_eq!(moore(&[5, 5, 5, 5]).count(), 80);
+        assert!(moore(&[1, 1]).all(|[x, y]| (0..3).contains(&x) && (0..3).contains(&y)));
+        let mut faces: Vec<_> = von_neumann(&[0, 0, 0]).collect();
+        faces.sort();
+        assert_eq!(
+            faces,
+            [
+                [-1, 0, 0],
+                [0, -1, 0],
+                [0, 0, -1],
+                [0, 0, 1],
+                [0, 1, 0],
+                [1, 0, 0]
+            ]
+        );
+        assert_eq!(hex(&[0, 0]).collect::<HashSet<_>>().len(), 6);
+    }
+
+    #[test]
+    fn stepping() {
+        // A glider moves one cell diagonally every four generations.
+        let glider: HashSet<[i64; 2]> = [[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]].into();
+        let life =
+            |live: &HashSet<[i64; 2]>| sparse(live, moore, |alive, n| n == 3 || alive && n == 2);
+        let mut world = Automaton::new(glider.clone(), life);
+        let moved: HashSet<_> = glider.iter().map(|[x, y]| [x + 1, y + 1]).collect();
+        assert_eq!(world.run(4), &moved);
+        assert_eq!(world.generation, 4);
+
+        // A block in a dense grid settles at once, the background blinking around it.
+        let block = Grid::parse("....\n.##.\n.##.\n....\n", |c| Some(c == '#'), "a cell").unwrap();
+        let flip = |d: &Dense<bool>| {
+            d.step(0, |d, pos| {
+                let n = d.count_around(pos, &ADJACENT, |c| *c);
+                if d.grid.contains(pos) {
+                    n == 3 || *d.get(pos) && n == 2
+                } else {
+                    !d.background
+                }
+            })
+        };
+        let mut dense = Automaton::new(Dense::new(block, false), flip);
+        assert_eq!(
+            dense.find_cycle(),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+        assert_eq!(dense.step().grid.values().filter(|c| **c).count(), 4);
+
+        let mut counter = Automaton::new(0, |n: &i32| (n + 1).min(5));
+        assert_eq!(counter.run_until(|n| *n == 3), &3);
+        assert_eq!(counter.run_until_stable(), 6);
+        assert_eq!(
+            counter.find_cycle(),
+            Cycle {
+                start: 6,
+                length: 1
+            }
+        );
+        assert_eq!(counter.generation, 7);
+    }
+}
@@ -0,0 +1,277 @@
+//! Toolkit settings, each taken from the first layer that sets it: `AOC_*` environment
+//! variables, then an `aoc.toml` such as
+//!
+//! ```toml
+//! input_dir = "~/aoc/inputs"   # read as <input_dir>/<year>/<dd>, never downloaded
+//! cache_dir = "input"          # downloads go to <cache_dir>/<year>/<dd>
+//! session_file = "~/.config/aoc/session"
+//! year = 2024
+//! format = "json"
+//! jobs = 4
+//! ```
+//!
+//! Relative paths in the file are relative to the file, and `~/` is the home directory. The
+//! runner's `--year`, `--format` and `--jobs` flags win over `year`, `format` and `jobs`; the
+//! other settings have no flag.
+
+use std::path::{Path, PathBuf};
+
+use crate::input::Session;
+use crate::toml::{parse_value, strip_comment, Value};
+use crate::ParseError;
+
+/// The output formats of the runner.
+pub const FORMATS: [&str; 3] = ["human", "json", "tsv"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Where downloaded inputs are cached (`cache_dir`, `AOC_CACHE_DIR`).
+    pub cache_dir: Option<PathBuf>,
+    /// Inputs kept by hand, read instead of the cache (`input_dir`, `AOC_INPUT_DIR`).
+    pub input_dir: Option<PathBuf>,
+    /// The session cookie (`session`, `AOC_SESSION`).
+    pub session: Option<String>,
+    /// A file holding the session cookie (`session_file`, `AOC_SESSION_FILE`).
+    pub session_file: Option<PathBuf>,
+    /// The website, for testing against another server (`base_url`, `AOC_BASE_URL`).
+    pub base_url: Option<String>,
+    /// The year of commands given no `--year` (`year`, `AOC_YEAR`).
+    pub year: Option<u16>,
+    /// One of [`FORMATS`] (`format`, `AOC_FORMAT`).
+    pub format: Option<String>,
+    /// Threads for running many days at once (`jobs`, `AOC_JOBS`).
+    pub jobs: Option<usize>,
+}
+
+/// Keys of the file, next to their environment variables.
+const KEYS: [(&str, &str); 8] = [
+    ("cache_dir", "AOC_CACHE_DIR"),
+    ("input_dir", "AOC_INPUT_DIR"),
+    ("session", "AOC_SESSION"),
+    ("session_file", "AOC_SESSION_FILE"),
+    ("base_url", "AOC_BASE_URL"),
+    ("year", "AOC_YEAR"),
+    ("format", "AOC_FORMAT"),
+    ("jobs", "AOC_JOBS"),
+];
+
+impl Config {
+    /// Settles `key` from `value`, `base` anchoring relative paths.
+    fn set(&mut self, key: &str, value: Value, base: &Path) -> Result<(), String> {
+        let path = |value: Value| -> Result<PathBuf, String> {
+            let Value::String(text) = value else {
+                return Err("a \"path\"".to_string());
+            };
+            Ok(match text.strip_prefix("~/") {
+                Some(rest) => home().ok_or("a path, but HOME is not set")?.join(rest),
+                None => base.join(text),
+            })
+        };
+        let text = |value: Value| match value {
+            Value::String(text) if !text.is_empty() => Ok(text),
+            _ => Err("a non-empty \"string\"".to_string()),
+        };
+        match key {
+            "cache_dir" => self.cache_dir = Some(path(value)?),
+            "input_dir" => self.input_dir = Some(path(value)?),
+            "session" => self.session = Some(text(value)?),
+            "session_file" => self.session_file = Some(path(value)?),
+            "base_url" => self.base_url = Some(text(value)?),
+            "year" => {
+                self.year = match value {
+                    Value::Integer(n @ 2015..=9999) => Some(n as u16),
+                    _ => return Err("a year from 2015 on".to_string()),
+                }
+            }
+            "format" => {
+                self.format = match value {
+                    Value::String(f) if FORMATS.contains(&f.as_str()) => Some(f),
+                    _ => return Err(format!("one of {}", FORMATS.join(", "))),
+                }
+            }
+            "jobs" => {
+                self.jobs = match value {
+                    Value::Integer(n @ 1..=1024) => Some(n as usize),
+                    _ => return Err("a number of threads from 1 to 1024".to_string()),
+                }
+            }
+            _ => return Err(expected_keys()),
+        }
+        Ok(())
+    }
+
+    /// Reads the settings of an `aoc.toml`.
+    pub fn parse(input: &str, base: &Path) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+        for line in input.lines() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::within(input, line, "key = value"))?;
+            let (key, value) = (key.trim(), value.trim());
+            if !KEYS.iter().any(|(k, _)| *k == key) {
+                return Err(ParseError::within(input, key, expected_keys()));
+            }
+            config
+                .set(key, parse_value(input, value)?, base)
+                .map_err(|e| ParseError::within(input, value, &e))?;
+        }
+        Ok(config)
+    }
+
+    /// Reads `path`, an absent file setting nothing.
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text, path.parent().unwrap_or(Path::new("")))
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+        }
+    }
+
+    /// Reads the `AOC_*` variables through `var`, skipping empty ones. Relative paths are
+    /// relative to the working directory.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (key, name) in KEYS {
+            let Some(value) = var(name).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            let parsed = match key {
+                "year" | "jobs" => value.parse().map(Value::Integer).ok(),
+                _ => None,
+            };
+            config
+                .set(
+                    key,
+                    parsed.unwrap_or(Value::String(value.clone())),
+                    Path::new(""),
+                )
+                .map_err(|e| format!("{name}: expected {e}, found {value:?}"))?;
+        }
+        Ok(config)
+    }
+
+    /// `self`, with what `over` sets taking precedence. `session` and `session_file` are
+    /// one setting: a layer giving either replaces both.
+    pub fn merge(self, over: Config) -> Config {
+        let (session, session_file) = match (over.session, over.session_file) {
+            (None, None) => (self.session, self.session_file),
+            over => over,
+        };
+        Config {
+            cache_dir: over.cache_dir.or(self.cache_dir),
+            input_dir: over.input_dir.or(self.input_dir),
+            session,
+            session_file,
+            base_url: over.base_url.or(self.base_url),
+            year: over.year.or(self.year),
+            format: over.format.or(self.format),
+            jobs: over.jobs.or(self.jobs),
+        }
+    }
+
+    /// The file at `path`, overridden by the process environment.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let file = Config::from_file(path)?;
+        let env = Config::from_env(|name| std::env::var(name).ok())?;
+        Ok(file.merge(env))
+    }
+
+    /// The session cookie given directly, or else its `session_file`, left unread.
+    pub fn session(&self) -> Option<Session> {
+        match (&self.session, &self.session_file) {
+            (Some(cookie), _) => Some(Session::Cookie(cookie.clone())),
+            (None, Some(path)) => Some(Session::File(path.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+fn expected_keys() -> String {
+    let keys: Vec<_> = KEYS.iter().map(|(key, _)| *key).collect();
+    format!("one of {}", keys.join(", "))
+}
+
+fn home() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .filter(|h| !h.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers() {
+        let file = Config::parse(
+            "# mine\ncache_dir = \"in\"\nyear = 2023\nformat = \"tsv\" # for scripts\njobs = 2\n",
+            Path::new("/ws"),
+        )
+        .unwrap();
+        assert_eq!(file.cache_dir, Some(PathBuf::from("/ws/in")));
+        let env = Config::from_env(|name| match name {
+            "AOC_YEAR" => Some("2024".to_string()),
+            "AOC_FORMAT" => Some(String::new()),
+            "AOC_INPUT_DIR" => Some("/mine".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(
+            file.merge(env),
+            Config {
+                cache_dir: Some(PathBuf::from("/ws/in")),
+                input_dir: Some(PathBuf::from("/mine")),
+                year: Some(2024),
+                format: Some("tsv".to_string()),
+                jobs: Some(2),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let base = Path::new("");
+        let e = Config::parse("year = 2024\nformat = \"yaml\"\n", base).unwrap_err();
+        assert_eq!((e.line, e.column), (Some(2), Some(10)));
+        assert_eq!(e.expected, "one of human, json, tsv");
+        let e = Config::parse("theads = 4\n", base).unwrap_err();
+        assert_eq!((e.line, e.column), (Some(1), Some(1)));
+        assert!(e.expected.starts_with("one of cache_dir, input_dir,"));
+        assert!(Config::parse("jobs = 0\n", base).is_err());
+        assert!(Config::parse("year\n", base).is_err());
+        let e = Config::from_env(|name| (name == "AOC_JOBS").then(|| "many".to_string()));
+        assert_eq!(
+            e,
+            Err(
+                "AOC_JOBS: expected a number of threads from 1 to 1024, found \"many\"".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn sessions() {
+        let file = Config::parse("session_file = \"session\"\n", Path::new("/ws")).unwrap();
+        let in_file = Some(Session::File(PathBuf::from("/ws/session")));
+        assert_eq!(file.session(), in_file);
+        let direct = Config {
+            session: Some("xyz".to_string()),
+            ..Config::default()
+        };
+        let cookie = Some(Session::Cookie("xyz".to_string()));
+        assert_eq!(file.clone().merge(direct.clone()).session(), cookie);
+        assert_eq!(Config::default().session(), None);
+
+        // A session file in a later layer wins over a session in an earlier one.
+        let env = Config::from_env(|name| {
+            (name == "AOC_SESSION_FILE").then(|| "/ws/session".to_string())
+        })
+        .unwrap();
+        assert_eq!(direct.merge(env).session(), in_file);
+    }
+}
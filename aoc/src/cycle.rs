@@ -0,0 +1,206 @@
+//! Finding where a sequence of states starts repeating, to tell a loop from an end or to
+//! skip ahead to a far state.
+//!
+//! [`brent`] and [`floyd`] only keep a couple of states, comparing them as they go, and
+//! need a sequence that never ends. [`hashed`] keeps every state, so that a run which
+//! may end (a walk leaving its map, a game someone wins) is told apart from a loop, and
+//! any state of it can be looked up.
+//!
+//! ```
+//! use aoc::cycle::{self, Cycle};
+//!
+//! // 3, 9, 27 % 10 = 7, 21 % 10 = 1, 3, ...
+//! let step = |n: &u32| n * 3 % 10;
+//! assert_eq!(cycle::brent(3, step), Cycle { start: 0, length: 4 });
+//! assert_eq!(cycle::state_after(3, step, 1_000_000_001), 9);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// States `start + length * k` are all the same, for any `k`, and the first `start` states
+/// do not come back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step giving the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Brent's algorithm, stepping about `start + 2 * length` times.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // A hare `length` steps ahead meets the tortoise where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Floyd's tortoise and hare, the hare moving twice as fast.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    let mut tortoise = initial;
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, finding the cycle with [`brent`] first.
+pub fn state_after<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(initial.clone(), &mut step);
+    let mut state = initial;
+    for _ in 0..cycle.reduce(n) {
+        state = step(&state);
+    }
+    state
+}
+
+/// The states of a run, each once.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Option<Cycle>,
+}
+
+impl<S> History<S> {
+    /// The states in the order they came, up to the end or to the first one coming back.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// How the run repeats, or `None` if it ended.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// The state after `n` steps, `None` past the end of a run that ended.
+    pub fn state_after(&self, n: usize) -> Option<&S> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.reduce(n)),
+            None => self.states.get(n),
+        }
+    }
+}
+
+/// Steps from `initial` until `step` gives no state or one seen before.
+pub fn hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> History<S> {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    let mut cycle = None;
+    while let Some(next) = step(states.last().unwrap()) {
+        if let Some(&start) = seen.get(&next) {
+            cycle = Some(Cycle {
+                start,
+                length: states.len() - start,
+            });
+            break;
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+    History { states, cycle }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pointer_chasing() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 3
+        let next = [1, 2, 3, 4, 5, 3];
+        let step = |i: &usize| next[*i];
+        let expected = Cycle {
+            start: 3,
+            length: 3,
+        };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(
+            floyd(4, step),
+            Cycle {
+                start: 0,
+                length: 3
+            }
+        );
+        assert_eq!(expected.reduce(2), 2);
+        assert_eq!(expected.reduce(10), 4);
+        assert_eq!(state_after(0, step, 10), 4);
+        assert_eq!(
+            brent(7, |_: &i32| 7),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn histories() {
+        let looping = hashed(0, |i: &usize| Some([1, 2, 3, 4, 5, 3][*i]));
+        assert_eq!(
+            looping.cycle(),
+            Some(Cycle {
+                start: 3,
+                length: 3
+            })
+        );
+        assert_eq!(looping.states(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(looping.state_after(1000), Some(&4));
+
+        let ending = hashed(10, |n: &u32| n.checked_sub(3));
+        assert_eq!(ending.cycle(), None);
+        assert_eq!(ending.states(), [10, 7, 4, 1]);
+        assert_eq!(ending.state_after(3), Some(&1));
+        assert_eq!(ending.state_after(4), None);
+    }
+}
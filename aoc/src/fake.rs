@@ -0,0 +1,118 @@
+//! Test doubles for the HTTP side of [`crate::input`] and [`crate::submit`].
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::rc::Rc;
+use std::thread::JoinHandle;
+
+use crate::input::{Http, Response};
+
+/// A request seen by [`Fake`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    pub session: String,
+    /// The form of a POST, `None` for a GET.
+    pub form: Option<String>,
+}
+
+pub type Requests = Rc<RefCell<Vec<Request>>>;
+
+/// Answers requests with canned responses, in order; the last one answers all remaining
+/// requests.
+pub struct Fake {
+    responses: RefCell<VecDeque<Response>>,
+    pub requests: Requests,
+}
+
+impl Fake {
+    pub fn new(responses: impl IntoIterator<Item = (u16, &'static str)>) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| Response {
+                status,
+                body: body.to_string(),
+            })
+            .collect();
+        Fake {
+            responses: RefCell::new(responses),
+            requests: Rc::default(),
+        }
+    }
+
+    fn respond(&self, request: Request) -> Result<Response, String> {
+        self.requests.borrow_mut().push(request);
+        let mut responses = self.responses.borrow_mut();
+        match responses.len() {
+            0 => Err("no response left".to_string()),
+            1 => Ok(responses[0].clone()),
+            _ => Ok(responses.pop_front().unwrap()),
+        }
+    }
+}
+
+impl Http for Fake {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        self.respond(Request {
+            url: url.to_string(),
+            session: session.to_string(),
+            form: None,
+        })
+    }
+
+    fn post(&self, url: &str, session: &str, form: &str) -> Result<Response, String> {
+        self.respond(Request {
+            url: url.to_string(),
+            session: session.to_string(),
+            form: Some(form.to_string()),
+        })
+    }
+}
+
+/// Serves one HTTP/1.1 connection per `(status, body)` on a local port. Returns the base URL
+/// and a handle yielding the raw requests received.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&mut stream);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                request
+            })
+            .collect()
+    });
+    (base_url, server)
+}
+
+fn read_request(stream: &mut impl Read) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    let head_len = loop {
+        if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let n = stream.read(&mut buf).unwrap();
+        request.extend_from_slice(&buf[..n]);
+    };
+    let head = String::from_utf8_lossy(&request[..head_len]).to_lowercase();
+    let body_len = head
+        .lines()
+        .find_map(|l| l.strip_prefix("content-length: "))
+        .map_or(0, |n| n.trim().parse().unwrap());
+    while request.len() < head_len + body_len {
+        let n = stream.read(&mut buf).unwrap();
+        request.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(request).unwrap()
+}
@@ -0,0 +1,429 @@
+//! Points, directions and rotations, in 2D and 3D.
+//!
+//! The 2D axes are the ones of [`Grid`](crate::grid::Grid): `x` grows to the right and `y`
+//! grows down, so [`Dir4::Up`] is `(0, -1)` and turning right is clockwise on the screen.
+//!
+//! ```
+//! use aoc::geometry::{Dir4, Point2};
+//!
+//! let walked = Point2::ORIGIN + Dir4::Right.delta() * 3 + Dir4::from_char('^').unwrap().delta();
+//! assert_eq!(walked, Point2::new(3, -1));
+//! assert_eq!(walked.manhattan(), 4);
+//! assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Pos;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// The distance from the origin along the axes.
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// The distance from the origin when diagonal steps count as one, as for a king.
+    pub fn chebyshev(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// The sign of each coordinate: the single (possibly diagonal) step towards `self`.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The point turned a quarter clockwise around the origin.
+    pub fn rotate_right(self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// The point turned a quarter counterclockwise around the origin.
+    pub fn rotate_left(self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Dir4::ALL.into_iter().map(move |d| self + d.delta())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Dir8::ALL.into_iter().map(move |d| self + d.delta())
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2::new(x, y)
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((x, y): Pos) -> Point2 {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+/// A grid position, to index a [`Grid`](crate::grid::Grid).
+impl From<Point2> for Pos {
+    fn from(p: Point2) -> Pos {
+        (p.x as isize, p.y as isize)
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+
+    fn coords(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3::new(x, y, z)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! arithmetic {
+    ($point:ident { $($c:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+            fn add(self, other: $point) -> $point {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+            fn sub(self, other: $point) -> $point {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+            fn neg(self) -> $point {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+            fn mul(self, n: i64) -> $point {
+                $point { $($c: self.$c * n),+ }
+            }
+        }
+    };
+}
+
+arithmetic!(Point2 { x, y });
+arithmetic!(Point3 { x, y, z });
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Clockwise from up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Reads an arrow (`^>v<`), a letter (`URDL`) or a compass point (`NESW`).
+    pub fn from_char(c: char) -> Option<Dir4> {
+        match c {
+            '^' | 'U' | 'N' => Some(Dir4::Up),
+            '>' | 'R' | 'E' => Some(Dir4::Right),
+            'v' | 'D' | 'S' => Some(Dir4::Down),
+            '<' | 'L' | 'W' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    /// The step one cell this way.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    /// The direction after `quarters` quarter turns clockwise, counterclockwise when
+    /// negative.
+    pub fn turn(self, quarters: i64) -> Dir4 {
+        Dir4::ALL[(self as i64 + quarters).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        self.turn(-1)
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        self.turn(2)
+    }
+
+    /// Whether the direction is left or right.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+}
+
+/// One of the eight directions, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::Up => Point2::new(0, -1),
+            Dir8::UpRight => Point2::new(1, -1),
+            Dir8::Right => Point2::new(1, 0),
+            Dir8::DownRight => Point2::new(1, 1),
+            Dir8::Down => Point2::new(0, 1),
+            Dir8::DownLeft => Point2::new(-1, 1),
+            Dir8::Left => Point2::new(-1, 0),
+            Dir8::UpLeft => Point2::new(-1, -1),
+        }
+    }
+
+    /// The direction after `eighths` eighth turns clockwise, counterclockwise when negative.
+    pub fn turn(self, eighths: i64) -> Dir8 {
+        Dir8::ALL[(self as i64 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Dir8 {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Dir8 {
+        self.turn(-1)
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        self.turn(4)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Dir8 {
+        Dir8::ALL[d as usize * 2]
+    }
+}
+
+/// A rotation of 3D space by quarter turns, mapping axes onto (possibly negated) axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    /// The rows of its matrix.
+    matrix: [[i64; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// The 24 rotations, the identity first. Mirror images are left out: they are not
+    /// reachable by turning.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut rotations = Vec::with_capacity(24);
+        for axes in PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, axis) in axes.into_iter().enumerate() {
+                    matrix[row][axis] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+                let rotation = Rotation { matrix };
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    pub fn apply(&self, p: Point3) -> Point3 {
+        let [x, y, z] = self
+            .matrix
+            .map(|row| row.iter().zip(p.coords()).map(|(m, c)| m * c).sum::<i64>());
+        Point3::new(x, y, z)
+    }
+
+    /// The rotation undoing this one.
+    pub fn inverse(&self) -> Rotation {
+        let m = &self.matrix;
+        Rotation {
+            matrix: [0, 1, 2].map(|i| [m[0][i], m[1][i], m[2][i]]),
+        }
+    }
+
+    /// `other`, then this rotation.
+    pub fn after(&self, other: &Rotation) -> Rotation {
+        let (a, b) = (&self.matrix, &other.matrix);
+        Rotation {
+            matrix: [0, 1, 2].map(|i| [0, 1, 2].map(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn points_and_directions() {
+        let p = Point2::new(3, -4);
+        assert_eq!((p.manhattan(), p.chebyshev()), (7, 4));
+        assert_eq!(p + Point2::new(1, 1) * 2 - -p, Point2::new(8, -6));
+        assert_eq!(p.rotate_right().rotate_left(), p);
+        assert_eq!(Dir4::Up.delta().rotate_right(), Dir4::Right.delta());
+        assert_eq!(Dir4::Left.turn(3), Dir4::Down);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Right.reverse(), Dir4::Left);
+        assert_eq!(
+            "^>v<".chars().map(Dir4::from_char).collect::<Vec<_>>(),
+            "NESW".chars().map(Dir4::from_char).collect::<Vec<_>>()
+        );
+        assert_eq!(Dir4::from_char('x'), None);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(
+            Point2::ORIGIN
+                .neighbours8()
+                .map(Point2::chebyshev)
+                .sum::<i64>(),
+            8
+        );
+        assert_eq!(Point3::new(1, -2, 3).neighbours6().count(), 6);
+    }
+
+    #[test]
+    fn rotations() {
+        let all = Rotation::all();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0], Rotation::IDENTITY);
+        let p = Point3::new(1, 2, 3);
+        let images: HashSet<Point3> = all.iter().map(|r| r.apply(p)).collect();
+        assert_eq!(images.len(), 24);
+        for r in &all {
+            assert_eq!(r.inverse().apply(r.apply(p)), p);
+            assert_eq!(r.after(&r.inverse()), Rotation::IDENTITY);
+            assert!(all.contains(&r.after(&all[5])));
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! A dense rectangle of cells, the map of most grid puzzles.
+//!
+//! Positions are `(x, y)`: the column, then the row, counted from the top left. They are
+//! signed so that stepping off an edge gives a position that is simply not in the grid.
+//!
+//! ```
+//! use aoc::grid::Grid;
+//!
+//! let grid: Grid<char> = "#.\n.#\n".parse().unwrap();
+//! assert_eq!(grid.find(|c| *c == '.'), Some((1, 0)));
+//! assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+//! ```
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A cell position, `(x, y)`.
+pub type Pos = (isize, isize);
+
+/// The steps to the four orthogonal neighbours, clockwise from up.
+pub const ORTHOGONAL: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to the eight neighbours, diagonals included, clockwise from up.
+pub const ADJACENT: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid of `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A `width` by `height` grid holding `cell(pos)` at each position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x as isize, y as isize)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads one cell per character, `cell` rejecting the characters that are not `expected`.
+    /// Rows must all have the same length; trailing empty lines are ignored.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.trim_end_matches(['\n', '\r']).lines() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::within(input, &line[i..i + c.len_utf8()], expected)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::within(
+                        input,
+                        line,
+                        format!("a row of {width} cells"),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new("a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    /// The cell at `pos`, `None` outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height as isize).flat_map(move |y| (0..width as isize).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The cells, row by row.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// The positions `steps` away from `pos` that are in the grid.
+    fn around<'a>(&self, pos: Pos, steps: &'a [Pos]) -> impl Iterator<Item = Pos> + 'a {
+        let (width, height) = (self.width as isize, self.height as isize);
+        steps
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter(move |(x, y)| (0..width).contains(x) && (0..height).contains(y))
+    }
+
+    /// The orthogonal neighbours of `pos` in the grid, in the order of [`ORTHOGONAL`].
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.around(pos, &ORTHOGONAL)
+    }
+
+    /// The neighbours of `pos` in the grid, diagonals included, in the order of [`ADJACENT`].
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.around(pos, &ADJACENT)
+    }
+
+    /// The cells from `from` on, moving by `step` until leaving the grid.
+    pub fn line(&self, from: Pos, step: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(Some(from), move |(x, y)| Some((x + step.0, y + step.1)))
+            .map_while(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// Row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals going down and right, from the top right corner to the bottom left one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Pos, &T)>> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let starts = (0..width)
+            .rev()
+            .map(|x| (x, 0))
+            .chain((1..height).map(|y| (0, y)));
+        starts.map(|start| self.line(start, (1, 1)))
+    }
+
+    /// The diagonals going down and left, from the top left corner to the bottom right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Pos, &T)>> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let starts = (0..width)
+            .map(|x| (x, 0))
+            .chain((1..height).map(move |y| (width - 1, y)));
+        starts.map(|start| self.line(start, (-1, 1)))
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The grid of `f` of each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, rows becoming columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height as isize;
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width as isize;
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.offset(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("{pos:?} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.offset(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{pos:?} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl std::str::FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, Some, "a cell")
+    }
+}
+
+/// The cells row by row, each row on its own line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let grid = Grid::parse("12\n34\n\n", |c| c.to_digit(10), "a digit").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 0)], 2);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+
+        let e = Grid::parse("12\n3x\n", |c| c.to_digit(10), "a digit").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (Some(2), Some(2), "a digit")
+        );
+        let e = "ab\nabc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (Some(2), "a row of 2 cells"));
+        assert!("\n".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn walking() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        let line: String = grid.line((0, 1), (1, -1)).map(|(_, c)| c).collect();
+        assert_eq!(line, "db");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().count(), 3);
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|d| d.map(|(_, c)| c).collect())
+            .collect();
+        assert_eq!(diagonals, ["c", "bf", "ae", "d"]);
+        let anti: Vec<String> = grid
+            .anti_diagonals()
+            .map(|d| d.map(|(_, c)| c).collect())
+            .collect();
+        assert_eq!(anti, ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn reshaping() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+        let mut filled = Grid::new(2, 1, '.');
+        filled[(1, 0)] = '#';
+        assert_eq!(filled.to_string(), ".#\n");
+    }
+}
@@ -0,0 +1,314 @@
+//! RGB images, written as PPM or PNG, and animated GIFs, all without compression
+//! libraries: PNG data is stored uncompressed and GIF frames are LZW encoded here, each
+//! written out as it comes.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Row by row from the top left.
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Paints the `size` by `size` square whose top left corner is `(x, y)`, as far as it
+    /// lies in the image.
+    pub fn fill_square(&mut self, x: usize, y: usize, size: usize, colour: Rgb) {
+        for row in y..(y + size).min(self.height) {
+            for column in x..(x + size).min(self.width) {
+                self.pixels[row * self.width + column] = colour;
+            }
+        }
+    }
+
+    /// The binary PPM (`P6`) of the image.
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// The PNG of the image, its data in stored deflate blocks.
+    pub fn png(&self) -> Vec<u8> {
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, then the default compression, filter and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        chunk(&mut out, b"IHDR", &header);
+        let mut raw = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // Each row starts with its filter, none here.
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut out, b"IEND", &[]);
+        out
+    }
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// A zlib stream holding `data` uncompressed.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend(((b << 16) | a).to_be_bytes());
+    out
+}
+
+/// An animated GIF being written, looping forever, its colours all from one palette.
+pub struct Gif<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    /// Hundredths of a second per frame.
+    delay: u16,
+    palette: Vec<Rgb>,
+    /// Bits per palette index, at least 2 as LZW needs.
+    bits: u32,
+}
+
+impl<W: Write> Gif<W> {
+    /// Writes the header. Frames are cropped or padded to `width` by `height`, and the
+    /// palette holds up to 256 colours.
+    pub fn new(
+        mut out: W,
+        width: usize,
+        height: usize,
+        delay_ms: u64,
+        palette: Vec<Rgb>,
+    ) -> io::Result<Gif<W>> {
+        if palette.is_empty() || palette.len() > 256 {
+            return Err(io::Error::other("a GIF palette has 1 to 256 colours"));
+        }
+        let bits = (1..=8).find(|n| 1 << n >= palette.len()).unwrap().max(2);
+        let mut header = b"GIF89a".to_vec();
+        header.extend((width as u16).to_le_bytes());
+        header.extend((height as u16).to_le_bytes());
+        // A global colour table of 2^bits entries.
+        header.extend([0x80 | 0x70 | (bits as u8 - 1), 0, 0]);
+        for i in 0..1 << bits {
+            header.extend(palette.get(i).unwrap_or(&[0; 3]));
+        }
+        // The Netscape extension: loop forever.
+        header.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+        out.write_all(&header)?;
+        Ok(Gif {
+            out,
+            width,
+            height,
+            delay: (delay_ms / 10).min(u16::MAX as u64) as u16,
+            palette,
+            bits,
+        })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Writes a frame, failing on colours missing from the palette.
+    pub fn push(&mut self, image: &Image) -> io::Result<()> {
+        let mut indices = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let colour = if x < image.width && y < image.height {
+                    image.get(x, y)
+                } else {
+                    self.palette[0]
+                };
+                let index = self
+                    .palette
+                    .iter()
+                    .position(|c| *c == colour)
+                    .ok_or_else(|| {
+                        io::Error::other(format!("colour {colour:?} missing from the GIF palette"))
+                    })?;
+                indices.push(index as u8);
+            }
+        }
+        let mut frame = vec![0x21, 0xf9, 4, 0];
+        frame.extend(self.delay.to_le_bytes());
+        frame.extend([0, 0, 0x2c, 0, 0, 0, 0]);
+        frame.extend((self.width as u16).to_le_bytes());
+        frame.extend((self.height as u16).to_le_bytes());
+        frame.push(0);
+        frame.push(self.bits as u8);
+        for block in lzw(&indices, self.bits).chunks(255) {
+            frame.push(block.len() as u8);
+            frame.extend(block);
+        }
+        frame.push(0);
+        self.out.write_all(&frame)
+    }
+
+    /// Ends the file.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// GIF's variable-width LZW coding of `indices`, each under `1 << min_bits`.
+fn lzw(indices: &[u8], min_bits: u32) -> Vec<u8> {
+    let clear = 1u16 << min_bits;
+    let end = clear + 1;
+    let mut out = Vec::new();
+    let (mut acc, mut acc_bits) = (0u32, 0);
+    let mut emit = |code: u16, bits: u32, out: &mut Vec<u8>| {
+        acc |= (code as u32) << acc_bits;
+        acc_bits += bits;
+        while acc_bits >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut bits = min_bits + 1;
+    emit(clear, bits, &mut out);
+    let mut prefix: Option<u16> = None;
+    for &index in indices {
+        let Some(current) = prefix else {
+            prefix = Some(index as u16);
+            continue;
+        };
+        if let Some(&code) = table.get(&(current, index)) {
+            prefix = Some(code);
+            continue;
+        }
+        emit(current, bits, &mut out);
+        if next == 4096 {
+            emit(clear, bits, &mut out);
+            table.clear();
+            next = end + 1;
+            bits = min_bits + 1;
+        } else {
+            table.insert((current, index), next);
+            // The decoder, a code behind, widens its codes once it has filled this width.
+            if next == 1 << bits && bits < 12 {
+                bits += 1;
+            }
+            next += 1;
+        }
+        prefix = Some(index as u16);
+    }
+    if let Some(current) = prefix {
+        emit(current, bits, &mut out);
+        // Reading it, the decoder adds the code `next` and may widen its codes for the end.
+        if next == 1 << bits && bits < 12 {
+            bits += 1;
+        }
+    }
+    emit(end, bits, &mut out);
+    if acc_bits > 0 {
+        out.push(acc as u8);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn still_images() {
+        let mut image = Image::new(3, 2, [0; 3]);
+        image.fill_square(2, 1, 4, [255, 0, 0]);
+        assert_eq!(image.get(2, 1), [255, 0, 0]);
+        assert_eq!(image.get(1, 1), [0; 3]);
+        let ppm = image.ppm();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 18);
+
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        let png = image.png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        // A last block, stored, of two bytes.
+        assert_eq!(&zlib_stored(&[1, 2])[2..7], [1, 2, 0, 0xfd, 0xff]);
+    }
+
+    #[test]
+    fn animation() {
+        let palette = vec![[9, 9, 9], [1, 2, 3], [4, 5, 6]];
+        let mut gif = Gif::new(Vec::new(), 2, 2, 250, palette).unwrap();
+        gif.push(&Image::new(2, 2, [1, 2, 3])).unwrap();
+        gif.push(&Image::new(1, 1, [4, 5, 6])).unwrap();
+        assert!(gif.push(&Image::new(1, 1, [7, 7, 7])).is_err());
+        let bytes = gif.finish().unwrap();
+        assert!(bytes
+            .starts_with(b"GIF89a\x02\0\x02\0\xf1\0\0\x09\x09\x09\x01\x02\x03\x04\x05\x06\0\0\0"));
+        assert_eq!(bytes.iter().filter(|b| **b == 0x2c).count(), 2);
+        assert_eq!(bytes.last(), Some(&0x3b));
+        // Four index 0s, in 3-bit codes: clear, 0, the pair 0 0 as the new code 6, 0, end.
+        assert_eq!(lzw(&[0, 0, 0, 0], 2), [0b1000_0100, 0b0101_0001]);
+    }
+}
@@ -0,0 +1,459 @@
+//! Puzzle inputs: a local cache, filled from the event's website on first use, and the
+//! other places a day's input can come from.
+
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::Year;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// A response to an HTTP GET.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP client used to talk to the website, replaceable in tests.
+pub trait Http {
+    /// GETs `url`, authenticated with the `session` cookie.
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+    /// POSTs the url-encoded `form` to `url`, authenticated with the `session` cookie.
+    fn post(&self, url: &str, session: &str, form: &str) -> Result<Response, String>;
+}
+
+/// Talks through the `curl` binary, which also covers HTTPS.
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        self.request(url, session, "")
+    }
+
+    fn post(&self, url: &str, session: &str, form: &str) -> Result<Response, String> {
+        self.request(
+            url,
+            session,
+            &format!("data = \"{}\"\n", quote("form", form)?),
+        )
+    }
+}
+
+impl Curl {
+    fn request(&self, url: &str, session: &str, options: &str) -> Result<Response, String> {
+        let config = format!(
+            "url = \"{}\"\nheader = \"Cookie: session={}\"\nuser-agent = \"aoc-runner\"\nwrite-out = \"\\n%{{http_code}}\"\n{options}",
+            quote("url", url)?,
+            quote("session", session)?
+        );
+        // Options go through stdin so the session never shows up in the process list.
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("cannot run curl: {e}"))?;
+        child
+            .stdin
+            .take()
+            .expect("piped stdin")
+            .write_all(config.as_bytes())
+            .map_err(|e| format!("cannot configure curl: {e}"))?;
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("curl failed: {e}"))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| format!("unexpected curl output {stdout:?}"))?;
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| format!("unexpected HTTP status {status:?}"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Escapes `s` for a double-quoted value of a curl config file. Control characters are
+/// refused: a newline would end the value and start another option.
+fn quote(what: &str, s: &str) -> Result<String, String> {
+    if s.contains(char::is_control) {
+        return Err(format!("the {what} holds a control character"));
+    }
+    Ok(s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The session cookie, given directly or as the file holding it. The file is only read once
+/// a download needs it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Session {
+    Cookie(String),
+    File(PathBuf),
+}
+
+impl Session {
+    pub fn cookie(&self) -> Result<String, String> {
+        let path = match self {
+            Session::Cookie(cookie) => return Ok(cookie.clone()),
+            Session::File(path) => path,
+        };
+        let session = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read the session from {}: {e}", path.display()))?;
+        match session.trim() {
+            "" => Err(format!("{} holds no session", path.display())),
+            session => Ok(session.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(PathBuf, std::io::Error),
+    /// The input is not cached and there is no session token to download it.
+    NoSession {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    /// The session file cannot be read.
+    Session(String),
+    /// A file that should hold the input is not there.
+    Missing {
+        what: String,
+        path: PathBuf,
+    },
+    Http(String),
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            InputError::NoSession { year, day, path } => write!(
+                f,
+                "no input for {year} day {day} at {} and no session token to download it",
+                path.display()
+            ),
+            InputError::Session(e) => f.write_str(e),
+            InputError::Missing { what, path } => write!(f, "no {what} at {}", path.display()),
+            InputError::Http(e) => write!(f, "download failed: {e}"),
+            InputError::Status { url, status, body } => {
+                write!(f, "{url} answered {status}: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file named on the command line.
+    Path(PathBuf),
+    /// Standard input, named `-`.
+    Stdin,
+    /// The example next to the tests of the year crate, `tests/examples/dayNN.txt`.
+    Example,
+    /// The puzzle input.
+    Real,
+}
+
+impl Source {
+    /// `-` is standard input and anything else a path; without an argument, the example
+    /// or the real input.
+    pub fn from_arg(arg: Option<&str>, example: bool) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None if example => Source::Example,
+            None => Source::Real,
+        }
+    }
+}
+
+/// Resolves `(year, day)` to `<cache>/<year>/<dd>`, downloading inputs that are not there yet.
+pub struct Inputs {
+    pub cache: PathBuf,
+    /// Inputs kept by hand as `<dir>/<year>/<dd>`, read instead of the cache and never downloaded.
+    pub dir: Option<PathBuf>,
+    pub base_url: String,
+    pub session: Option<Session>,
+    pub http: Box<dyn Http>,
+}
+
+impl Inputs {
+    /// A cache in `cache`, downloading from the event's website with `curl`.
+    pub fn new(cache: impl Into<PathBuf>, session: Option<Session>) -> Self {
+        Inputs {
+            cache: cache.into(),
+            dir: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session,
+            http: Box::new(Curl),
+        }
+    }
+
+    /// Where the example of `day` is kept in the crate of `year`.
+    pub fn example_path(year: &Year, day: u8) -> PathBuf {
+        Path::new(year.dir)
+            .join("tests/examples")
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// The input of `day` of `year` from `source`.
+    pub fn resolve(&self, year: &Year, day: u8, source: &Source) -> Result<String, InputError> {
+        let (what, path) = match source {
+            Source::Stdin => {
+                let mut input = String::new();
+                return std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| InputError::Io(PathBuf::from("<stdin>"), e));
+            }
+            Source::Real if self.dir.is_none() => return self.get(year.year, day),
+            Source::Real => (
+                format!("input for {} day {day}", year.year),
+                self.real_path(year.year, day),
+            ),
+            Source::Example => (
+                format!("example for {} day {day}", year.year),
+                Inputs::example_path(year, day),
+            ),
+            Source::Path(path) => ("input file".to_string(), path.clone()),
+        };
+        std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => InputError::Missing { what, path },
+            _ => InputError::Io(path, e),
+        })
+    }
+
+    /// Where the real input of `day` is read from: under `dir` when set, the cache otherwise.
+    pub fn real_path(&self, year: u16, day: u8) -> PathBuf {
+        match &self.dir {
+            Some(dir) => dir.join(year.to_string()).join(format!("{day:02}")),
+            None => self.path(year, day),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache.join(year.to_string()).join(format!("{day:02}"))
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+
+    /// The input of `day`, downloaded and cached unless it already is.
+    pub fn get(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        if path.exists() {
+            return read(&path);
+        }
+        let Some(session) = &self.session else {
+            return Err(InputError::NoSession { year, day, path });
+        };
+        let session = session.cookie().map_err(InputError::Session)?;
+        let url = self.url(year, day);
+        let response = self.http.get(&url, &session).map_err(InputError::Http)?;
+        if response.status != 200 {
+            return Err(InputError::Status {
+                url,
+                status: response.status,
+                body: response.body,
+            });
+        }
+        write(&path, &response.body)?;
+        Ok(response.body)
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|e| InputError::Io(path.to_path_buf(), e))
+}
+
+/// Writes through a temporary file, so an interrupted download is never taken for an input.
+fn write(path: &Path, contents: &str) -> Result<(), InputError> {
+    let io = |e| InputError::Io(path.to_path_buf(), e);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io)?;
+    }
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, contents).map_err(io)?;
+    std::fs::rename(&partial, path).map_err(io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake::{serve, Fake, Request, Requests};
+    use std::rc::Rc;
+
+    fn inputs(name: &str, status: u16) -> (Inputs, Requests) {
+        let cache = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache);
+        let fake = Fake::new([(status, "1\n2\n")]);
+        let requests = Rc::clone(&fake.requests);
+        let inputs = Inputs {
+            cache,
+            dir: None,
+            base_url: "http://localhost:8080/".to_string(),
+            session: Some(Session::Cookie("secret".to_string())),
+            http: Box::new(fake),
+        };
+        (inputs, requests)
+    }
+
+    #[test]
+    fn control_characters_are_not_sent() {
+        let e = Curl.get(
+            "http://localhost:8080/",
+            "secret\nurl = \"http://elsewhere/\"",
+        );
+        assert_eq!(e, Err("the session holds a control character".to_string()));
+        assert_eq!(quote("url", "a\"b\\c"), Ok("a\\\"b\\\\c".to_string()));
+    }
+
+    #[test]
+    fn downloads_once_then_reads_the_cache() {
+        let (inputs, requests) = inputs("once", 200);
+        assert_eq!(inputs.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(inputs.get(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(
+            *requests.borrow(),
+            [Request {
+                url: "http://localhost:8080/2022/day/1/input".to_string(),
+                session: "secret".to_string(),
+                form: None,
+            }]
+        );
+        assert!(inputs.path(2022, 1).ends_with("2022/01"));
+        std::fs::remove_dir_all(&inputs.cache).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (inputs, requests) = inputs("failed", 404);
+        assert!(matches!(
+            inputs.get(2022, 2),
+            Err(InputError::Status { status: 404, .. })
+        ));
+        assert!(!inputs.path(2022, 2).exists());
+        assert!(inputs.get(2022, 2).is_err());
+        assert_eq!(requests.borrow().len(), 2);
+    }
+
+    #[test]
+    fn no_session_no_download() {
+        let (mut inputs, requests) = inputs("anonymous", 200);
+        inputs.session = None;
+        assert!(matches!(
+            inputs.get(2022, 3),
+            Err(InputError::NoSession { .. })
+        ));
+        assert!(requests.borrow().is_empty());
+    }
+
+    #[test]
+    fn session_file_read_only_to_download() {
+        let (mut inputs, requests) = inputs("session-file", 200);
+        let file = inputs.cache.with_extension("session");
+        inputs.session = Some(Session::File(file.clone()));
+        std::fs::create_dir_all(inputs.cache.join("2022")).unwrap();
+        std::fs::write(inputs.path(2022, 4), "cached\n").unwrap();
+        assert_eq!(inputs.get(2022, 4).unwrap(), "cached\n");
+        assert!(matches!(inputs.get(2022, 5), Err(InputError::Session(_))));
+        std::fs::write(&file, "secret\n").unwrap();
+        assert_eq!(inputs.get(2022, 5).unwrap(), "1\n2\n");
+        assert_eq!(requests.borrow()[0].session, "secret");
+        std::fs::remove_dir_all(&inputs.cache).unwrap();
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(Source::from_arg(Some("-"), false), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("in.txt"), false),
+            Source::Path(PathBuf::from("in.txt"))
+        );
+        assert_eq!(Source::from_arg(None, true), Source::Example);
+        assert_eq!(Source::from_arg(None, false), Source::Real);
+
+        let (mut inputs, requests) = inputs("sources", 200);
+        let dir = inputs.cache.join("by-hand");
+        std::fs::create_dir_all(dir.join("2022")).unwrap();
+        std::fs::write(dir.join("2022/05"), "kept\n").unwrap();
+        inputs.dir = Some(dir.clone());
+        let year = Year {
+            year: 2022,
+            dir: env!("CARGO_MANIFEST_DIR"),
+            days: &[],
+        };
+        assert_eq!(inputs.resolve(&year, 5, &Source::Real).unwrap(), "kept\n");
+        let e = inputs.resolve(&year, 6, &Source::Real).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "no input for 2022 day 6 at {}",
+                dir.join("2022/06").display()
+            )
+        );
+        assert!(requests.borrow().is_empty());
+        let e = inputs.resolve(&year, 6, &Source::Example).unwrap_err();
+        assert!(
+            e.to_string().starts_with("no example for 2022 day 6 at "),
+            "{e}"
+        );
+        assert!(e.to_string().ends_with("tests/examples/day06.txt"), "{e}");
+        let e = inputs
+            .resolve(&year, 6, &Source::Path(dir.join("nope")))
+            .unwrap_err();
+        assert!(matches!(e, InputError::Missing { .. }), "{e}");
+        std::fs::remove_dir_all(&inputs.cache).unwrap();
+    }
+
+    #[test]
+    fn curl_against_a_local_server() {
+        let (base_url, server) = serve(vec![(200, "1\n2\n"), (200, "ok")]);
+        let response = Curl
+            .get(&format!("{base_url}/2022/day/1/input"), "secret")
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "1\n2\n".to_string()
+            }
+        );
+        let response = Curl
+            .post(
+                &format!("{base_url}/2022/day/1/answer"),
+                "secret",
+                "level=1&answer=42",
+            )
+            .unwrap();
+        assert_eq!(response.body, "ok");
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("Cookie: session=secret"));
+        assert!(requests[1].starts_with("POST /2022/day/1/answer "));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=42"));
+    }
+}
@@ -0,0 +1,320 @@
+use std::error::Error;
+use std::fmt;
+
+pub mod answers;
+pub mod automaton;
+pub mod config;
+pub mod cycle;
+#[cfg(test)]
+mod fake;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod ocr;
+pub mod render;
+pub mod search;
+pub mod submit;
+mod toml;
+pub mod trace;
+
+/// Parses a raw puzzle input with the parameters for its kind, keeping it ready to answer
+/// either part. Registered through [`solution`].
+pub type Parse = fn(&str, InputKind) -> Result<Box<dyn Parsed>, ParseError>;
+
+/// The solutions of one event, as registered by its crate.
+pub struct Year {
+    pub year: u16,
+    /// Root of the year crate.
+    pub dir: &'static str,
+    pub days: &'static [(u8, Parse)],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<Parse> {
+        self.days.iter().find(|(n, _)| *n == day).map(|(_, d)| *d)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part has no working solution yet.
+    Unsolved,
+    /// The solution ran but found no answer in this input, e.g. no two entries summing to
+    /// the target.
+    NoAnswer,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Unsolved => f.write_str("unsolved"),
+            Answer::NoAnswer => f.write_str("no answer"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Why a puzzle input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text in the input, when known.
+    pub line: Option<usize>,
+    /// 1-based column, in characters, of the offending text, when known.
+    pub column: Option<usize>,
+    /// The offending text, when there is one.
+    pub text: Option<String>,
+    /// What the input should have had instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about the input as a whole, e.g. a missing section.
+    pub fn new(expected: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            column: None,
+            text: None,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error about `text`, located from where it sits in `input` when it is a slice
+    /// of it.
+    pub fn within(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+        let (line, column) = if offset <= input.len() && text.len() <= input.len() - offset {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            (
+                Some(before.matches('\n').count() + 1),
+                Some(before[line_start..].chars().count() + 1),
+            )
+        } else {
+            (None, None)
+        };
+        ParseError {
+            line,
+            column,
+            text: Some(text.to_string()),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("parse error")?;
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at {line}:{column}")?,
+            (Some(line), None) => write!(f, " at line {line}")?,
+            _ => {}
+        }
+        write!(f, ": expected {}", self.expected)?;
+        if let Some(text) = &self.text {
+            write!(f, ", found {text:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, into a `T`, or reports where it is in `input`
+/// and that `expected` was expected.
+pub fn parse_at<T: std::str::FromStr>(
+    input: &str,
+    text: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::within(input, text, expected))
+}
+
+/// Whether an input is the real one or an example from the puzzle text, which often comes
+/// with smaller sizes or thresholds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    #[default]
+    Real,
+    Example,
+}
+
+/// A value the puzzle text fixes, with its value for the real input and for the examples,
+/// e.g. `const SIZE: Param<usize> = Param { real: 70, example: 6 };`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param<T> {
+    pub real: T,
+    pub example: T,
+}
+
+impl<T: Copy> Param<T> {
+    /// The value for `kind` of input.
+    pub fn get(self, kind: InputKind) -> T {
+        match kind {
+            InputKind::Real => self.real,
+            InputKind::Example => self.example,
+        }
+    }
+}
+
+/// A puzzle solution: parse the input once, then answer each part from it.
+pub trait Solution {
+    type Input;
+
+    /// Parses the real input. Implement either this or [`Solution::parse_with`].
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, InputKind::Real)
+    }
+
+    /// Parses an input of the given kind; days whose [`Param`]s differ between the examples
+    /// and the real input implement this instead of [`Solution::parse`].
+    fn parse_with(input: &str, kind: InputKind) -> Result<Self::Input, ParseError> {
+        let _ = kind;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// A parsed input whose solution is no longer known statically.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Answer;
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => S::part1(&self.0),
+            Part::Two => S::part2(&self.0),
+        }
+    }
+}
+
+/// Registry entry for `S`, e.g. `(1, aoc::solution::<day01::Day01>())`.
+pub const fn solution<S: Solution + 'static>() -> Parse
+where
+    S::Input: 'static,
+{
+    |input, kind| Ok(Box::new(Input::<S>(S::parse_with(input, kind)?)))
+}
+
+/// Parses `input` and answers both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input), S::part2(&input)))
+}
+
+/// Declares a `#[test]` checking a [`Solution`] against a puzzle example stored in
+/// `tests/examples/` next to the invoking test file, with the example values of its
+/// [`Param`]s. Parts without a known answer for that example are left out.
+#[macro_export]
+macro_rules! example {
+    ($(#[$attr:meta])* $name:ident: $solution:ty, $file:literal $(, part1 = $part1:expr)? $(, part2 = $part2:expr)? $(,)?) => {
+        $(#[$attr])*
+        #[test]
+        fn $name() {
+            let input = <$solution as $crate::Solution>::parse_with(
+                include_str!(concat!("examples/", $file)),
+                $crate::InputKind::Example,
+            )
+                .unwrap_or_else(|e| panic!("{}: {e}", $file));
+            $(assert_eq!(
+                <$solution as $crate::Solution>::part1(&input),
+                $crate::Answer::from($part1),
+                "{} part 1",
+                $file
+            );)?
+            $(assert_eq!(
+                <$solution as $crate::Solution>::part2(&input),
+                $crate::Answer::from($part2),
+                "{} part 2",
+                $file
+            );)?
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors_locate_their_text() {
+        let input = "1 2\n3 x4\n";
+        let text = &input[6..8];
+        let e = ParseError::within(input, text, "a number");
+        assert_eq!((e.line, e.column), (Some(2), Some(3)));
+        assert_eq!(
+            e.to_string(),
+            "parse error at 2:3: expected a number, found \"x4\""
+        );
+        assert_eq!(parse_at::<u8>(input, text, "a number"), Err(e));
+
+        let elsewhere = ParseError::within(input, "x4", "a number");
+        assert_eq!((elsewhere.line, elsewhere.column), (None, None));
+        assert_eq!(
+            ParseError::new("a second section").to_string(),
+            "parse error: expected a second section"
+        );
+    }
+
+    #[test]
+    fn params_follow_the_input_kind() {
+        const SIZE: Param<usize> = Param {
+            real: 70,
+            example: 6,
+        };
+        assert_eq!(SIZE.get(InputKind::Real), 70);
+        assert_eq!(SIZE.get(InputKind::Example), 6);
+    }
+}
@@ -0,0 +1,166 @@
+//! Reading the capital letters some puzzles draw as their answer, in the 4x6 font of most
+//! years or the 6x10 one of 2018.
+//!
+//! ```
+//! use aoc::grid::Grid;
+//!
+//! let screen = Grid::parse(
+//!     "#..#.###.\n#..#..#..\n####..#..\n#..#..#..\n#..#..#..\n#..#.###.\n",
+//!     |c| Some(c == '#'),
+//!     "a pixel",
+//! )
+//! .unwrap();
+//! assert_eq!(aoc::ocr::recognise(&screen).as_deref(), Some("HI"));
+//! ```
+
+use crate::grid::{Grid, Pos};
+use crate::render::Frame;
+use crate::Answer;
+
+type Glyph = (char, &'static [&'static str]);
+
+/// The letters seen in puzzles so far, six rows high.
+const SMALL: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letters of 2018, ten rows high.
+#[rustfmt::skip]
+const LARGE: &[Glyph] = &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// The letters drawn by the lit cells of `screen`, separated by unlit columns. `None` if
+/// the letters are not six or ten cells high or one of them is not in the font.
+pub fn recognise(screen: &Grid<bool>) -> Option<String> {
+    let lit: Vec<Pos> = screen
+        .iter()
+        .filter(|(_, on)| **on)
+        .map(|(p, _)| p)
+        .collect();
+    let (top, bottom) = (
+        lit.iter().map(|p| p.1).min()?,
+        lit.iter().map(|p| p.1).max()?,
+    );
+    let font = match bottom - top + 1 {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+    let column = |x: isize| -> String {
+        (top..=bottom)
+            .map(|y| if screen[(x, y)] { '#' } else { '.' })
+            .collect()
+    };
+    let columns: Vec<String> = (0..screen.width() as isize).map(column).collect();
+    columns
+        .split(|c| !c.contains('#'))
+        .filter(|letter| !letter.is_empty())
+        .map(|letter| {
+            font.iter()
+                .find(|(_, rows)| columns_of(rows) == letter)
+                .map(|(c, _)| *c)
+        })
+        .collect()
+}
+
+/// The columns of a glyph, top to bottom.
+fn columns_of(rows: &[&str]) -> Vec<String> {
+    (0..rows[0].len())
+        .map(|x| rows.iter().map(|row| &row[x..=x]).collect())
+        .collect()
+}
+
+/// The letters of `screen`, or the screen itself, to be read by a person, if they are not
+/// all known.
+pub fn answer(screen: &Grid<bool>) -> Answer {
+    match recognise(screen) {
+        Some(letters) => letters.into(),
+        None => Frame::new()
+            .grid(screen, |on| on.then_some('#'))
+            .bounds(
+                (0, 0),
+                (screen.width() as isize - 1, screen.height() as isize - 1),
+            )
+            .to_string()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The letters of `font` side by side, a blank column after each.
+    fn spell(font: &[Glyph], word: &str) -> Grid<bool> {
+        let glyphs: Vec<_> = word
+            .chars()
+            .map(|c| font.iter().find(|(g, _)| *g == c).unwrap().1)
+            .collect();
+        let rows = (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|g| format!("{}.", g[y]))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+        Grid::parse(&rows, |c| Some(c == '#'), "a pixel").unwrap()
+    }
+
+    #[test]
+    fn fonts() {
+        for font in [SMALL, LARGE] {
+            let word: String = font.iter().map(|(c, _)| *c).collect();
+            assert_eq!(recognise(&spell(font, &word)), Some(word));
+            assert!(font
+                .iter()
+                .all(|(_, rows)| rows.iter().all(|row| row.len() == rows[0].len())));
+        }
+    }
+
+    #[test]
+    fn unknown_letters() {
+        let mut screen = spell(SMALL, "HELLO");
+        screen[(0, 0)] = false;
+        assert_eq!(recognise(&screen), None);
+        let Answer::Text(drawn) = answer(&screen) else {
+            panic!("not drawn")
+        };
+        assert!(drawn.starts_with("...#.####.#....#.....##..\n#..#.#"));
+        assert_eq!(answer(&spell(SMALL, "HELLO")), Answer::from("HELLO"));
+        assert_eq!(recognise(&Grid::new(3, 3, false)), None);
+    }
+}
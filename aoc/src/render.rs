@@ -0,0 +1,631 @@
+//! Drawing grids and point sets as text, and animating or recording them.
+//!
+//! A [`Frame`] collects glyphs at positions, from a [`Grid`] or any set of points, and
+//! prints as rows of text. [`animate!`](crate::animate) shows frames one after the other
+//! on stderr with a caption and a [`Legend`] colouring each glyph, and saves them as
+//! images, when asked for by specs from the runner's `--animate` and `--record` or the
+//! `AOC_ANIMATE` and `AOC_RECORD` variables. Both are comma separated targets, as for
+//! [tracing](crate::trace) (`2024::day15`, or `all`), with settings:
+//!
+//! - animation: `delay=MS` between frames (100 by default) and `colour=on`, `off` or
+//!   `auto`, colours and clearing the screen being on in a terminal unless `NO_COLOR` is
+//!   set;
+//! - recording: `format=gif` (the default), `png` or `ppm`, the directory `dir=PATH`
+//!   (`frames` by default), `scale=N` pixels per cell (4), `delay=MS` between GIF frames
+//!   (100) and `every=N` to keep one frame in `N`. Files are named after the module, one
+//!   numbered image per frame, or one GIF per run of frames of the same size.
+//!
+//! ```
+//! use aoc::render::{Colour, Frame, Legend};
+//!
+//! let frame = Frame::new().points([(0, 0), (2, 1)], '#').point((1, 0), '@');
+//! assert_eq!(frame.to_string(), "#@.\n..#\n");
+//! let legend = Legend::new().entry('#', Colour::Blue, "wall");
+//! aoc::animate!(legend, frame, "step {}", 1);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+use crate::grid::{Grid, Pos};
+use crate::image::{Gif, Image, Rgb};
+use crate::trace::{Filter, Level};
+
+pub const ANIMATE_VAR: &str = "AOC_ANIMATE";
+pub const RECORD_VAR: &str = "AOC_RECORD";
+
+/// Image pixels of the background, and of glyphs the legend does not colour.
+const BACKGROUND: Rgb = [24, 24, 24];
+const FOREGROUND: Rgb = [220, 220, 220];
+
+/// The ANSI terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    const ALL: [Colour; 9] = [
+        Colour::Black,
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+        Colour::White,
+        Colour::Grey,
+    ];
+
+    /// The colour in images, close to common terminal themes.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [255, 255, 255],
+            Colour::Grey => [118, 118, 118],
+        }
+    }
+
+    /// The escape code setting this as the foreground colour.
+    fn code(self) -> u8 {
+        match self {
+            Colour::Black => 30,
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// What the glyphs of a frame stand for, and their colours.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Legend {
+    entries: Vec<(char, Colour, String)>,
+}
+
+impl Legend {
+    pub fn new() -> Legend {
+        Legend::default()
+    }
+
+    /// Colours `glyph`, listed with `label` under the frame unless that is empty.
+    pub fn entry(mut self, glyph: char, colour: Colour, label: impl Into<String>) -> Legend {
+        self.entries.push((glyph, colour, label.into()));
+        self
+    }
+
+    fn colour(&self, glyph: char) -> Option<Colour> {
+        self.entries
+            .iter()
+            .find(|(g, _, _)| *g == glyph)
+            .map(|(_, colour, _)| *colour)
+    }
+}
+
+/// Glyphs at positions, drawn over a background from the top left corner of their bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    glyphs: HashMap<Pos, char>,
+    /// The corners drawn, both included; the extent of the glyphs if not set.
+    bounds: Option<(Pos, Pos)>,
+    background: char,
+    wide: bool,
+}
+
+impl Default for Frame {
+    fn default() -> Frame {
+        Frame::new()
+    }
+}
+
+impl Frame {
+    /// An empty frame, its background `.`.
+    pub fn new() -> Frame {
+        Frame {
+            glyphs: HashMap::new(),
+            bounds: None,
+            background: '.',
+            wide: false,
+        }
+    }
+
+    pub fn background(mut self, glyph: char) -> Frame {
+        self.background = glyph;
+        self
+    }
+
+    /// Draws from `min` to `max`, both included, whatever the glyphs cover.
+    pub fn bounds(mut self, min: Pos, max: Pos) -> Frame {
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Draws every cell twice as wide, which looks square in most terminals.
+    pub fn wide(mut self) -> Frame {
+        self.wide = true;
+        self
+    }
+
+    /// Puts `glyph` at `pos`, over anything already there.
+    pub fn point(mut self, pos: Pos, glyph: char) -> Frame {
+        self.glyphs.insert(pos, glyph);
+        self
+    }
+
+    pub fn points(mut self, points: impl IntoIterator<Item = Pos>, glyph: char) -> Frame {
+        self.glyphs
+            .extend(points.into_iter().map(|pos| (pos, glyph)));
+        self
+    }
+
+    /// Every cell of `grid`, as the glyph `draw` gives it, or none.
+    pub fn grid<T>(mut self, grid: &Grid<T>, mut draw: impl FnMut(&T) -> Option<char>) -> Frame {
+        self.glyphs.extend(
+            grid.iter()
+                .filter_map(|(pos, cell)| Some((pos, draw(cell)?))),
+        );
+        self
+    }
+
+    /// The glyph drawn at `pos`.
+    pub fn get(&self, pos: Pos) -> char {
+        self.glyphs.get(&pos).copied().unwrap_or(self.background)
+    }
+
+    fn extent(&self) -> Option<(Pos, Pos)> {
+        self.bounds.or_else(|| {
+            let xs = self.glyphs.keys().map(|(x, _)| *x);
+            let ys = self.glyphs.keys().map(|(_, y)| *y);
+            Some((
+                (xs.clone().min()?, ys.clone().min()?),
+                (xs.max()?, ys.max()?),
+            ))
+        })
+    }
+
+    /// The rows of the frame, each glyph in its colour in `legend` when `colour` is set.
+    pub fn paint(&self, legend: &Legend, colour: bool) -> String {
+        let mut out = String::new();
+        let Some(((x0, y0), (x1, y1))) = self.extent() else {
+            return out;
+        };
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let glyph = self.get((x, y));
+                let code = legend.colour(glyph).filter(|_| colour).map(Colour::code);
+                if let Some(code) = code {
+                    let _ = write!(out, "\x1b[{code}m");
+                }
+                out.push(glyph);
+                if self.wide {
+                    out.push(glyph);
+                }
+                if code.is_some() {
+                    out.push_str("\x1b[0m");
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The frame as an image, each cell a square of `scale` pixels (twice as wide for a
+    /// [`wide`](Frame::wide) frame) in its colour in `legend`.
+    pub fn image(&self, legend: &Legend, scale: usize) -> Image {
+        let Some(((x0, y0), (x1, y1))) = self.extent() else {
+            return Image::new(0, 0, BACKGROUND);
+        };
+        let columns = if self.wide { 2 } else { 1 };
+        let (width, height) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+        let mut image = Image::new(width * columns * scale, height * scale, BACKGROUND);
+        for (&(x, y), &glyph) in &self.glyphs {
+            if x < x0 || x > x1 || y < y0 || y > y1 || glyph == self.background {
+                continue;
+            }
+            let colour = legend.colour(glyph).map_or(FOREGROUND, Colour::rgb);
+            let (column, row) = ((x - x0) as usize * columns, (y - y0) as usize);
+            for c in column..column + columns {
+                image.fill_square(c * scale, row * scale, scale, colour);
+            }
+        }
+        image
+    }
+}
+
+/// Every colour [`Frame::image`] paints with.
+pub fn palette() -> Vec<Rgb> {
+    let mut palette = vec![BACKGROUND, FOREGROUND];
+    palette.extend(Colour::ALL.map(Colour::rgb));
+    palette
+}
+
+/// The frame without colours.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.paint(&Legend::default(), false))
+    }
+}
+
+/// Parses the targets of a spec, handing each `key=value` setting to `setting`.
+fn parse_spec(
+    spec: &str,
+    mut setting: impl FnMut(&str, &str) -> Result<(), String>,
+) -> Result<Filter, String> {
+    let mut targets = Vec::new();
+    for item in spec.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+        match item.split_once('=') {
+            Some((key, value)) => setting(key.trim(), value.trim())?,
+            None if item == "all" => targets.push(Level::Trace.name()),
+            None => targets.push(item),
+        }
+    }
+    // Every target is a directive showing everything, the level being irrelevant here.
+    Filter::parse(&targets.join(","))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, expected: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {value:?} (expected {expected})"))
+}
+
+/// What is animated, and how.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    targets: Filter,
+    pub delay: Duration,
+    /// Colours and screen clearing, `None` to use them in a terminal.
+    pub colour: Option<bool>,
+}
+
+impl Default for Animation {
+    fn default() -> Animation {
+        Animation {
+            targets: Filter::default(),
+            delay: Duration::from_millis(100),
+            colour: None,
+        }
+    }
+}
+
+impl Animation {
+    pub fn parse(spec: &str) -> Result<Animation, String> {
+        let mut animation = Animation::default();
+        animation.targets = parse_spec(spec, |key, value| {
+            match (key, value) {
+                ("delay", ms) => {
+                    animation.delay = Duration::from_millis(parse_number(ms, "milliseconds")?)
+                }
+                ("colour", "on") => animation.colour = Some(true),
+                ("colour", "off") => animation.colour = Some(false),
+                ("colour", "auto") => animation.colour = None,
+                ("colour", other) => {
+                    return Err(format!(
+                        "unknown colour {other:?} (expected on, off or auto)"
+                    ))
+                }
+                _ => {
+                    return Err(format!(
+                        "unknown setting {key:?} (expected delay or colour)"
+                    ))
+                }
+            }
+            Ok(())
+        })?;
+        Ok(animation)
+    }
+
+    pub fn enabled(&self, target: &str) -> bool {
+        self.targets.enabled(Level::Trace, target)
+    }
+}
+
+/// The image formats frames are saved in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Gif,
+    Png,
+    Ppm,
+}
+
+/// What is recorded, and how.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recording {
+    targets: Filter,
+    pub format: Format,
+    pub dir: PathBuf,
+    /// Pixels per cell.
+    pub scale: usize,
+    /// Between GIF frames.
+    pub delay: Duration,
+    /// One frame in `every` is saved.
+    pub every: usize,
+}
+
+impl Default for Recording {
+    fn default() -> Recording {
+        Recording {
+            targets: Filter::default(),
+            format: Format::Gif,
+            dir: PathBuf::from("frames"),
+            scale: 4,
+            delay: Duration::from_millis(100),
+            every: 1,
+        }
+    }
+}
+
+impl Recording {
+    pub fn parse(spec: &str) -> Result<Recording, String> {
+        let mut recording = Recording::default();
+        recording.targets = parse_spec(spec, |key, value| {
+            match (key, value) {
+                ("format", "gif") => recording.format = Format::Gif,
+                ("format", "png") => recording.format = Format::Png,
+                ("format", "ppm") => recording.format = Format::Ppm,
+                ("format", other) => {
+                    return Err(format!(
+                        "unknown format {other:?} (expected gif, png or ppm)"
+                    ))
+                }
+                ("dir", dir) => recording.dir = PathBuf::from(dir),
+                ("scale", n) => recording.scale = parse_number(n, "pixels per cell")?,
+                ("delay", ms) => {
+                    recording.delay = Duration::from_millis(parse_number(ms, "milliseconds")?)
+                }
+                ("every", n) => recording.every = parse_number(n, "a number of frames")?,
+                _ => {
+                    return Err(format!(
+                        "unknown setting {key:?} (expected format, dir, scale, delay or every)"
+                    ))
+                }
+            }
+            Ok(())
+        })?;
+        if recording.scale == 0 || recording.every == 0 {
+            return Err("scale and every must be at least 1".to_string());
+        }
+        Ok(recording)
+    }
+
+    pub fn enabled(&self, target: &str) -> bool {
+        self.targets.enabled(Level::Trace, target)
+    }
+}
+
+static ANIMATION: OnceLock<Animation> = OnceLock::new();
+static RECORDING: OnceLock<Recording> = OnceLock::new();
+
+/// Sets what is animated from `spec`, overriding `AOC_ANIMATE`. Fails once anything was
+/// drawn.
+pub fn init_animation(spec: &str) -> Result<(), String> {
+    let animation = Animation::parse(spec)?;
+    ANIMATION
+        .set(animation)
+        .map_err(|_| "animation is already configured".to_string())
+}
+
+/// Sets what is recorded from `spec`, overriding `AOC_RECORD`. Fails once anything was
+/// drawn.
+pub fn init_recording(spec: &str) -> Result<(), String> {
+    let recording = Recording::parse(spec)?;
+    RECORDING
+        .set(recording)
+        .map_err(|_| "recording is already configured".to_string())
+}
+
+/// The setting from `var` if it is valid, none otherwise.
+fn from_env<T: Default>(var: &str, parse: fn(&str) -> Result<T, String>) -> T {
+    let spec = std::env::var(var).unwrap_or_default();
+    parse(&spec).unwrap_or_else(|e| {
+        eprintln!("{var}: {e}");
+        T::default()
+    })
+}
+
+fn animation() -> &'static Animation {
+    ANIMATION.get_or_init(|| from_env(ANIMATE_VAR, Animation::parse))
+}
+
+fn recording() -> &'static Recording {
+    RECORDING.get_or_init(|| from_env(RECORD_VAR, Recording::parse))
+}
+
+/// Whether [`animate!`](crate::animate) in `target` draws anything.
+pub fn enabled(target: &str) -> bool {
+    animation().enabled(target) || recording().enabled(target)
+}
+
+/// The files being written for a target.
+#[derive(Default)]
+struct Sink {
+    frames: usize,
+    /// Images, or GIFs, started.
+    files: usize,
+    gif: Option<Gif<BufWriter<File>>>,
+}
+
+impl Sink {
+    fn save(&mut self, target: &str, recording: &Recording, image: &Image) -> io::Result<()> {
+        std::fs::create_dir_all(&recording.dir)?;
+        let mut path = |extension: &str| {
+            self.files += 1;
+            let stem = target.replace("::", "-");
+            recording
+                .dir
+                .join(format!("{stem}-{:05}.{extension}", self.files))
+        };
+        match recording.format {
+            Format::Png => std::fs::write(path("png"), image.png()),
+            Format::Ppm => std::fs::write(path("ppm"), image.ppm()),
+            Format::Gif => {
+                let size = (image.width(), image.height());
+                if self.gif.as_ref().is_none_or(|gif| gif.size() != size) {
+                    let file = BufWriter::new(File::create(path("gif"))?);
+                    let delay = recording.delay.as_millis() as u64;
+                    let gif = Gif::new(file, size.0, size.1, delay, palette())?;
+                    if let Some(done) = self.gif.replace(gif) {
+                        done.finish()?;
+                    }
+                }
+                self.gif.as_mut().unwrap().push(image)
+            }
+        }
+    }
+}
+
+static SINKS: Mutex<Vec<(String, Sink)>> = Mutex::new(Vec::new());
+
+fn record(target: &str, legend: &Legend, frame: &Frame) {
+    let recording = recording();
+    let mut sinks = SINKS.lock().unwrap_or_else(PoisonError::into_inner);
+    let i = match sinks.iter().position(|(t, _)| t == target) {
+        Some(i) => i,
+        None => {
+            sinks.push((target.to_string(), Sink::default()));
+            sinks.len() - 1
+        }
+    };
+    let sink = &mut sinks[i].1;
+    sink.frames += 1;
+    if (sink.frames - 1).is_multiple_of(recording.every) {
+        let image = frame.image(legend, recording.scale);
+        if let Err(e) = sink.save(target, recording, &image) {
+            eprintln!("{RECORD_VAR}: {}: {e}", recording.dir.display());
+        }
+    }
+}
+
+/// Completes the GIFs being recorded, to be called once everything is solved.
+pub fn finish() -> io::Result<()> {
+    let mut sinks = SINKS.lock().unwrap_or_else(PoisonError::into_inner);
+    for (_, sink) in sinks.iter_mut() {
+        if let Some(gif) = sink.gif.take() {
+            gif.finish()?;
+        }
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn show(target: &str, legend: &Legend, frame: &Frame, caption: fmt::Arguments) {
+    if recording().enabled(target) {
+        record(target, legend, frame);
+    }
+    let settings = animation();
+    if !settings.enabled(target) {
+        return;
+    }
+    let colour = settings.colour.unwrap_or_else(|| {
+        std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+    });
+    let mut out = String::new();
+    if colour {
+        // Back to the top left of a cleared screen.
+        out.push_str("\x1b[H\x1b[2J");
+    }
+    let _ = writeln!(out, "{caption}");
+    out.push_str(&frame.paint(legend, colour));
+    let entries: Vec<String> = legend
+        .entries
+        .iter()
+        .filter(|(_, _, label)| !label.is_empty())
+        .map(|(glyph, _, label)| format!("{glyph} {label}"))
+        .collect();
+    if !entries.is_empty() {
+        let _ = writeln!(out, "{}", entries.join("  "));
+    }
+    eprintln!("{out}");
+    std::thread::sleep(settings.delay);
+}
+
+/// Shows `frame` with `legend` under a caption formatted from the remaining arguments, then
+/// waits for the delay, when animation is enabled for the calling module, and saves it when
+/// recording is. The frame and the legend are only built then.
+#[macro_export]
+macro_rules! animate {
+    ($legend:expr, $frame:expr, $($caption:tt)+) => {
+        if $crate::render::enabled(module_path!()) {
+            $crate::render::show(module_path!(), &$legend, &$frame, format_args!($($caption)+));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drawing() {
+        let grid: Grid<char> = "#.#\n.##\n".parse().unwrap();
+        let frame = Frame::new()
+            .grid(&grid, |c| (*c == '#').then_some('#'))
+            .point((1, 0), '@')
+            .background(' ');
+        assert_eq!(frame.to_string(), "#@#\n ##\n");
+        let legend = Legend::new().entry('@', Colour::Red, "robot");
+        assert_eq!(
+            frame.paint(&legend, true).lines().next(),
+            Some("#\x1b[31m@\x1b[0m#")
+        );
+
+        let far = Frame::new().points([(5, 5), (7, 6)], 'o').wide();
+        assert_eq!(far.to_string(), "oo....\n....oo\n");
+        let bounded = far.bounds((5, 4), (6, 5));
+        assert_eq!(bounded.to_string(), "....\noo..\n");
+        assert_eq!(Frame::new().to_string(), "");
+
+        let image = frame.image(&legend, 2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(3, 1), Colour::Red.rgb());
+        assert_eq!(image.get(0, 0), FOREGROUND);
+        assert_eq!(image.get(0, 3), BACKGROUND);
+        assert_eq!(bounded.image(&legend, 1).width(), 4);
+    }
+
+    #[test]
+    fn settings() {
+        let animation = Animation::parse("2024::day15, delay=20,colour=off").unwrap();
+        assert!(animation.enabled("aoc2024::day15"));
+        assert!(!animation.enabled("aoc2024::day14"));
+        assert_eq!(animation.delay, Duration::from_millis(20));
+        assert_eq!(animation.colour, Some(false));
+        assert!(Animation::parse("all").unwrap().enabled("aoc2021::day11"));
+        assert!(!Animation::parse("").unwrap().enabled("aoc2021::day11"));
+        assert!(Animation::parse("delay=soon").is_err());
+        assert!(Animation::parse("speed=2").is_err());
+
+        let recording = Recording::parse("day14,format=png,dir=out,scale=2,every=10").unwrap();
+        assert!(recording.enabled("aoc2022::day14"));
+        assert_eq!(recording.format, Format::Png);
+        assert_eq!(recording.dir, PathBuf::from("out"));
+        assert_eq!((recording.scale, recording.every), (2, 10));
+        assert_eq!(Recording::parse("").unwrap().format, Format::Gif);
+        assert!(Recording::parse("format=jpeg").is_err());
+        assert!(Recording::parse("every=0").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! Shortest paths over any graph given as a neighbour function.
+//!
+//! Every search starts from one or more nodes at cost 0 and stops once `goal` accepts a
+//! node, or when everything reachable is settled (pass `|_| false` for that). The
+//! [`Search`] it returns holds the cost of each settled node and all its predecessors on
+//! shortest paths, to rebuild one path or the set of nodes on any of them.
+//!
+//! ```
+//! use aoc::search;
+//!
+//! // Steps of +1 or *2 from 1 to 10.
+//! let found = search::bfs([1], |n| [n + 1, n * 2], |n| *n == 10);
+//! assert_eq!(found.goal_cost(), Some(4));
+//! assert_eq!(found.path(&10), Some(vec![1, 2, 4, 5, 10]));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search settled.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    costs: HashMap<N, u64>,
+    /// The nodes one step before each settled node on its shortest paths, empty for starts.
+    predecessors: HashMap<N, Vec<N>>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    /// The node `goal` accepted, if the search reached one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The cost of the cheapest path to `node`, if it was settled.
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every settled node with its cost.
+    pub fn costs(&self) -> &HashMap<N, u64> {
+        &self.costs
+    }
+
+    /// The nodes right before `node` on its shortest paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on some cheapest path to `node`, `node` included.
+    pub fn on_shortest_paths(&self, node: &N) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        if self.costs.contains_key(node) {
+            let mut pending = vec![node.clone()];
+            while let Some(node) = pending.pop() {
+                pending.extend(
+                    self.predecessors(&node)
+                        .iter()
+                        .filter(|p| !seen.contains(*p))
+                        .cloned(),
+                );
+                seen.insert(node);
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search, every step costing 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(e) = search.costs.entry(start.clone()) {
+            e.insert(0);
+            search.predecessors.insert(start.clone(), Vec::new());
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let cost = search.costs[&node] + 1;
+        for next in neighbours(&node) {
+            match search.costs.entry(next.clone()) {
+                Entry::Vacant(e) => {
+                    e.insert(cost);
+                    search.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+                Entry::Occupied(e) if *e.get() == cost => {
+                    search
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's search, `neighbours` giving each next node with the cost of the step there.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+/// A* search: Dijkstra's, trying first the nodes whose cost so far plus `heuristic` is
+/// lowest. The heuristic must never overestimate the cost left to a goal, and should not
+/// drop by more than a step costs, for the first goal found to be a cheapest one.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    // The best cost found so far of the nodes not settled yet, and the nodes giving it.
+    let mut open: HashMap<N, (u64, Vec<N>)> = HashMap::new();
+    // Estimated total, cost so far and the index of the node in `nodes`; ties go to the
+    // cheaper node, so that a node's predecessors settle before it.
+    let mut heap = BinaryHeap::new();
+    let mut nodes = Vec::new();
+    for start in starts {
+        if let Entry::Vacant(e) = open.entry(start.clone()) {
+            e.insert((0, Vec::new()));
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let node = nodes[i].clone();
+        match open.get(&node) {
+            Some((best, _)) if *best == cost => {}
+            _ => continue,
+        }
+        let (_, predecessors) = open.remove(&node).unwrap();
+        search.costs.insert(node.clone(), cost);
+        search.predecessors.insert(node.clone(), predecessors);
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, step) in neighbours(&node) {
+            if search.costs.contains_key(&next) {
+                continue;
+            }
+            let next_cost = cost + step;
+            let (best, predecessors) = open.entry(next.clone()).or_insert((u64::MAX, Vec::new()));
+            if next_cost == *best {
+                predecessors.push(node.clone());
+            } else if next_cost < *best {
+                (*best, *predecessors) = (next_cost, vec![node.clone()]);
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small road map, both ways: a-b 1, a-c 4, b-c 2, b-d 5, c-d 1, d-e 3.
+    fn roads(node: &char) -> Vec<(char, u64)> {
+        let edges = [
+            ('a', 'b', 1),
+            ('a', 'c', 4),
+            ('b', 'c', 2),
+            ('b', 'd', 5),
+            ('c', 'd', 1),
+            ('d', 'e', 3),
+        ];
+        edges
+            .iter()
+            .filter_map(|&(from, to, cost)| match *node {
+                n if n == from => Some((to, cost)),
+                n if n == to => Some((from, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weighted() {
+        let found = dijkstra(['a'], roads, |n| *n == 'e');
+        assert_eq!(found.goal(), Some(&'e'));
+        assert_eq!(found.goal_cost(), Some(7));
+        assert_eq!(found.path(&'e'), Some(vec!['a', 'b', 'c', 'd', 'e']));
+        // a-b-c-d costs 4, a-c-d 5 and a-b-d 6.
+        assert_eq!(found.predecessors(&'d'), ['c']);
+
+        let everything = dijkstra(['e'], roads, |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.cost(&'a'), Some(7));
+        assert_eq!(everything.costs().len(), 5);
+
+        let guided = astar(
+            ['a'],
+            roads,
+            |n| if *n == 'e' { 0 } else { 1 },
+            |n| *n == 'e',
+        );
+        assert_eq!(guided.goal_cost(), Some(7));
+    }
+
+    #[test]
+    fn every_shortest_path() {
+        // A 3x3 grid walked from both top corners to the centre.
+        let inside = |(x, y): (i32, i32)| (0..3).contains(&x) && (0..3).contains(&y);
+        let steps = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(move |p| inside(*p))
+        };
+        let found = bfs([(0, 0), (2, 0)], steps, |p| *p == (1, 1));
+        assert_eq!(found.goal_cost(), Some(2));
+        assert_eq!(found.predecessors(&(1, 1)).len(), 3);
+        let on_paths = found.on_shortest_paths(&(1, 1));
+        assert_eq!(on_paths.len(), 6);
+        assert!(!on_paths.contains(&(0, 2)));
+        let path = found.path(&(1, 1)).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(found.cost(&path[0]), Some(0));
+        assert_eq!(bfs([0], |n| [(n + 1) % 5], |n| *n < 0).costs().len(), 5);
+    }
+}
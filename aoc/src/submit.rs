@@ -0,0 +1,611 @@
+//! Answer submission, with a local ledger of every attempt so known-wrong answers and
+//! cooldowns are caught before reaching the website.
+
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::input::{Curl, Http, DEFAULT_BASE_URL};
+use crate::{Answer, Part};
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// Not judged: an answer was submitted too recently.
+    Wait,
+    /// Not judged: the part is already solved.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Whether the answer was actually judged, and so must not be submitted again.
+    pub fn is_judgement(self) -> bool {
+        !matches!(self, Verdict::Wait | Verdict::AlreadySolved)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::AlreadySolved => "already-solved",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::Wait,
+            Verdict::AlreadySolved,
+        ]
+        .into_iter()
+        .find(|v| v.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "not judged, submitted too recently",
+            Verdict::AlreadySolved => "not judged, already solved",
+        })
+    }
+}
+
+/// The website's answer to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long until the next submission is accepted.
+    pub cooldown: Option<Duration>,
+    /// The text of the reply, without markup.
+    pub message: String,
+}
+
+/// Reads the verdict out of the HTML page answering a submission.
+pub fn parse_reply(html: &str) -> Option<Reply> {
+    let message = article_text(html);
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("answer too recently") {
+        Verdict::Wait
+    } else if message.contains("solving the right level") {
+        Verdict::AlreadySolved
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        return None;
+    };
+    let cooldown = match verdict {
+        Verdict::Wait => left_to_wait(&message),
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => wait_before_retry(&message),
+        Verdict::Correct | Verdict::AlreadySolved => None,
+    };
+    Some(Reply {
+        verdict,
+        cooldown,
+        message,
+    })
+}
+
+/// The text inside `<article>`, or the whole page when there is none, without tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(inner, _)| {
+            inner.split_once('>').map_or(inner, |(_, a)| a)
+        });
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 1m 23s left to wait."
+fn left_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left to wait")?;
+    amount
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |total, unit| {
+            let (n, unit) = unit.split_at(unit.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            let secs = match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(secs))
+        })
+}
+
+/// "Please wait one minute before trying again." / "please wait 5 minutes before ..."
+fn wait_before_retry(message: &str) -> Option<Duration> {
+    let lower = message.to_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches('.') {
+        "second" | "seconds" => Some(Duration::from_secs(n)),
+        "minute" | "minutes" => Some(Duration::from_secs(n * 60)),
+        _ => None,
+    }
+}
+
+/// One submission as recorded in the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time of the submission.
+    pub at: u64,
+    /// Unix time before which the part takes no other answer, `0` for none.
+    pub until: u64,
+}
+
+impl Attempt {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.year,
+            self.day,
+            self.part.number(),
+            self.verdict.name(),
+            self.at,
+            self.until,
+            self.answer
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Attempt> {
+        let mut fields = line.splitn(7, '\t');
+        let mut next = || fields.next();
+        Some(Attempt {
+            year: next()?.parse().ok()?,
+            day: next()?.parse().ok()?,
+            part: match next()? {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            },
+            verdict: Verdict::from_name(next()?)?,
+            at: next()?.parse().ok()?,
+            until: next()?.parse().ok()?,
+            answer: next()?.to_string(),
+        })
+    }
+}
+
+/// Every attempt made so far, stored one per line in a tab-separated file.
+pub struct Ledger {
+    pub path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`, empty when the file does not exist yet.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Ledger, SubmitError> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(SubmitError::Io(path, e)),
+        };
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Attempt::from_line(line).ok_or_else(|| SubmitError::Ledger {
+                    path: path.clone(),
+                    line: i + 1,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Ledger { path, attempts })
+    }
+
+    /// Appends `attempt` to the file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), SubmitError> {
+        let io = |e| SubmitError::Io(self.path.clone(), e);
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(io)?;
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(attempt.to_line().as_bytes()))
+            .map_err(io)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// The judged attempts at one part.
+    pub fn judged(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |a| {
+            a.year == year && a.day == day && a.part == part && a.verdict.is_judgement()
+        })
+    }
+
+    /// Why `answer` must not be submitted at time `now`, if it must not.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), SubmitError> {
+        for attempt in self.judged(year, day, part) {
+            if attempt.answer == answer {
+                return Err(SubmitError::Known {
+                    answer: answer.to_string(),
+                    verdict: attempt.verdict,
+                });
+            }
+            if attempt.verdict == Verdict::Correct {
+                return Err(SubmitError::Solved {
+                    correct: attempt.answer.clone(),
+                });
+            }
+            let (Ok(n), Ok(bound)) = (answer.parse::<i128>(), attempt.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            if (attempt.verdict == Verdict::TooHigh && n > bound)
+                || (attempt.verdict == Verdict::TooLow && n < bound)
+            {
+                return Err(SubmitError::OutOfBounds {
+                    answer: answer.to_string(),
+                    bound: attempt.answer.clone(),
+                    verdict: attempt.verdict,
+                });
+            }
+        }
+        let until = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .map(|a| a.until)
+            .max()
+            .unwrap_or(0);
+        if now < until {
+            return Err(SubmitError::Cooldown(Duration::from_secs(until - now)));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Io(PathBuf, std::io::Error),
+    Ledger {
+        path: PathBuf,
+        line: usize,
+    },
+    NoSession,
+    /// Not a value the website takes: unsolved, without an answer, or spanning several lines.
+    Unsubmittable(Answer),
+    /// This exact answer was already judged.
+    Known {
+        answer: String,
+        verdict: Verdict,
+    },
+    /// An earlier answer shows this one is wrong too.
+    OutOfBounds {
+        answer: String,
+        bound: String,
+        verdict: Verdict,
+    },
+    /// The part was already solved with another answer.
+    Solved {
+        correct: String,
+    },
+    /// Nothing may be submitted for that long.
+    Cooldown(Duration),
+    Http(String),
+    Status {
+        url: String,
+        status: u16,
+    },
+    /// The reply matched none of the known verdicts.
+    Unexpected(String),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            SubmitError::Ledger { path, line } => {
+                write!(f, "{}:{line}: malformed ledger entry", path.display())
+            }
+            SubmitError::NoSession => f.write_str("no session token to submit with"),
+            SubmitError::Unsubmittable(answer) => write!(f, "cannot submit {answer:?}"),
+            SubmitError::Known { answer, verdict } => {
+                write!(f, "{answer} was already submitted: {verdict}")
+            }
+            SubmitError::OutOfBounds {
+                answer,
+                bound,
+                verdict,
+            } => write!(f, "{answer} is wrong: {bound} was already {verdict}"),
+            SubmitError::Solved { correct } => write!(f, "already solved with {correct}"),
+            SubmitError::Cooldown(left) => write!(f, "wait {}s before submitting", left.as_secs()),
+            SubmitError::Http(e) => write!(f, "submission failed: {e}"),
+            SubmitError::Status { url, status } => write!(f, "{url} answered {status}"),
+            SubmitError::Unexpected(message) => write!(f, "unexpected reply: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Posts answers to the website, consulting and filling the ledger.
+pub struct Submitter {
+    pub ledger: Ledger,
+    pub base_url: String,
+    pub session: Option<String>,
+    pub http: Box<dyn Http>,
+}
+
+impl Submitter {
+    /// Submits to the event's website with `curl`, keeping the ledger at `ledger`.
+    pub fn new(ledger: impl Into<PathBuf>, session: Option<String>) -> Result<Self, SubmitError> {
+        Ok(Submitter {
+            ledger: Ledger::open(ledger)?,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session,
+            http: Box::new(Curl),
+        })
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/answer",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+
+    /// Submits `answer` unless the ledger already tells how it would be judged, or that
+    /// the website would not judge it yet.
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: SystemTime,
+    ) -> Result<Reply, SubmitError> {
+        let value = match answer {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) if !s.trim().is_empty() && !s.trim().contains('\n') => {
+                s.trim().to_string()
+            }
+            _ => return Err(SubmitError::Unsubmittable(answer.clone())),
+        };
+        let now = now.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        self.ledger.check(year, day, part, &value, now)?;
+        let session = self.session.as_deref().ok_or(SubmitError::NoSession)?;
+        let url = self.url(year, day);
+        let form = format!("level={}&answer={}", part.number(), form_encode(&value));
+        let response = self
+            .http
+            .post(&url, session, &form)
+            .map_err(SubmitError::Http)?;
+        if response.status != 200 {
+            return Err(SubmitError::Status {
+                url,
+                status: response.status,
+            });
+        }
+        let reply = parse_reply(&response.body)
+            .ok_or_else(|| SubmitError::Unexpected(article_text(&response.body)))?;
+        self.ledger.record(Attempt {
+            year,
+            day,
+            part,
+            answer: value,
+            verdict: reply.verdict,
+            at: now,
+            until: reply.cooldown.map_or(0, |c| now + c.as_secs()),
+        })?;
+        Ok(reply)
+    }
+}
+
+/// Percent-encodes `s` for an `application/x-www-form-urlencoded` body.
+fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Where the ledger lives inside an inputs cache.
+pub fn ledger_path(cache: &Path) -> PathBuf {
+    cache.join("submissions.tsv")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake::{serve, Fake};
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span>one gold star</span> closer.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, [...] Please wait one minute before trying again. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn submitter(name: &str, fake: Fake) -> Submitter {
+        let path = std::env::temp_dir().join(format!("aoc-ledger-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Submitter {
+            ledger: Ledger::open(path).unwrap(),
+            base_url: "http://localhost:8080".to_string(),
+            session: Some("secret".to_string()),
+            http: Box::new(fake),
+        }
+    }
+
+    #[test]
+    fn replies() {
+        let reply = |html| parse_reply(html).map(|r| (r.verdict, r.cooldown));
+        let secs = |s| Some(Duration::from_secs(s));
+        assert_eq!(reply(CORRECT), Some((Verdict::Correct, None)));
+        assert_eq!(reply(TOO_HIGH), Some((Verdict::TooHigh, secs(60))));
+        assert_eq!(reply(TOO_LOW), Some((Verdict::TooLow, secs(300))));
+        assert_eq!(reply(WAIT), Some((Verdict::Wait, secs(83))));
+        assert_eq!(reply(SOLVED), Some((Verdict::AlreadySolved, None)));
+        assert_eq!(reply("<html>Puzzle inputs differ by user.</html>"), None);
+        assert!(parse_reply(CORRECT)
+            .unwrap()
+            .message
+            .starts_with("That's the right answer! You are one gold star"));
+    }
+
+    #[test]
+    fn ledger_refuses_what_it_can_judge() {
+        let fake = Fake::new([(200, TOO_HIGH), (200, TOO_LOW), (200, CORRECT)]);
+        let requests = std::rc::Rc::clone(&fake.requests);
+        let mut submitter = submitter("refuse", fake);
+        let mut submit = |n: i64, t| submitter.submit(2022, 1, Part::One, &n.into(), at(t));
+
+        assert_eq!(submit(100, 1000).unwrap().verdict, Verdict::TooHigh);
+        assert!(matches!(submit(50, 1030), Err(SubmitError::Cooldown(d)) if d.as_secs() == 30));
+        assert!(matches!(
+            submit(100, 2000),
+            Err(SubmitError::Known {
+                verdict: Verdict::TooHigh,
+                ..
+            })
+        ));
+        assert!(matches!(
+            submit(120, 2000),
+            Err(SubmitError::OutOfBounds { .. })
+        ));
+        assert_eq!(submit(10, 2000).unwrap().verdict, Verdict::TooLow);
+        assert!(matches!(
+            submit(5, 3000),
+            Err(SubmitError::OutOfBounds { .. })
+        ));
+        assert_eq!(submit(50, 3000).unwrap().verdict, Verdict::Correct);
+        assert!(matches!(submit(51, 3000), Err(SubmitError::Solved { .. })));
+        assert_eq!(requests.borrow().len(), 3);
+        assert_eq!(
+            requests.borrow()[0].form.as_deref(),
+            Some("level=1&answer=100")
+        );
+
+        // A fresh load sees the same history.
+        let ledger = Ledger::open(&submitter.ledger.path).unwrap();
+        assert_eq!(ledger.attempts, submitter.ledger.attempts);
+        assert_eq!(ledger.judged(2022, 1, Part::Two).count(), 0);
+        std::fs::remove_file(&ledger.path).unwrap();
+    }
+
+    #[test]
+    fn waits_are_not_judgements() {
+        let mut submitter = submitter(
+            "wait",
+            Fake::new([(200, WAIT), (200, CORRECT), (200, CORRECT)]),
+        );
+        let answer = Answer::from("ABC");
+        let reply = submitter
+            .submit(2022, 10, Part::Two, &answer, at(0))
+            .unwrap();
+        assert_eq!(reply.verdict, Verdict::Wait);
+        assert!(matches!(
+            submitter.submit(2022, 10, Part::Two, &answer, at(60)),
+            Err(SubmitError::Cooldown(_))
+        ));
+        // The wait is for that part only.
+        let reply = submitter
+            .submit(2022, 10, Part::One, &13140.into(), at(60))
+            .unwrap();
+        assert_eq!(reply.verdict, Verdict::Correct);
+        let reply = submitter
+            .submit(2022, 10, Part::Two, &answer, at(90))
+            .unwrap();
+        assert_eq!(reply.verdict, Verdict::Correct);
+        std::fs::remove_file(&submitter.ledger.path).unwrap();
+    }
+
+    #[test]
+    fn unsubmittable_answers() {
+        let mut submitter = submitter("unsubmittable", Fake::new([]));
+        for answer in [
+            Answer::Unsolved,
+            Answer::NoAnswer,
+            Answer::from("#..\n.#.\n"),
+        ] {
+            assert!(matches!(
+                submitter.submit(2022, 10, Part::Two, &answer, at(0)),
+                Err(SubmitError::Unsubmittable(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn against_a_local_server() {
+        let (base_url, server) = serve(vec![(200, TOO_LOW)]);
+        let mut submitter = submitter("server", Fake::new([]));
+        submitter.http = Box::new(Curl);
+        submitter.base_url = base_url;
+        let reply = submitter
+            .submit(2024, 18, Part::Two, &"6,1".into(), at(0))
+            .unwrap();
+        assert_eq!(reply.verdict, Verdict::TooLow);
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/18/answer "));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=6%2C1"));
+        std::fs::remove_file(&submitter.ledger.path).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+//! The subset of TOML read by the toolkit's files: `key = value` lines, `# comments`, and
+//! integer or basic string values.
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value {
+    Integer(i128),
+    String(String),
+}
+
+/// `line` without a trailing `# comment`, leaving `#` inside strings alone.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// A value of the right-hand side of `key = value`.
+pub(crate) fn parse_value(input: &str, value: &str) -> Result<Value, ParseError> {
+    if let Some(inner) = value.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| ParseError::within(input, value, "a closing '\"'"))?;
+        let mut text = String::new();
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            text.push(match c {
+                '\\' => match chars.next() {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    _ => return Err(ParseError::within(input, value, "one of \\n \\t \\\" \\\\")),
+                },
+                c => c,
+            });
+        }
+        return Ok(Value::String(text));
+    }
+    value
+        .replace('_', "")
+        .parse()
+        .map(Value::Integer)
+        .map_err(|_| ParseError::within(input, value, "an integer or a \"string\""))
+}
+
+/// `s` as a basic string, the reverse of [`parse_value`].
+pub(crate) fn quote(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
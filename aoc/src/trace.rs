@@ -0,0 +1,239 @@
+//! Diagnostics that solvers keep in place, printed to stderr only when asked for.
+//!
+//! What is printed is set by a spec, from `AOC_TRACE` or the runner's `--trace`: comma
+//! separated directives, each a level (`debug`) or a target and a level
+//! (`aoc2024::day15=trace`). Targets are module paths; a directive names a module, a prefix
+//! of one (`aoc2024`) or a suffix (`day15`), with `2024::day15` short for `aoc2024::day15`.
+//! The most specific directive that matches wins, and nothing is printed by default.
+//!
+//! ```
+//! aoc::debug!("{} boxes left", 3);
+//! ```
+
+use std::fmt;
+use std::sync::OnceLock;
+
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// `off`, or the most verbose level shown.
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    if s == "off" {
+        return Ok(None);
+    }
+    Level::ALL
+        .into_iter()
+        .find(|l| l.name() == s)
+        .map(Some)
+        .ok_or_else(|| {
+            format!("unknown trace level {s:?} (expected off, error, warn, info, debug or trace)")
+        })
+}
+
+/// Which targets print at which levels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Targets (`None` for the default) and the most verbose level they show.
+    directives: Vec<(Option<String>, Option<Level>)>,
+}
+
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut directives = Vec::new();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            directives.push(match directive.split_once('=') {
+                Some((target, _)) if target.trim().is_empty() => {
+                    return Err(format!("missing target in {directive:?}"))
+                }
+                Some((target, level)) => (Some(expand(target.trim())), parse_level(level.trim())?),
+                None => match parse_level(directive) {
+                    Ok(level) => (None, level),
+                    // A bare target shows everything.
+                    Err(_) if is_target(directive) => (Some(expand(directive)), Some(Level::Trace)),
+                    Err(_) => {
+                        return Err(format!(
+                            "unknown trace level or target {directive:?} (expected off, error, \
+                             warn, info, debug, trace or a target such as 2022::day15)"
+                        ))
+                    }
+                },
+            });
+        }
+        Ok(Filter { directives })
+    }
+
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let mut best: Option<(usize, Option<Level>)> = None;
+        for (directive, max) in &self.directives {
+            let specificity = match directive {
+                None => 0,
+                Some(d) if matches(d, target) => 1 + d.len(),
+                Some(_) => continue,
+            };
+            if best.is_none_or(|(s, _)| specificity >= s) {
+                best = Some((specificity, *max));
+            }
+        }
+        best.and_then(|(_, max)| max)
+            .is_some_and(|max| level <= max)
+    }
+}
+
+/// Whether a bare word names code of the workspace rather than a mistyped level: a path,
+/// a crate, a year or a day.
+fn is_target(word: &str) -> bool {
+    !word.contains(char::is_whitespace)
+        && (word.contains("::")
+            || word.starts_with("aoc")
+            || word.starts_with("day")
+            || word.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// `2024::day15` is short for the crate `aoc2024`.
+fn expand(target: &str) -> String {
+    if target.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("aoc{target}")
+    } else {
+        target.to_string()
+    }
+}
+
+fn matches(directive: &str, target: &str) -> bool {
+    target == directive
+        || target
+            .strip_prefix(directive)
+            .is_some_and(|rest| rest.starts_with("::"))
+        || target
+            .strip_suffix(directive)
+            .is_some_and(|rest| rest.ends_with("::"))
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Sets the filter from `spec`, overriding `AOC_TRACE`. Fails once anything was traced.
+pub fn init(spec: &str) -> Result<(), String> {
+    let filter = Filter::parse(spec)?;
+    FILTER
+        .set(filter)
+        .map_err(|_| "tracing is already configured".to_string())
+}
+
+fn filter() -> &'static Filter {
+    FILTER.get_or_init(|| {
+        let spec = std::env::var(ENV_VAR).unwrap_or_default();
+        Filter::parse(&spec).unwrap_or_else(|e| {
+            eprintln!("{ENV_VAR}: {e}");
+            Filter::default()
+        })
+    })
+}
+
+/// Whether [`log!`](crate::log) at `level` in `target` prints anything.
+pub fn enabled(level: Level, target: &str) -> bool {
+    filter().enabled(level, target)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let text = args.to_string();
+    let mut lines = text.lines();
+    eprintln!("[{level:<5} {target}] {}", lines.next().unwrap_or(""));
+    // Grids and other multi-line output stay aligned.
+    for line in lines {
+        eprintln!("{line}");
+    }
+}
+
+/// Prints to stderr when tracing is enabled for `level` in the calling module. The
+/// arguments are only evaluated then.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($level, module_path!()) {
+            $crate::trace::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// [`log!`] at [`Level::Info`](trace::Level::Info).
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+/// [`log!`] at [`Level::Debug`](trace::Level::Debug).
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+/// [`log!`] at [`Level::Trace`](trace::Level::Trace).
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters() {
+        let off = Filter::parse("").unwrap();
+        assert!(!off.enabled(Level::Error, "aoc2024::day15"));
+
+        let filter = Filter::parse("info, 2024::day15=trace, day17=off, aoc2022").unwrap();
+        assert!(filter.enabled(Level::Info, "aoc2023::day01"));
+        assert!(!filter.enabled(Level::Debug, "aoc2023::day01"));
+        assert!(filter.enabled(Level::Trace, "aoc2024::day15"));
+        assert!(!filter.enabled(Level::Trace, "aoc2024::day15x"));
+        assert!(!filter.enabled(Level::Error, "aoc2024::day17"));
+        assert!(filter.enabled(Level::Trace, "aoc2022::day10"));
+        assert!(Filter::parse("2021")
+            .unwrap()
+            .enabled(Level::Debug, "aoc2021::day15"));
+
+        let e = Filter::parse("loud").unwrap_err();
+        assert!(
+            e.starts_with("unknown trace level or target \"loud\""),
+            "{e}"
+        );
+        assert!(Filter::parse("aoc::grid").is_ok());
+        assert!(Filter::parse("day15=loud").is_err());
+        assert!(Filter::parse("=debug").is_err());
+    }
+}
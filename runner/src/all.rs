@@ -0,0 +1,255 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aoc::answers::Answers;
+use aoc::Parse;
+
+use crate::verify::{self, Check, Status};
+
+/// One day to solve, with everything read up front so workers only compute.
+pub struct Job<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Parse,
+    pub input: Result<String, String>,
+    pub expected: &'a Answers,
+}
+
+fn solve(job: &Job) -> Status {
+    match &job.input {
+        Ok(input) => verify::verify(job.day, job.parse, input, job.expected),
+        Err(e) => Status::MissingInput(e.clone()),
+    }
+}
+
+/// Solves `jobs` on `threads` workers, a panicking day failing alone. Outcomes are in the
+/// order of `jobs`.
+pub fn run_all(jobs: &[Job], threads: usize) -> Vec<Status> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                let _ = sender.send((i, solve(job)));
+            });
+        }
+    });
+    drop(sender);
+    let mut outcomes: Vec<_> = receiver.into_iter().collect();
+    outcomes.sort_by_key(|(i, _)| *i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+/// Counts of days by how they went; only panics, parse failures, mismatches and parts
+/// without an answer fail a run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub ok: usize,
+    pub unchecked: usize,
+    pub failed: usize,
+    pub missing_inputs: usize,
+}
+
+/// One row per day: both answers, the time spent and what went wrong, then the counts.
+pub fn table(jobs: &[Job], outcomes: &[Status], wall: Duration) -> (String, Summary) {
+    let mut summary = Summary::default();
+    let mut total = Duration::ZERO;
+    let mut rows = vec![[
+        "year".to_string(),
+        "day".to_string(),
+        "part 1".to_string(),
+        "part 2".to_string(),
+        "time".to_string(),
+        "status".to_string(),
+    ]];
+    for (job, outcome) in jobs.iter().zip(outcomes) {
+        let mut answers = [String::new(), String::new()];
+        let mut time = String::new();
+        let status = match outcome {
+            Status::Checked { parse, parts } => {
+                let elapsed = *parse + parts.iter().map(|p| p.elapsed).sum::<Duration>();
+                total += elapsed;
+                time = format!("{elapsed:.2?}");
+                let mut failures = Vec::new();
+                let mut unchecked = false;
+                for run in parts {
+                    answers[run.part.number() as usize - 1] = verify::one_line(&run.answer);
+                    match &run.check {
+                        Check::Ok => {}
+                        Check::Unchecked => unchecked = true,
+                        Check::Mismatch { expected } => failures.push(format!(
+                            "MISMATCH: part {} expected {}",
+                            run.part.number(),
+                            verify::one_line(expected)
+                        )),
+                        Check::NoAnswer => {
+                            failures.push(format!("NO ANSWER: part {}", run.part.number()))
+                        }
+                    }
+                }
+                if !failures.is_empty() {
+                    summary.failed += 1;
+                    failures.join(", ")
+                } else if unchecked {
+                    summary.unchecked += 1;
+                    "unchecked".to_string()
+                } else {
+                    summary.ok += 1;
+                    "ok".to_string()
+                }
+            }
+            Status::MissingInput(e) => {
+                summary.missing_inputs += 1;
+                format!("no input: {e}")
+            }
+            Status::ParseFailed(e) => {
+                summary.failed += 1;
+                format!("FAILED: {e}")
+            }
+            Status::Panicked(e) => {
+                summary.failed += 1;
+                format!("PANICKED: {e}")
+            }
+        };
+        let [part1, part2] = answers;
+        rows.push([
+            job.year.to_string(),
+            format!("{:02}", job.day),
+            part1,
+            part2,
+            time,
+            status,
+        ]);
+    }
+
+    let mut widths = [0; 6];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut out = String::new();
+    for row in &rows {
+        let line = format!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:<w3$}  {:>w4$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            row[5],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    let _ = writeln!(
+        out,
+        "{} days: {} ok, {} unchecked, {} failed, {} without input; {:.2?} of solving in {:.2?}",
+        jobs.len(),
+        summary.ok,
+        summary.unchecked,
+        summary.failed,
+        summary.missing_inputs,
+        total,
+        wall
+    );
+    (out, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{Answer, ParseError, Solution};
+
+    struct Double;
+
+    impl Solution for Double {
+        type Input = i64;
+
+        fn parse(input: &str) -> Result<i64, ParseError> {
+            aoc::parse_at(input, input.trim(), "a number")
+        }
+
+        fn part1(n: &i64) -> Answer {
+            assert!(*n >= 0, "negative input");
+            (n * 2).into()
+        }
+
+        fn part2(n: &i64) -> Answer {
+            if *n == 0 {
+                return Answer::NoAnswer;
+            }
+            (n * 4).into()
+        }
+    }
+
+    fn job<'a>(day: u8, input: Result<&str, &str>, expected: &'a Answers) -> Job<'a> {
+        Job {
+            year: 2024,
+            day,
+            parse: aoc::solution::<Double>(),
+            input: input.map(String::from).map_err(String::from),
+            expected,
+        }
+    }
+
+    #[test]
+    fn panics_stay_in_their_day() {
+        verify::install_panic_hook();
+        let expected = Answers::parse("[1]\npart1 = 2\npart2 = 4\n[3]\npart1 = 7\n").unwrap();
+        let jobs: Vec<_> = [
+            Ok("1"),
+            Ok("-1"),
+            Ok("1"),
+            Ok("x"),
+            Err("no session"),
+            Ok("0"),
+        ]
+        .into_iter()
+        .zip(1..)
+        .map(|(input, day)| job(day, input, &expected))
+        .collect();
+        let outcomes = run_all(&jobs, 3);
+        assert_eq!(outcomes.len(), jobs.len());
+        let Status::Panicked(message) = &outcomes[1] else {
+            panic!("{:?}", outcomes[1]);
+        };
+        assert!(
+            message.starts_with("negative input (runner/src/all.rs:"),
+            "{message}"
+        );
+
+        let (text, summary) = table(&jobs, &outcomes, Duration::from_millis(5));
+        assert_eq!(
+            summary,
+            Summary {
+                ok: 1,
+                unchecked: 0,
+                failed: 4,
+                missing_inputs: 1,
+            }
+        );
+        let lines: Vec<_> = text.lines().collect();
+        assert!(lines[0].starts_with("year  day  part 1  part 2"), "{text}");
+        assert!(lines[1].starts_with("2024  01   2       4"), "{text}");
+        assert!(lines[1].ends_with("  ok"), "{text}");
+        assert!(lines[2].contains("PANICKED: negative input"), "{text}");
+        assert!(lines[3].ends_with("MISMATCH: part 1 expected 7"), "{text}");
+        assert!(lines[4].contains("FAILED: parse error at 1:1"), "{text}");
+        assert!(lines[5].ends_with("no input: no session"), "{text}");
+        assert!(lines[6].ends_with("NO ANSWER: part 2"), "{text}");
+        assert!(lines[7].starts_with("6 days: 1 ok, 0 unchecked, 4 failed, 1 without input;"));
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use aoc::{InputKind, Parse, ParseError, Part};
+
+/// Summary of repeated timings of the same step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics on an empty sample.
+    pub fn new(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let n = samples.len();
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: samples[0],
+            median: samples[n / 2],
+            mean,
+            max: samples[n - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings of one day: parse, then each part on the parsed input.
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: [Stats; 2],
+}
+
+impl DayBench {
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.parts[0].median + self.parts[1].median
+    }
+}
+
+/// Times `runs` rounds of parsing `input` and solving both parts; `runs` is at least 1.
+pub fn bench(
+    day: u8,
+    parse: Parse,
+    input: &str,
+    kind: InputKind,
+    runs: usize,
+) -> Result<DayBench, ParseError> {
+    let mut samples = [const { Vec::new() }; 3];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = parse(input, kind)?;
+        samples[0].push(start.elapsed());
+        for part in Part::BOTH {
+            let start = Instant::now();
+            std::hint::black_box(parsed.solve(part));
+            samples[part.number() as usize].push(start.elapsed());
+        }
+    }
+    let [parse, part1, part2] = samples.each_mut().map(|s| Stats::new(s));
+    Ok(DayBench {
+        day,
+        parse,
+        parts: [part1, part2],
+    })
+}
+
+/// Detailed statistics of a single day.
+pub fn report(bench: &DayBench, runs: usize) -> String {
+    let mut out = format!("day {:02}, {runs} runs\n", bench.day);
+    for (step, stats) in [
+        ("parse", &bench.parse),
+        ("part1", &bench.parts[0]),
+        ("part2", &bench.parts[1]),
+    ] {
+        let _ = writeln!(
+            out,
+            "{step}: median {:.2?}, mean {:.2?} ± {:.2?}, min {:.2?}, max {:.2?}",
+            stats.median, stats.mean, stats.stddev, stats.min, stats.max
+        );
+    }
+    out
+}
+
+/// One row per day with median timings, and their sum.
+pub fn table(year: u16, benches: &[DayBench]) -> String {
+    let mut out = format!(
+        "{year} | {:>10} | {:>10} | {:>10} | {:>10}\n",
+        "parse", "part1", "part2", "total"
+    );
+    out.push_str(&format!(
+        "-----|{:-<12}|{:-<12}|{:-<12}|{:-<12}\n",
+        "", "", "", ""
+    ));
+    for bench in benches {
+        let _ = writeln!(
+            out,
+            "  {:02} | {:>10} | {:>10} | {:>10} | {:>10}",
+            bench.day,
+            format!("{:.2?}", bench.parse.median),
+            format!("{:.2?}", bench.parts[0].median),
+            format!("{:.2?}", bench.parts[1].median),
+            format!("{:.2?}", bench.total()),
+        );
+    }
+    let total = benches.iter().map(DayBench::total).sum::<Duration>();
+    let _ = writeln!(
+        out,
+        "{:>4} | {:>10} | {:>10} | {:>10} | {:>10}",
+        "all",
+        "",
+        "",
+        "",
+        format!("{total:.2?}")
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let mut samples = [3, 1, 2, 6].map(Duration::from_millis);
+        let stats = Stats::new(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(6));
+        assert_eq!(stats.stddev.as_micros(), 1870);
+    }
+}
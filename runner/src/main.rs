@@ -0,0 +1,574 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+mod all;
+mod bench;
+mod output;
+mod scaffold;
+mod verify;
+mod watch;
+
+use aoc::config::Config;
+use output::{Format, Record};
+
+const USAGE: &str =
+    "usage: aoc run --year YEAR --day DAY [--part 1|2] [--time] [--format human|json|tsv] [--example | INPUT]
+       aoc bench --year YEAR [--day DAY] [--runs N] [--example | INPUT]
+       aoc submit --year YEAR --day DAY --part 1|2 [INPUT]
+       aoc verify --year YEAR [--day DAY] [--lock] [INPUT]
+       aoc all [--year YEAR] [--jobs N]
+       aoc watch --year YEAR --day DAY [--example | INPUT]
+       aoc new --year YEAR --day DAY
+every command also takes --trace SPEC, e.g. --trace 2024::day15=debug (see AOC_TRACE)
+--animate SPEC, e.g. --animate 2024::day15,delay=50 (see AOC_ANIMATE)
+and --record SPEC, e.g. --record 2024::day14,format=png,dir=frames (see AOC_RECORD)
+settings come from aoc.toml at the workspace root (or AOC_CONFIG), overridden by AOC_* variables";
+
+const YEARS: &[&aoc::Year] = &[
+    &aoc2020::YEAR,
+    &aoc2021::YEAR,
+    &aoc2022::YEAR,
+    &aoc2023::YEAR,
+    &aoc2024::YEAR,
+];
+
+const DEFAULT_RUNS: usize = 10;
+
+/// The workspace `new` scaffolds days into.
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Where downloaded inputs are kept unless the configuration says otherwise.
+const DEFAULT_CACHE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../input");
+
+/// The configuration file read unless `AOC_CONFIG` names another.
+const DEFAULT_CONFIG: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The settings of `aoc.toml` and the environment, loaded by `main`.
+fn config() -> &'static Config {
+    CONFIG.get().expect("configuration loaded")
+}
+
+/// Reads the configuration; a session file is only read by the commands that need it.
+fn load_config() -> Result<Config, String> {
+    let path = match var("AOC_CONFIG") {
+        Some(path) if !std::path::Path::new(&path).exists() => {
+            return Err(format!("AOC_CONFIG: no configuration at {path}"))
+        }
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(DEFAULT_CONFIG),
+    };
+    Config::load(&path)
+}
+
+#[derive(Debug, Default)]
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<aoc::Part>,
+    runs: Option<usize>,
+    jobs: Option<usize>,
+    time: bool,
+    lock: bool,
+    example: bool,
+    format: Format,
+    input: Option<String>,
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{flag} expects a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: {value:?}"))
+}
+
+/// Parses the arguments of a subcommand, which accepts only the `allowed` flags (and
+/// `--trace`, `--animate` and `--record`, taken by every command) and needs the `required`
+/// ones.
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+    allowed: &[&str],
+    required: &[&str],
+) -> Result<Args, String> {
+    let config = config();
+    let mut parsed = Args::default();
+    if let Some(format) = &config.format {
+        parsed.format = format.parse()?;
+    }
+    while let Some(arg) = args.next() {
+        let global = ["--trace", "--animate", "--record"].contains(&arg.as_str());
+        if arg.starts_with("--") && !global && !allowed.contains(&arg.as_str()) {
+            return Err(format!("unknown flag {arg}"));
+        }
+        match arg.as_str() {
+            "--year" => parsed.year = Some(parse_number(&arg, args.next())?),
+            "--day" => parsed.day = Some(parse_number(&arg, args.next())?),
+            "--part" => {
+                parsed.part = match parse_number(&arg, args.next())? {
+                    1 => Some(aoc::Part::One),
+                    2 => Some(aoc::Part::Two),
+                    n => return Err(format!("invalid value for --part: {n} (expected 1 or 2)")),
+                }
+            }
+            "--runs" => {
+                parsed.runs = match parse_number(&arg, args.next())? {
+                    0 => {
+                        return Err("invalid value for --runs: 0 (expected at least 1)".to_string())
+                    }
+                    n => Some(n),
+                }
+            }
+            "--jobs" => parsed.jobs = Some(parse_number(&arg, args.next())?),
+            "--time" => parsed.time = true,
+            "--lock" => parsed.lock = true,
+            "--example" => parsed.example = true,
+            // Global, so set before any day runs; a bad spec is a usage error.
+            "--trace" => aoc::trace::init(&args.next().ok_or("--trace expects a spec")?)?,
+            "--animate" => {
+                aoc::render::init_animation(&args.next().ok_or("--animate expects a spec")?)?
+            }
+            "--record" => {
+                aoc::render::init_recording(&args.next().ok_or("--record expects a spec")?)?
+            }
+            "--format" => parsed.format = args.next().ok_or("--format expects a value")?.parse()?,
+            _ if parsed.input.is_none() => parsed.input = Some(arg),
+            _ => return Err(format!("unexpected argument {arg:?}")),
+        }
+    }
+    if required.contains(&"--year") {
+        parsed.year = parsed.year.or(config.year);
+    }
+    if allowed.contains(&"--jobs") {
+        parsed.jobs = parsed.jobs.or(config.jobs);
+    }
+    for (flag, given) in [
+        ("--year", parsed.year.is_some()),
+        ("--day", parsed.day.is_some()),
+    ] {
+        if required.contains(&flag) && !given {
+            return Err(format!("missing {flag}"));
+        }
+    }
+    if parsed.example && parsed.input.is_some() {
+        return Err("--example and an INPUT cannot be combined".to_string());
+    }
+    Ok(parsed)
+}
+
+fn find_year(year: u16) -> Result<&'static aoc::Year, String> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .copied()
+        .ok_or_else(|| format!("no solutions registered for {year}"))
+}
+
+fn var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+fn cache_dir() -> PathBuf {
+    config()
+        .cache_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE))
+}
+
+/// The inputs, as configured.
+fn inputs() -> aoc::input::Inputs {
+    let config = config();
+    let mut inputs = aoc::input::Inputs::new(cache_dir(), config.session());
+    inputs.dir = config.input_dir.clone();
+    if let Some(base_url) = &config.base_url {
+        inputs.base_url = base_url.clone();
+    }
+    inputs
+}
+
+/// Examples are parsed with the example values of the day's parameters.
+fn kind(args: &Args) -> aoc::InputKind {
+    if args.example {
+        aoc::InputKind::Example
+    } else {
+        aoc::InputKind::Real
+    }
+}
+
+/// The input the command line asks for: INPUT (`-` for stdin), the example with `--example`,
+/// the real input otherwise.
+fn read_input(year: &aoc::Year, day: u8, args: &Args) -> Result<String, String> {
+    let source = aoc::input::Source::from_arg(args.input.as_deref(), args.example);
+    inputs()
+        .resolve(year, day, &source)
+        .map_err(|e| e.to_string())
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let registered = find_year(year)?;
+    let parse = registered
+        .day(day)
+        .ok_or_else(|| format!("no solution registered for {year} day {day}"))?;
+    let input_raw = read_input(registered, day, &args)?;
+    let start = Instant::now();
+    let parsed = parse(&input_raw, kind(&args)).map_err(|e| format!("{year} day {day}: {e}"))?;
+    if args.time && args.format == Format::Human {
+        println!("Parse: {:.2?}", start.elapsed());
+    }
+    if let Some(header) = args.format.header() {
+        println!("{header}");
+    }
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => aoc::Part::BOTH.to_vec(),
+    };
+    for part in parts {
+        let start = Instant::now();
+        let answer = parsed.solve(part);
+        let record = Record {
+            year,
+            day,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        };
+        println!("{}", args.format.record(&record, args.time));
+    }
+    Ok(())
+}
+
+fn bench(args: Args) -> Result<(), String> {
+    let year = args.year.unwrap();
+    let registered = find_year(year)?;
+    let runs = args.runs.unwrap_or(DEFAULT_RUNS);
+    if let Some(day) = args.day {
+        let Some(parse) = registered.day(day) else {
+            return Err(format!("no solution registered for {year} day {day}"));
+        };
+        let input_raw = read_input(registered, day, &args)?;
+        let result = bench::bench(day, parse, &input_raw, kind(&args), runs)
+            .map_err(|e| format!("{year} day {day}: {e}"))?;
+        print!("{}", bench::report(&result, runs));
+        return Ok(());
+    }
+    if args.input.is_some() {
+        return Err("an INPUT can only be given together with --day".to_string());
+    }
+    let mut results = Vec::new();
+    for (day, parse) in registered.days {
+        match read_input(registered, *day, &args).and_then(|input| {
+            bench::bench(*day, *parse, &input, kind(&args), runs).map_err(|e| e.to_string())
+        }) {
+            Ok(result) => results.push(result),
+            Err(e) => eprintln!("{year} day {day}: skipped, {e}"),
+        }
+    }
+    print!("{}", bench::table(year, &results));
+    Ok(())
+}
+
+fn submit(args: Args) -> Result<(), String> {
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let part = args.part.ok_or("missing --part")?;
+    let registered = find_year(year)?;
+    let Some(parse) = registered.day(day) else {
+        return Err(format!("no solution registered for {year} day {day}"));
+    };
+    let input_raw = read_input(registered, day, &args)?;
+    let answer = parse(&input_raw, aoc::InputKind::Real)
+        .map_err(|e| format!("{year} day {day}: {e}"))?
+        .solve(part);
+    println!("Star{}: {answer}", part.number());
+    let ledger = aoc::submit::ledger_path(&cache_dir());
+    let session = config().session().map(|s| s.cookie()).transpose()?;
+    let mut submitter = aoc::submit::Submitter::new(ledger, session).map_err(|e| e.to_string())?;
+    if let Some(base_url) = &config().base_url {
+        submitter.base_url = base_url.clone();
+    }
+    let reply = submitter
+        .submit(year, day, part, &answer, std::time::SystemTime::now())
+        .map_err(|e| e.to_string())?;
+    println!("{}", reply.verdict);
+    if let Some(cooldown) = reply.cooldown {
+        println!("next submission in {}s", cooldown.as_secs());
+    }
+    if reply.verdict == aoc::submit::Verdict::Correct {
+        Ok(())
+    } else {
+        Err(reply.message)
+    }
+}
+
+/// Checks answers against the year's `answers.toml`; `--lock` records the unchecked ones.
+fn verify(args: Args) -> Result<(), String> {
+    let year = args.year.unwrap();
+    let registered = find_year(year)?;
+    if args.input.is_some() && args.day.is_none() {
+        return Err("an INPUT can only be given together with --day".to_string());
+    }
+    let path = registered.answers_path();
+    let mut answers = aoc::answers::Answers::load(&path)?;
+    let mut days = Vec::new();
+    for (day, parse) in registered.days {
+        if args.day.is_some_and(|d| d != *day) {
+            continue;
+        }
+        let status = match read_input(registered, *day, &args) {
+            Err(e) => verify::Status::MissingInput(e),
+            Ok(input) => verify::verify(*day, *parse, &input, &answers),
+        };
+        days.push((*day, status));
+    }
+    if let (Some(day), true) = (args.day, days.is_empty()) {
+        return Err(format!("no solution registered for {year} day {day}"));
+    }
+    let (report, summary) = verify::report(year, &days);
+    print!("{report}");
+    if args.lock {
+        let mut locked = 0;
+        for (day, status) in &days {
+            let verify::Status::Checked { parts, .. } = status else {
+                continue;
+            };
+            for run in parts {
+                if run.check == verify::Check::Unchecked && run.answer != aoc::Answer::Unsolved {
+                    answers.set(*day, run.part, run.answer.clone());
+                    locked += 1;
+                }
+            }
+        }
+        if locked > 0 {
+            answers.save(&path)?;
+        }
+        println!("locked {locked} answers in {}", path.display());
+    }
+    if summary.failed() {
+        return Err(format!(
+            "{year}: {} mismatched answers, {} parts without an answer, {} inputs failed to parse, {} days panicked",
+            summary.mismatches, summary.no_answers, summary.parse_failures, summary.panics
+        ));
+    }
+    Ok(())
+}
+
+fn all(args: Args) -> Result<(), String> {
+    let years = match args.year {
+        Some(year) => vec![find_year(year)?],
+        None => YEARS.to_vec(),
+    };
+    if let Some(input) = args.input {
+        return Err(format!("all reads the real inputs, got {input:?}"));
+    }
+    let mut expected = Vec::new();
+    for registered in &years {
+        expected.push(aoc::answers::Answers::load(&registered.answers_path())?);
+    }
+    let inputs = inputs();
+    let mut jobs = Vec::new();
+    for (registered, expected) in years.iter().zip(&expected) {
+        for (day, parse) in registered.days {
+            let input = inputs
+                .resolve(registered, *day, &aoc::input::Source::Real)
+                .map_err(|e| e.to_string());
+            jobs.push(all::Job {
+                year: registered.year,
+                day: *day,
+                parse: *parse,
+                input,
+                expected,
+            });
+        }
+    }
+    let threads = match args.jobs {
+        Some(0) => return Err("--jobs must be at least 1".to_string()),
+        Some(jobs) => jobs,
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let start = Instant::now();
+    let outcomes = all::run_all(&jobs, threads);
+    let (table, summary) = all::table(&jobs, &outcomes, start.elapsed());
+    print!("{table}");
+    if summary.failed > 0 {
+        return Err(format!("{} days failed", summary.failed));
+    }
+    Ok(())
+}
+
+/// How often `watch` looks for changes.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Runs `cargo` in the workspace, which rebuilds what changed first.
+fn cargo(args: &[String]) -> Result<std::process::Output, String> {
+    Command::new(var("CARGO").unwrap_or_else(|| "cargo".to_string()))
+        .args(args)
+        .current_dir(WORKSPACE)
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("cannot run cargo: {e}"))
+}
+
+/// Rebuilds and runs the day, then its example tests, comparing the answers to the
+/// `previous` ones.
+fn watch_once(
+    year: u16,
+    day: u8,
+    run_args: &[String],
+    previous: &mut Option<BTreeMap<u8, String>>,
+) -> Result<(), String> {
+    let run = cargo(run_args)?;
+    if run.status.success() {
+        let answers = watch::parse_answers(&String::from_utf8_lossy(&run.stdout));
+        print!("{}", watch::diff(previous.as_ref(), &answers));
+        *previous = Some(answers);
+    } else {
+        eprint!("{}", String::from_utf8_lossy(&run.stderr));
+    }
+    let test = cargo(&[
+        "test".to_string(),
+        format!("--package=aoc{year}"),
+        "--test=examples".to_string(),
+        "--".to_string(),
+        format!("day{day:02}"),
+    ])?;
+    let results = watch::parse_examples(&String::from_utf8_lossy(&test.stdout));
+    if results.is_empty() && !test.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&test.stderr));
+    } else {
+        print!("{}", watch::report_examples(&results));
+    }
+    Ok(())
+}
+
+fn watch(args: Args) -> Result<(), String> {
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let mut run_args: Vec<String> = [
+        "run",
+        "--quiet",
+        "--package=aoc-runner",
+        "--",
+        "run",
+        "--year",
+    ]
+    .map(String::from)
+    .into();
+    run_args.extend([
+        year.to_string(),
+        "--day".to_string(),
+        day.to_string(),
+        "--format".to_string(),
+        "tsv".to_string(),
+    ]);
+    let input = match aoc::input::Source::from_arg(args.input.as_deref(), args.example) {
+        aoc::input::Source::Stdin => return Err("watch cannot read standard input".to_string()),
+        aoc::input::Source::Path(path) => {
+            run_args.push(path.display().to_string());
+            Some(path)
+        }
+        aoc::input::Source::Example => {
+            run_args.push("--example".to_string());
+            None
+        }
+        aoc::input::Source::Real => Some(inputs().real_path(year, day)),
+    };
+    let paths = watch::paths(std::path::Path::new(WORKSPACE), year, day, input);
+    println!("watching {} files, Ctrl-C to stop", paths.len());
+    let mut snapshot = watch::Snapshot::take(&paths);
+    let mut previous = None;
+    watch_once(year, day, &run_args, &mut previous)?;
+    loop {
+        std::thread::sleep(WATCH_INTERVAL);
+        let now = watch::Snapshot::take(&paths);
+        let changed: Vec<_> = now
+            .changed(&snapshot)
+            .iter()
+            .map(|p| p.strip_prefix(WORKSPACE).unwrap_or(p).display().to_string())
+            .collect();
+        if changed.is_empty() {
+            continue;
+        }
+        // Let editors finish writing before building.
+        std::thread::sleep(WATCH_INTERVAL / 5);
+        snapshot = watch::Snapshot::take(&paths);
+        println!("\n== {year} day {day}: {} changed", changed.join(", "));
+        watch_once(year, day, &run_args, &mut previous)?;
+    }
+}
+
+fn new(args: Args) -> Result<(), String> {
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    if let Some(input) = args.input {
+        return Err(format!("new takes no input, got {input:?}"));
+    }
+    let root = std::path::Path::new(WORKSPACE);
+    for path in scaffold::new_day(root, year, day)? {
+        let path = path.strip_prefix(root).unwrap_or(&path);
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    verify::install_panic_hook();
+    match load_config() {
+        Ok(config) => CONFIG.set(config).expect("configuration loaded once"),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("run") => parse_args(
+            args,
+            &[
+                "--year",
+                "--day",
+                "--part",
+                "--time",
+                "--format",
+                "--example",
+            ],
+            &["--year", "--day"],
+        )
+        .map(run),
+        Some("bench") => parse_args(
+            args,
+            &["--year", "--day", "--runs", "--example"],
+            &["--year"],
+        )
+        .map(bench),
+        Some("submit") => {
+            parse_args(args, &["--year", "--day", "--part"], &["--year", "--day"]).map(submit)
+        }
+        Some("verify") => parse_args(args, &["--year", "--day", "--lock"], &["--year"]).map(verify),
+        Some("all") => parse_args(args, &["--year", "--jobs"], &[]).map(all),
+        Some("watch") => parse_args(
+            args,
+            &["--year", "--day", "--example"],
+            &["--year", "--day"],
+        )
+        .map(watch),
+        Some("new") => parse_args(args, &["--year", "--day"], &["--year", "--day"]).map(new),
+        _ => Err("missing command".to_string()),
+    };
+    if let Err(e) = aoc::render::finish() {
+        eprintln!("{}: {e}", aoc::render::RECORD_VAR);
+    }
+    match result {
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Ok(Err(e)) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+        Ok(Ok(())) => ExitCode::SUCCESS,
+    }
+}
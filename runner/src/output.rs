@@ -0,0 +1,166 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc::{Answer, Part};
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Star1: answer`, for people.
+    #[default]
+    Human,
+    /// One JSON object per line.
+    Json,
+    /// Tab-separated values, after a header line.
+    Tsv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!(
+                "unknown format {s:?} (expected json, tsv or human)"
+            )),
+        }
+    }
+}
+
+/// One answered part.
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl Format {
+    /// What goes before the first record.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some("year\tday\tpart\tanswer\telapsed"),
+            Format::Human | Format::Json => None,
+        }
+    }
+
+    /// `record` on one line, except for multi-line answers in human output. Elapsed
+    /// times are in seconds; humans only get them when `time` is set.
+    pub fn record(self, record: &Record, time: bool) -> String {
+        let Record {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+        } = record;
+        let part = part.number();
+        let seconds = elapsed.as_secs_f64();
+        match self {
+            Format::Human if time => format!("Star{part}: {answer} ({elapsed:.2?})"),
+            Format::Human => format!("Star{part}: {answer}"),
+            Format::Json => {
+                let answer = match answer {
+                    Answer::Number(n) => n.to_string(),
+                    Answer::Text(s) => json_string(s),
+                    Answer::Unsolved | Answer::NoAnswer => "null".to_string(),
+                };
+                format!(
+                    "{{\"year\":{year},\"day\":{day},\"part\":{part},\"answer\":{answer},\"elapsed\":{seconds:.9}}}"
+                )
+            }
+            Format::Tsv => {
+                let answer = match answer {
+                    Answer::Unsolved | Answer::NoAnswer => String::new(),
+                    answer => tsv_field(&answer.to_string()),
+                };
+                format!("{year}\t{day}\t{part}\t{answer}\t{seconds:.9}")
+            }
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escapes the tabs and newlines that would break a row, as `\t` and `\n`.
+fn tsv_field(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer) -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            part: Part::Two,
+            answer,
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn records() {
+        let number = record(Answer::Number(-42));
+        let text = record(Answer::from("#.\n.#\n"));
+        let unsolved = record(Answer::Unsolved);
+        assert_eq!(Format::Human.record(&number, false), "Star2: -42");
+        assert_eq!(Format::Human.record(&number, true), "Star2: -42 (1.50ms)");
+        assert_eq!(
+            Format::Json.record(&number, false),
+            r#"{"year":2022,"day":10,"part":2,"answer":-42,"elapsed":0.001500000}"#
+        );
+        assert_eq!(
+            Format::Json.record(&text, false),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n.#\n","elapsed":0.001500000}"##
+        );
+        assert!(Format::Json
+            .record(&unsolved, false)
+            .contains(r#""answer":null,"#));
+        let no_answer = record(Answer::NoAnswer);
+        assert_eq!(Format::Human.record(&no_answer, false), "Star2: no answer");
+        assert!(Format::Json
+            .record(&no_answer, false)
+            .contains(r#""answer":null,"#));
+        assert_eq!(
+            Format::Tsv.record(&text, false),
+            "2022\t10\t2\t#.\\n.#\\n\t0.001500000"
+        );
+        assert_eq!(
+            Format::Tsv.record(&unsolved, true),
+            "2022\t10\t2\t\t0.001500000"
+        );
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("tsv".parse(), Ok(Format::Tsv));
+        assert_eq!("human".parse(), Ok(Format::Human));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use std::path::{Path, PathBuf};
+
+/// The source of a new day, in the layout of the 2024 days.
+fn day_template(day: u8) -> String {
+    format!(
+        r#"use aoc::{{Answer, ParseError, Solution}};
+
+#[derive(Debug)]
+pub struct Data {{
+    pub lines: Vec<String>,
+}}
+
+impl std::str::FromStr for Data {{
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {{
+        Ok(Data {{
+            lines: input.lines().map(String::from).collect(),
+        }})
+    }}
+}}
+
+fn star1(_data: &Data) -> Answer {{
+    Answer::Unsolved
+}}
+
+fn star2(_data: &Data) -> Answer {{
+    Answer::Unsolved
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Data;
+
+    fn parse(input_raw: &str) -> Result<Data, ParseError> {{
+        input_raw.parse()
+    }}
+
+    fn part1(l: &Data) -> Answer {{
+        star1(l)
+    }}
+
+    fn part2(l: &Data) -> Answer {{
+        star2(l)
+    }}
+}}
+"#
+    )
+}
+
+/// The `lib.rs` of a new year. Like every crate of the workspace it overrides no lints, so
+/// new days get the default clippy checks.
+fn lib_template(year: u16) -> String {
+    format!(
+        "pub const YEAR: aoc::Year = aoc::Year {{
+    year: {year},
+    dir: env!(\"CARGO_MANIFEST_DIR\"),
+    days: &[
+    ],
+}};
+"
+    )
+}
+
+fn manifest_template(year: u16) -> String {
+    format!(
+        "[package]
+name = \"aoc{year}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+aoc = {{ path = \"../aoc\" }}
+"
+    )
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// Inserts `new` among the lines for which `key` is some, keeping them sorted by it,
+/// or after the line `anchor` matches when there are none yet.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    new: &str,
+    key: impl Fn(&str) -> Option<K>,
+    anchor: impl Fn(&str) -> bool,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let new_key = key(new).expect("the inserted line has a key");
+    let peers: Vec<usize> = (0..lines.len())
+        .filter(|&i| key(lines[i]).is_some())
+        .collect();
+    let at = match peers.iter().find(|&&i| key(lines[i]).unwrap() > new_key) {
+        Some(&i) => i,
+        None => match peers.last() {
+            Some(&i) => i + 1,
+            None => {
+                lines
+                    .iter()
+                    .position(|l| anchor(l))
+                    .ok_or_else(|| format!("nowhere to insert {:?}", new.trim()))?
+                    + 1
+            }
+        },
+    };
+    lines.insert(at, new);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn day_module(line: &str) -> Option<String> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')
+        .map(String::from)
+}
+
+fn day_entry(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix('(')?
+        .split_once(',')?
+        .0
+        .parse()
+        .ok()
+}
+
+fn year_member(line: &str) -> Option<u16> {
+    line.trim()
+        .strip_prefix("&aoc")?
+        .strip_suffix("::YEAR,")?
+        .parse()
+        .ok()
+}
+
+fn year_dependency(line: &str) -> Option<u16> {
+    line.strip_prefix("aoc")?.split_once(' ')?.0.parse().ok()
+}
+
+/// Adds `"YEAR"` to the `members` of the workspace manifest.
+fn add_member(manifest: &str, year: u16) -> Result<String, String> {
+    let (before, rest) = manifest
+        .split_once("members = [")
+        .ok_or("no members list in the workspace manifest")?;
+    let (list, after) = rest
+        .split_once(']')
+        .ok_or("unterminated members list in the workspace manifest")?;
+    let mut members: Vec<String> = list
+        .split(',')
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+        .collect();
+    let new = format!("\"{year}\"");
+    let at = members
+        .iter()
+        .position(|m| m.trim_matches('"').parse::<u16>().is_ok_and(|y| y > year))
+        .unwrap_or(members.len());
+    members.insert(at, new);
+    Ok(format!("{before}members = [{}]{after}", members.join(", ")))
+}
+
+/// Creates the crate of `year` and registers it with the workspace and the runner.
+fn new_year(root: &Path, year: u16) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(year.to_string());
+    let workspace = root.join("Cargo.toml");
+    let runner_manifest = root.join("runner/Cargo.toml");
+    let runner_main = root.join("runner/src/main.rs");
+
+    let workspace_text = add_member(&read(&workspace)?, year)?;
+    let runner_manifest_text = insert_sorted(
+        &read(&runner_manifest)?,
+        &format!("aoc{year} = {{ path = \"../{year}\" }}"),
+        year_dependency,
+        |l| l.starts_with("aoc = "),
+    )?;
+    let runner_main_text = insert_sorted(
+        &read(&runner_main)?,
+        &format!("    &aoc{year}::YEAR,"),
+        year_member,
+        |l| l.starts_with("const YEARS"),
+    )?;
+
+    let manifest = dir.join("Cargo.toml");
+    write(&manifest, &manifest_template(year))?;
+    let mut created = vec![manifest];
+    // Sources already there, say from before the crate was added, are kept
+    for (path, contents) in [
+        (dir.join("src/lib.rs"), lib_template(year)),
+        (
+            dir.join("tests/examples.rs"),
+            format!("use aoc::example;\nuse aoc{year}::*;\n"),
+        ),
+    ] {
+        if !path.exists() {
+            write(&path, &contents)?;
+            created.push(path);
+        }
+    }
+    write(&workspace, &workspace_text)?;
+    write(&runner_manifest, &runner_manifest_text)?;
+    write(&runner_main, &runner_main_text)?;
+    Ok([created, vec![workspace, runner_manifest, runner_main]].concat())
+}
+
+/// Scaffolds `day` of `year` in the workspace at `root`: the day module, its registration,
+/// an empty example and a test that fails until the example answers are filled in.
+/// Returns the files created or modified; nothing is written if the day already exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not between 1 and 25"));
+    }
+    let dir = root.join(year.to_string());
+    let source = dir.join(format!("src/day{day:02}.rs"));
+    let lib = dir.join("src/lib.rs");
+    let example = dir.join(format!("tests/examples/day{day:02}.txt"));
+    let tests = dir.join("tests/examples.rs");
+    for path in [&source, &example] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    if lib.exists() && read(&lib)?.contains(&format!("pub mod day{day:02};")) {
+        return Err(format!(
+            "day{day:02} is already declared in {}",
+            lib.display()
+        ));
+    }
+
+    let mut touched = if dir.join("Cargo.toml").exists() {
+        Vec::new()
+    } else {
+        new_year(root, year)?
+    };
+    let lib_text = read(&lib)?;
+    let module = format!("pub mod day{day:02};");
+    // A new crate has no day modules yet, nor a header to put them after
+    let lib_text = if lib_text.lines().any(|l| day_module(l).is_some()) {
+        insert_sorted(&lib_text, &module, day_module, |_| false)?
+    } else {
+        format!("{module}\n\n{lib_text}")
+    };
+    let lib_text = insert_sorted(
+        &lib_text,
+        &format!("        ({day}, aoc::solution::<day{day:02}::Day{day:02}>()),"),
+        day_entry,
+        |l| l.trim() == "days: &[",
+    )?;
+    let mut tests_text = if tests.exists() {
+        read(&tests)?
+    } else {
+        format!("use aoc::example;\nuse aoc{year}::*;\n")
+    };
+    tests_text.push_str(&format!(
+        "example!(day{day:02}: day{day:02}::Day{day:02}, \"day{day:02}.txt\", part1 = 0);\n"
+    ));
+
+    write(&source, &day_template(day))?;
+    write(&example, "")?;
+    write(&lib, &lib_text)?;
+    write(&tests, &tests_text)?;
+    for path in [source, example, lib, tests] {
+        if !touched.contains(&path) {
+            touched.push(path);
+        }
+    }
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\nresolver = \"2\"\nmembers = [\"aoc\", \"runner\", \"2022\"]\n",
+        )
+        .unwrap();
+        write(
+            &root.join("runner/Cargo.toml"),
+            "[dependencies]\naoc = { path = \"../aoc\" }\naoc2022 = { path = \"../2022\" }\n",
+        )
+        .unwrap();
+        write(
+            &root.join("runner/src/main.rs"),
+            "const YEARS: &[&aoc::Year] = &[\n    &aoc2022::YEAR,\n];\n",
+        )
+        .unwrap();
+        write(&root.join("2022/Cargo.toml"), &manifest_template(2022)).unwrap();
+        write(
+            &root.join("2022/src/lib.rs"),
+            &lib_template(2022)
+                .replace("pub const", "pub mod day01;\npub mod day03;\n\npub const")
+                .replace(
+                    "days: &[\n",
+                    "days: &[\n        (1, aoc::solution::<day01::Day01>()),\n        (3, aoc::solution::<day03::Day03>()),\n",
+                ),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn day_in_an_existing_year() {
+        let root = workspace("day");
+        new_day(&root, 2022, 2).unwrap();
+        let lib = read(&root.join("2022/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(lib.contains(
+            "(1, aoc::solution::<day01::Day01>()),\n        (2, aoc::solution::<day02::Day02>()),\n        (3,"
+        ));
+        assert!(read(&root.join("2022/src/day02.rs"))
+            .unwrap()
+            .contains("impl Solution for Day02 {"));
+        assert_eq!(
+            read(&root.join("2022/tests/examples/day02.txt")).unwrap(),
+            ""
+        );
+        assert!(read(&root.join("2022/tests/examples.rs"))
+            .unwrap()
+            .ends_with("example!(day02: day02::Day02, \"day02.txt\", part1 = 0);\n"));
+
+        let err = new_day(&root, 2022, 2).unwrap_err();
+        assert!(err.ends_with("day02.rs already exists"), "{err}");
+        assert!(new_day(&root, 2022, 26).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn day_in_a_new_year() {
+        let root = workspace("year");
+        new_day(&root, 2025, 1).unwrap();
+        assert!(read(&root.join("Cargo.toml"))
+            .unwrap()
+            .contains("members = [\"aoc\", \"runner\", \"2022\", \"2025\"]\n"));
+        assert!(read(&root.join("runner/Cargo.toml"))
+            .unwrap()
+            .ends_with("aoc2022 = { path = \"../2022\" }\naoc2025 = { path = \"../2025\" }\n"));
+        assert!(read(&root.join("runner/src/main.rs"))
+            .unwrap()
+            .contains("    &aoc2022::YEAR,\n    &aoc2025::YEAR,\n];"));
+        let lib = read(&root.join("2025/src/lib.rs")).unwrap();
+        assert!(lib.starts_with("pub mod day01;\n\npub const YEAR"));
+        assert!(!lib.contains("#!["));
+        assert!(lib.contains("days: &[\n        (1, aoc::solution::<day01::Day01>()),\n    ],"));
+        assert_eq!(
+            read(&root.join("2025/tests/examples.rs")).unwrap(),
+            "use aoc::example;\nuse aoc2025::*;\nexample!(day01: day01::Day01, \"day01.txt\", part1 = 0);\n"
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn year_without_a_manifest_keeps_its_sources() {
+        let root = workspace("sources");
+        let lib = "pub mod day01;\n\npub const YEAR: aoc::Year = aoc::Year {\n    year: 2025,\n    dir: env!(\"CARGO_MANIFEST_DIR\"),\n    days: &[\n        (1, aoc::solution::<day01::Day01>()),\n    ],\n};\n";
+        let tests = "use aoc::example;\nuse aoc2025::*;\nexample!(day01: day01::Day01, \"day01.txt\", part1 = 3);\n";
+        write(&root.join("2025/src/lib.rs"), lib).unwrap();
+        write(&root.join("2025/tests/examples.rs"), tests).unwrap();
+        let touched = new_day(&root, 2025, 2).unwrap();
+        assert!(touched.contains(&root.join("2025/Cargo.toml")));
+        let lib = read(&root.join("2025/src/lib.rs")).unwrap();
+        assert!(lib.starts_with("pub mod day01;\npub mod day02;\n"));
+        assert!(lib.contains("(1, aoc::solution::<day01::Day01>()),\n        (2,"));
+        assert!(read(&root.join("2025/tests/examples.rs"))
+            .unwrap()
+            .starts_with(tests));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,312 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::Write;
+use std::panic::AssertUnwindSafe;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use aoc::answers::Answers;
+use aoc::{Answer, Parse, Part};
+
+/// How an answer compares to the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Ok,
+    Mismatch {
+        expected: Answer,
+    },
+    /// Nothing expected yet.
+    Unchecked,
+    /// The solution found no answer in the input, whatever was expected.
+    NoAnswer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub check: Check,
+}
+
+/// What became of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Checked {
+        parse: Duration,
+        parts: Vec<PartRun>,
+    },
+    MissingInput(String),
+    ParseFailed(String),
+    /// The message and location of the panic, once [`install_panic_hook`] has run.
+    Panicked(String),
+}
+
+thread_local! {
+    /// Whether [`verify`] is solving on this thread.
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+    /// The last panic of the thread while solving, kept by the hook.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs the panic hook recording the panics of [`verify`] instead of printing them; any
+/// other panic still goes to the previous hook. Only the first call does anything.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !SOLVING.get() {
+                return previous(info);
+            }
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("non-string payload");
+            let message = match info.location() {
+                Some(at) => format!("{message} ({}:{})", at.file(), at.line()),
+                None => message.to_string(),
+            };
+            PANIC.with(|p| *p.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Solves both parts of `day` and compares them to `expected`, a panicking day failing alone.
+pub fn verify(day: u8, parse: Parse, input: &str, expected: &Answers) -> Status {
+    SOLVING.set(true);
+    let solved = std::panic::catch_unwind(AssertUnwindSafe(|| solve(day, parse, input, expected)));
+    SOLVING.set(false);
+    solved.unwrap_or_else(|_| {
+        Status::Panicked(
+            PANIC
+                .with(|p| p.borrow_mut().take())
+                .unwrap_or_else(|| "unknown panic".to_string()),
+        )
+    })
+}
+
+fn solve(day: u8, parse: Parse, input: &str, expected: &Answers) -> Status {
+    let start = Instant::now();
+    let parsed = match parse(input, aoc::InputKind::Real) {
+        Ok(parsed) => parsed,
+        Err(e) => return Status::ParseFailed(e.to_string()),
+    };
+    let parse = start.elapsed();
+    let parts = Part::BOTH
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = parsed.solve(part);
+            let elapsed = start.elapsed();
+            let check = match expected.get(day, part) {
+                _ if answer == Answer::NoAnswer => Check::NoAnswer,
+                None => Check::Unchecked,
+                Some(expected) if *expected == answer => Check::Ok,
+                Some(expected) => Check::Mismatch {
+                    expected: expected.clone(),
+                },
+            };
+            PartRun {
+                part,
+                answer,
+                elapsed,
+                check,
+            }
+        })
+        .collect();
+    Status::Checked { parse, parts }
+}
+
+/// Counts of each outcome, over parts for answers and over days otherwise.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub ok: usize,
+    pub mismatches: usize,
+    pub no_answers: usize,
+    pub unchecked: usize,
+    pub missing_inputs: usize,
+    pub parse_failures: usize,
+    pub panics: usize,
+}
+
+impl Summary {
+    pub fn failed(&self) -> bool {
+        self.mismatches + self.no_answers + self.parse_failures + self.panics > 0
+    }
+}
+
+/// Multi-line answers on one line.
+pub fn one_line(answer: &Answer) -> String {
+    answer.to_string().trim_end().replace('\n', "\\n")
+}
+
+/// One line per part or day, then the counts.
+pub fn report(year: u16, days: &[(u8, Status)]) -> (String, Summary) {
+    let mut out = String::new();
+    let mut summary = Summary::default();
+    for (day, status) in days {
+        match status {
+            Status::Checked { parse, parts } => {
+                for run in parts {
+                    let (verdict, detail) = match &run.check {
+                        Check::Ok => {
+                            summary.ok += 1;
+                            ("ok", String::new())
+                        }
+                        Check::Mismatch { expected } => {
+                            summary.mismatches += 1;
+                            ("MISMATCH", format!(" (expected {})", one_line(expected)))
+                        }
+                        Check::Unchecked => {
+                            summary.unchecked += 1;
+                            ("unchecked", String::new())
+                        }
+                        Check::NoAnswer => {
+                            summary.no_answers += 1;
+                            ("NO ANSWER", String::new())
+                        }
+                    };
+                    let _ = writeln!(
+                        out,
+                        "{year} day {day:02} part {}  {verdict:<9}  {:>10}  {}{detail}",
+                        run.part.number(),
+                        format!("{:.2?}", run.elapsed),
+                        one_line(&run.answer),
+                    );
+                }
+                let _ = writeln!(
+                    out,
+                    "{year} day {day:02} parse   {:>21}",
+                    format!("{parse:.2?}")
+                );
+            }
+            Status::MissingInput(e) => {
+                summary.missing_inputs += 1;
+                let _ = writeln!(out, "{year} day {day:02}         no input: {e}");
+            }
+            Status::ParseFailed(e) => {
+                summary.parse_failures += 1;
+                let _ = writeln!(out, "{year} day {day:02}         FAILED: {e}");
+            }
+            Status::Panicked(e) => {
+                summary.panics += 1;
+                let _ = writeln!(out, "{year} day {day:02}         PANICKED: {e}");
+            }
+        }
+    }
+    let _ = writeln!(
+        out,
+        "{} ok, {} mismatched, {} without an answer, {} unchecked, {} without input, {} failed to parse, {} panicked",
+        summary.ok,
+        summary.mismatches,
+        summary.no_answers,
+        summary.unchecked,
+        summary.missing_inputs,
+        summary.parse_failures,
+        summary.panics
+    );
+    (out, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn double(input: &str, kind: aoc::InputKind) -> Result<Box<dyn aoc::Parsed>, aoc::ParseError> {
+        struct Double;
+        impl aoc::Solution for Double {
+            type Input = i64;
+            fn parse(input: &str) -> Result<i64, aoc::ParseError> {
+                aoc::parse_at(input, input.trim(), "a number")
+            }
+            fn part1(n: &i64) -> Answer {
+                if *n == 0 {
+                    return Answer::NoAnswer;
+                }
+                (n * 2).into()
+            }
+            fn part2(n: &i64) -> Answer {
+                assert!(*n >= 0, "negative input");
+                Answer::Unsolved
+            }
+        }
+        aoc::solution::<Double>()(input, kind)
+    }
+
+    #[test]
+    fn checks() {
+        let mut expected = Answers::default();
+        expected.set(1, Part::One, Answer::Number(4));
+        expected.set(2, Part::One, Answer::Number(5));
+        let checks = |day, input| match verify(day, double, input, &expected) {
+            Status::Checked { parts, .. } => parts.into_iter().map(|p| p.check).collect::<Vec<_>>(),
+            status => panic!("{status:?}"),
+        };
+        assert_eq!(checks(1, "2\n"), [Check::Ok, Check::Unchecked]);
+        assert_eq!(
+            checks(2, "2\n"),
+            [
+                Check::Mismatch {
+                    expected: Answer::Number(5)
+                },
+                Check::Unchecked
+            ]
+        );
+        assert_eq!(checks(2, "0\n"), [Check::NoAnswer, Check::Unchecked]);
+        assert!(matches!(
+            verify(1, double, "two", &expected),
+            Status::ParseFailed(_)
+        ));
+    }
+
+    #[test]
+    fn panics() {
+        install_panic_hook();
+        let expected = Answers::default();
+        let days = [
+            (1, verify(1, double, "-1", &expected)),
+            (2, verify(2, double, "2", &expected)),
+        ];
+        let Status::Panicked(message) = &days[0].1 else {
+            panic!("{:?}", days[0].1);
+        };
+        assert!(
+            message.starts_with("negative input (runner/src/verify.rs:"),
+            "{message}"
+        );
+        let (text, summary) = report(2024, &days);
+        assert_eq!(summary.panics, 1);
+        assert!(summary.failed());
+        assert!(text.contains("2024 day 01         PANICKED: negative input"));
+    }
+
+    #[test]
+    fn summary() {
+        let expected = Answers::parse("[1]\npart1 = 5\n").unwrap();
+        let days = [
+            (1, verify(1, double, "2", &expected)),
+            (2, Status::MissingInput("no session".to_string())),
+            (3, verify(3, double, "0", &expected)),
+        ];
+        let (text, summary) = report(2024, &days);
+        assert_eq!(
+            summary,
+            Summary {
+                mismatches: 1,
+                no_answers: 1,
+                unchecked: 2,
+                missing_inputs: 1,
+                ..Summary::default()
+            }
+        );
+        assert!(summary.failed());
+        assert!(text.contains("MISMATCH"));
+        assert!(text.contains("4 (expected 5)"));
+        assert!(text.contains("2024 day 03 part 1  NO ANSWER"));
+        assert!(text.ends_with(
+            "0 ok, 1 mismatched, 1 without an answer, 2 unchecked, 1 without input, 0 failed to parse, 0 panicked\n"
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Modification times of the watched files, `None` for the ones that do not exist (yet).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        Snapshot(
+            paths
+                .iter()
+                .map(|path| {
+                    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+                    (path.clone(), modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// The files that appeared, changed or disappeared since `earlier`.
+    pub fn changed<'a>(&'a self, earlier: &Snapshot) -> Vec<&'a Path> {
+        self.0
+            .iter()
+            .filter(|(path, modified)| earlier.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+}
+
+/// The source of `day`, its example test file and examples, and `input` if any.
+pub fn paths(root: &Path, year: u16, day: u8, input: Option<PathBuf>) -> Vec<PathBuf> {
+    let dir = root.join(year.to_string());
+    let mut paths = vec![
+        dir.join(format!("src/day{day:02}.rs")),
+        dir.join("tests/examples.rs"),
+    ];
+    let examples = dir.join("tests/examples");
+    let prefix = format!("day{day:02}");
+    if let Ok(entries) = std::fs::read_dir(&examples) {
+        let mut found: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with(&prefix))
+            })
+            .collect();
+        found.sort();
+        paths.extend(found);
+    }
+    paths.extend(input);
+    paths
+}
+
+/// The answers of `run --format tsv`, by part number.
+pub fn parse_answers(tsv: &str) -> BTreeMap<u8, String> {
+    tsv.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [_, _, part, answer, _] = fields[..] else {
+                return None;
+            };
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+/// Each answer, and what it was in the `previous` run when that differs.
+pub fn diff(previous: Option<&BTreeMap<u8, String>>, current: &BTreeMap<u8, String>) -> String {
+    let mut out = String::new();
+    for (part, answer) in current {
+        let shown = if answer.is_empty() {
+            "unsolved"
+        } else {
+            answer
+        };
+        let change = match previous.map(|p| p.get(part)) {
+            None => String::new(),
+            Some(Some(before)) if before == answer => " (unchanged)".to_string(),
+            Some(Some(before)) if before.is_empty() => " (was unsolved)".to_string(),
+            Some(Some(before)) => format!(" (was {before})"),
+            Some(None) => " (new)".to_string(),
+        };
+        let _ = writeln!(out, "Star{part}: {shown}{change}");
+    }
+    out
+}
+
+/// How one example test went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleResult {
+    pub name: String,
+    /// `ok`, `FAILED` or `ignored`.
+    pub verdict: String,
+    /// For failures, the assertion and its expected and actual values.
+    pub details: Vec<String>,
+}
+
+/// The tests of the `cargo test` output `stdout`, with the assertions that failed.
+pub fn parse_examples(stdout: &str) -> Vec<ExampleResult> {
+    let mut results: Vec<ExampleResult> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, verdict) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            Some(ExampleResult {
+                name: name.to_string(),
+                verdict: verdict.trim().to_string(),
+                details: Vec::new(),
+            })
+        })
+        .collect();
+    let mut current = None;
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            current = results.iter().position(|r| r.name == name);
+        } else if line.starts_with("stack backtrace:") || line == "failures:" {
+            current = None;
+        } else if let Some(i) = current {
+            if !line.trim().is_empty()
+                && !line.starts_with("thread '")
+                && !line.starts_with("note:")
+            {
+                results[i].details.push(line.to_string());
+            }
+        }
+    }
+    results
+}
+
+pub fn report_examples(results: &[ExampleResult]) -> String {
+    let mut out = String::new();
+    if results.is_empty() {
+        out.push_str("no example tests\n");
+    }
+    for result in results {
+        let _ = writeln!(out, "example {}: {}", result.name, result.verdict);
+        for line in &result.details {
+            let _ = writeln!(out, "    {line}");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2024/src")).unwrap();
+        std::fs::create_dir_all(dir.join("2024/tests/examples")).unwrap();
+        for file in ["day03.txt", "day03-2.txt", "day04.txt"] {
+            std::fs::write(dir.join("2024/tests/examples").join(file), "").unwrap();
+        }
+        let watched = paths(&dir, 2024, 3, Some(dir.join("input/2024/03")));
+        let names: Vec<_> = watched
+            .iter()
+            .map(|p| p.strip_prefix(&dir).unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "2024/src/day03.rs",
+                "2024/tests/examples.rs",
+                "2024/tests/examples/day03-2.txt",
+                "2024/tests/examples/day03.txt",
+                "input/2024/03"
+            ]
+        );
+        let before = Snapshot::take(&watched);
+        assert!(Snapshot::take(&watched).changed(&before).is_empty());
+        std::fs::write(&watched[0], "").unwrap();
+        assert_eq!(Snapshot::take(&watched).changed(&before), [&watched[0]]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answers() {
+        let first = parse_answers(
+            "year\tday\tpart\tanswer\telapsed\n2024\t3\t1\t161\t0.1\n2024\t3\t2\t\t0.1\n",
+        );
+        assert_eq!(diff(None, &first), "Star1: 161\nStar2: unsolved\n");
+        let second = parse_answers(
+            "year\tday\tpart\tanswer\telapsed\n2024\t3\t1\t161\t0.1\n2024\t3\t2\t48\t0.1\n",
+        );
+        assert_eq!(
+            diff(Some(&first), &second),
+            "Star1: 161 (unchanged)\nStar2: 48 (was unsolved)\n"
+        );
+        let third = parse_answers("year\tday\tpart\tanswer\telapsed\n2024\t3\t1\t160\t0.1\n");
+        assert_eq!(diff(Some(&second), &third), "Star1: 160 (was 161)\n");
+    }
+
+    #[test]
+    fn examples() {
+        let stdout = "
+running 2 tests
+test day03 ... FAILED
+test day03_dos ... ok
+
+failures:
+
+---- day03 stdout ----
+
+thread 'day03' (7) panicked at 2024/tests/examples.rs:8:1:
+assertion `left == right` failed: day03.txt part 1
+  left: Number(160)
+ right: Number(161)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    day03
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 3 filtered out; finished in 0.00s
+";
+        let results = parse_examples(stdout);
+        assert_eq!(
+            results,
+            [
+                ExampleResult {
+                    name: "day03".to_string(),
+                    verdict: "FAILED".to_string(),
+                    details: vec![
+                        "assertion `left == right` failed: day03.txt part 1".to_string(),
+                        "  left: Number(160)".to_string(),
+                        " right: Number(161)".to_string(),
+                    ],
+                },
+                ExampleResult {
+                    name: "day03_dos".to_string(),
+                    verdict: "ok".to_string(),
+                    details: Vec::new(),
+                },
+            ]
+        );
+        assert_eq!(
+            report_examples(&results),
+            "example day03: FAILED\n    assertion `left == right` failed: day03.txt part 1\n      left: Number(160)\n     right: Number(161)\nexample day03_dos: ok\n"
+        );
+    }
+}